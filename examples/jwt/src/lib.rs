@@ -32,29 +32,17 @@ pub fn new_token(
     let unsigned_token = Token::new(header, claims);
 
     let secret_key = get_secret_key_from_env();
+    let stored_password_hash = get_password_hash_from_env()?;
 
     let (_, signed_token) = secret_key?.expose_secret(move |exposed_key| {
         let key: Hmac<Sha256> =
             Hmac::new_from_slice(&*exposed_key.as_bytes()).map_err(|_e| "Invalid key")?;
-        let (_, returned_value) = secret_password.expose_secret(|exposed_password| {
-            if *exposed_password != "password" {
-                return Err("Wrong password used!");
-            }
-            return Ok(());
-        });
-        // let (next_secret, returned_value) = secret_password.expose_secret(|exposed_password| {
-        //     if *exposed_password != "password" {
-        //         return Err("Wrong password used!");
-        //     }
-        //     return Ok(());
-        // });
-        // let (next_secret, returned_value) = next_secret.expose_secret(|exposed_password| {
-        //     if *exposed_password != "password" {
-        //         return Err("Wrong password used!");
-        //     }
-        //     return Ok(());
-        // });
-        let _ = returned_value?;
+        let (_, matches) = secret_password.verify_password(&stored_password_hash);
+        // let (next_secret, matches) = secret_password.verify_password(&stored_password_hash);
+        // let (next_secret, matches) = next_secret.verify_password(&stored_password_hash);
+        if !matches.map_err(|_e| "Invalid password hash")? {
+            return Err("Wrong password used!".into());
+        }
         let signed_token = unsigned_token
             .sign_with_key(&key)
             .map_err(|_e| "Sign error")?;
@@ -77,6 +65,11 @@ pub fn login(token: &str) -> Result<String, Box<dyn Error>> {
     Ok(claims.sub)
 }
 
+/// Returns the Argon2id PHC string of the password, which is stored instead of the password itself.
+pub(crate) fn get_password_hash_from_env() -> Result<String, Box<dyn Error>> {
+    Ok(env::var("PASSWORD_HASH")?)
+}
+
 pub(crate) fn get_secret_key_from_env() -> Result<Secret<String, U1>, Box<dyn Error>> {
     Ok(env::var("SECRET_KEY").map(Secret::new)?)
 }
@@ -1,7 +1,7 @@
 #!/bin/bash
 
 # Array of feature names
-features=("cloneable-secret", "alloc", "zeroize", "debug-secret")
+features=("cloneable-secret", "alloc", "zeroize", "debug-secret", "display-secret", "std", "command", "derive", "serde", "fingerprint", "memfd-secret", "keyring", "sealed", "envelope", "rand-core", "getrandom", "password", "hkdf", "password-hash")
 
 # Calculate the total number of features
 total_features=${#features[@]}
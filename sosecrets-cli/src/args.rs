@@ -0,0 +1,339 @@
+//! Parsing of the command line.
+
+use std::{ffi::OsString, fmt, path::PathBuf};
+
+/// The usage of `sosecrets`, printed by `sosecrets --help` and after a usage error.
+pub(crate) const USAGE: &str = "\
+Usage:
+  sosecrets seal [KEY] [--label LABEL] [--iterations N] [--force] OUTPUT
+  sosecrets run [KEY] --env NAME=FILE... -- COMMAND [ARGS...]
+  sosecrets inspect [--fingerprint-key-file PATH [KEY]] FILE
+
+Commands:
+  seal      Seals the secret read from the standard input, or prompted on the terminal,
+            into the envelope file OUTPUT.
+  run       Runs COMMAND with the secret of each envelope FILE in its environment variable NAME.
+  inspect   Prints the label, version, KDF and length of the envelope FILE, and with
+            --fingerprint-key-file, the fingerprint of its secret under the key in PATH.
+
+KEY is one of:
+  --key-file PATH          A raw 256-bit key, i.e. a file of exactly 32 bytes.
+  --passphrase-file PATH   A passphrase, i.e. the first line of the file.
+and defaults to a passphrase prompted on the terminal.
+";
+
+/// Where the key of the envelopes is read from.
+#[derive(Debug, PartialEq, Eq)]
+pub(crate) enum KeySource {
+    /// A file holding a raw 256-bit key.
+    KeyFile(PathBuf),
+    /// A file whose first line is the passphrase.
+    PassphraseFile(PathBuf),
+    /// A passphrase prompted on the terminal.
+    Prompt,
+}
+
+/// A parsed command line.
+#[derive(Debug, PartialEq, Eq)]
+pub(crate) enum Command {
+    Help,
+    Seal {
+        key: KeySource,
+        label: String,
+        iterations: Option<u32>,
+        force: bool,
+        output: PathBuf,
+    },
+    Run {
+        key: KeySource,
+        env: Vec<(OsString, PathBuf)>,
+        program: OsString,
+        args: Vec<OsString>,
+    },
+    Inspect {
+        key: KeySource,
+        fingerprint_key: Option<PathBuf>,
+        file: PathBuf,
+    },
+}
+
+/// An invalid command line.
+#[derive(Debug, PartialEq, Eq)]
+pub(crate) struct UsageError(String);
+
+impl fmt::Display for UsageError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+/// The arguments of a command, consumed from left to right.
+struct Args<I> {
+    args: I,
+    command: &'static str,
+}
+
+impl<I: Iterator<Item = OsString>> Args<I> {
+    /// Returns the value of the option `option`.
+    fn value(&mut self, option: &str) -> Result<OsString, UsageError> {
+        self.args.next().ok_or_else(|| {
+            UsageError(format!(
+                "{}: the option {} requires a value",
+                self.command, option
+            ))
+        })
+    }
+
+    /// Returns the value of the option `option`, which must be valid UTF-8.
+    fn string_value(&mut self, option: &str) -> Result<String, UsageError> {
+        self.value(option)?.into_string().map_err(|_| {
+            UsageError(format!(
+                "{}: the value of {} is not valid UTF-8",
+                self.command, option
+            ))
+        })
+    }
+
+    /// Sets `key` to the key source of the option `option`, if it is one.
+    fn key_option(&mut self, option: &str, key: &mut KeySource) -> Result<bool, UsageError> {
+        let source = match option {
+            "--key-file" => KeySource::KeyFile(self.value(option)?.into()),
+            "--passphrase-file" => KeySource::PassphraseFile(self.value(option)?.into()),
+            _ => return Ok(false),
+        };
+        if *key != KeySource::Prompt {
+            return Err(self.error("only one of --key-file and --passphrase-file may be given"));
+        }
+        *key = source;
+        Ok(true)
+    }
+
+    fn error(&self, message: impl fmt::Display) -> UsageError {
+        UsageError(format!("{}: {}", self.command, message))
+    }
+}
+
+/// Parses the command line `args`, without the name of the program.
+pub(crate) fn parse(args: impl IntoIterator<Item = OsString>) -> Result<Command, UsageError> {
+    let mut args = args.into_iter();
+    let command = match args.next() {
+        None => return Err(UsageError(String::from("missing command"))),
+        Some(command) => command,
+    };
+    let command = match command.to_str() {
+        Some("seal") => "seal",
+        Some("run") => "run",
+        Some("inspect") => "inspect",
+        Some("-h" | "--help" | "help") => return Ok(Command::Help),
+        _ => {
+            return Err(UsageError(format!(
+                "unknown command {}",
+                command.to_string_lossy()
+            )))
+        }
+    };
+    let mut args = Args { args, command };
+    let mut key = KeySource::Prompt;
+    let mut positionals = Vec::new();
+
+    match command {
+        "seal" => {
+            let mut label = String::new();
+            let mut iterations = None;
+            let mut force = false;
+            while let Some(arg) = args.args.next() {
+                match arg.to_str() {
+                    Some("-h" | "--help") => return Ok(Command::Help),
+                    Some("--label") => label = args.string_value("--label")?,
+                    Some("--iterations") => {
+                        let value = args.string_value("--iterations")?;
+                        iterations = Some(value.parse().map_err(|_| {
+                            args.error(format_args!("invalid number of iterations {}", value))
+                        })?);
+                    }
+                    Some("--force") => force = true,
+                    Some(option) if args.key_option(option, &mut key)? => {}
+                    Some(option) if option.starts_with('-') => {
+                        return Err(args.error(format_args!("unknown option {}", option)))
+                    }
+                    _ => positionals.push(arg),
+                }
+            }
+            if iterations.is_some() && matches!(key, KeySource::KeyFile(_)) {
+                return Err(args.error("--iterations requires a passphrase"));
+            }
+            let output = single_positional(&args, positionals, "OUTPUT")?;
+            Ok(Command::Seal {
+                key,
+                label,
+                iterations,
+                force,
+                output,
+            })
+        }
+        "run" => {
+            let mut env = Vec::new();
+            let mut command_line = None;
+            while let Some(arg) = args.args.next() {
+                match arg.to_str() {
+                    Some("-h" | "--help") => return Ok(Command::Help),
+                    Some("--env") => {
+                        let value = args.value("--env")?;
+                        env.push(env_assignment(&args, value)?);
+                    }
+                    Some("--") => {
+                        command_line = Some(args.args.by_ref().collect::<Vec<_>>());
+                        break;
+                    }
+                    Some(option) if args.key_option(option, &mut key)? => {}
+                    _ => {
+                        return Err(args.error(format_args!(
+                            "unexpected argument {}, the command must follow --",
+                            arg.to_string_lossy()
+                        )))
+                    }
+                }
+            }
+            if env.is_empty() {
+                return Err(args.error("at least one --env NAME=FILE is required"));
+            }
+            let mut command_line = command_line.unwrap_or_default().into_iter();
+            let program = command_line
+                .next()
+                .ok_or_else(|| args.error("missing COMMAND after --"))?;
+            Ok(Command::Run {
+                key,
+                env,
+                program,
+                args: command_line.collect(),
+            })
+        }
+        _ => {
+            let mut fingerprint_key = None;
+            while let Some(arg) = args.args.next() {
+                match arg.to_str() {
+                    Some("-h" | "--help") => return Ok(Command::Help),
+                    Some("--fingerprint-key-file") => {
+                        fingerprint_key = Some(args.value("--fingerprint-key-file")?.into())
+                    }
+                    Some(option) if args.key_option(option, &mut key)? => {}
+                    Some(option) if option.starts_with('-') => {
+                        return Err(args.error(format_args!("unknown option {}", option)))
+                    }
+                    _ => positionals.push(arg),
+                }
+            }
+            if fingerprint_key.is_none() && key != KeySource::Prompt {
+                return Err(args.error("a key is only needed with --fingerprint-key-file"));
+            }
+            let file = single_positional(&args, positionals, "FILE")?;
+            Ok(Command::Inspect {
+                key,
+                fingerprint_key,
+                file,
+            })
+        }
+    }
+}
+
+/// Returns the only positional argument `name`.
+fn single_positional<I: Iterator<Item = OsString>>(
+    args: &Args<I>,
+    positionals: Vec<OsString>,
+    name: &str,
+) -> Result<PathBuf, UsageError> {
+    let mut positionals = positionals.into_iter();
+    match (positionals.next(), positionals.next()) {
+        (Some(positional), None) => Ok(positional.into()),
+        (None, _) => Err(args.error(format_args!("missing {}", name))),
+        (Some(_), Some(extra)) => Err(args.error(format_args!(
+            "unexpected argument {}",
+            extra.to_string_lossy()
+        ))),
+    }
+}
+
+/// Splits the value `NAME=FILE` of `--env`.
+fn env_assignment<I: Iterator<Item = OsString>>(
+    args: &Args<I>,
+    value: OsString,
+) -> Result<(OsString, PathBuf), UsageError> {
+    use std::os::unix::ffi::{OsStrExt, OsStringExt};
+
+    let bytes = value.into_vec();
+    match bytes.iter().position(|&byte| byte == b'=') {
+        Some(equals) if equals > 0 && !bytes[..equals].contains(&0) => {
+            let name = std::ffi::OsStr::from_bytes(&bytes[..equals]).to_owned();
+            let file = std::ffi::OsStr::from_bytes(&bytes[equals + 1..]).into();
+            Ok((name, file))
+        }
+        _ => Err(args.error(format_args!(
+            "invalid --env {}, expected NAME=FILE",
+            String::from_utf8_lossy(&bytes)
+        ))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse_str(args: &[&str]) -> Result<Command, UsageError> {
+        parse(args.iter().map(OsString::from))
+    }
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(
+            parse_str(&["seal", "--key-file", "k", "--label", "db", "out.env"]),
+            Ok(Command::Seal {
+                key: KeySource::KeyFile("k".into()),
+                label: String::from("db"),
+                iterations: None,
+                force: false,
+                output: "out.env".into(),
+            })
+        );
+        assert_eq!(
+            parse_str(&["run", "--env", "A=a.env", "--env", "B=b=c", "--", "sh", "-c", "--"]),
+            Ok(Command::Run {
+                key: KeySource::Prompt,
+                env: vec![("A".into(), "a.env".into()), ("B".into(), "b=c".into())],
+                program: "sh".into(),
+                args: vec!["-c".into(), "--".into()],
+            })
+        );
+        assert_eq!(
+            parse_str(&["inspect", "a.env"]),
+            Ok(Command::Inspect {
+                key: KeySource::Prompt,
+                fingerprint_key: None,
+                file: "a.env".into(),
+            })
+        );
+        assert_eq!(parse_str(&["seal", "--help"]), Ok(Command::Help));
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        for args in [
+            &[][..],
+            &["unseal"],
+            &["seal"],
+            &["seal", "a", "b"],
+            &["seal", "--label"],
+            &["seal", "--key-file", "k", "--passphrase-file", "p", "out"],
+            &["seal", "--key-file", "k", "--iterations", "10", "out"],
+            &["seal", "--iterations", "many", "out"],
+            &["run", "--", "sh"],
+            &["run", "--env", "=a.env", "--", "sh"],
+            &["run", "--env", "A", "--", "sh"],
+            &["run", "--env", "A=a.env"],
+            &["run", "--env", "A=a.env", "sh"],
+            &["inspect", "--key-file", "k", "a.env"],
+            &["inspect", "--verbose", "a.env"],
+        ] {
+            assert!(parse_str(args).is_err(), "{:?} must not parse", args);
+        }
+    }
+}
@@ -0,0 +1,313 @@
+//! The `sosecrets` command line tool, which seals secrets into the envelope files of [`sosecrets-rs`](https://docs.rs/sosecrets-rs),
+//! runs commands with them, and inspects them.
+//!
+//! - `sosecrets seal` reads a secret from the standard input, or from a prompt on the terminal which does not echo it,
+//!   and seals it into an envelope file, see `sosecrets_rs::envelope`.
+//! - `sosecrets run -- COMMAND` opens envelope files and executes `COMMAND` with their secrets in its environment.
+//!   The secrets are never printed; the process of `sosecrets` is replaced by `COMMAND`, so that no other process keeps a copy of them.
+//! - `sosecrets inspect` prints the metadata of an envelope file, which is not secret: its label, version, KDF and length,
+//!   and optionally the fingerprint of its secret, see `sosecrets_rs::fingerprint`.
+//!
+//! Run `sosecrets --help` for the full usage.
+
+#[cfg(unix)]
+mod args;
+#[cfg(unix)]
+mod terminal;
+
+use std::process::ExitCode;
+
+#[cfg(unix)]
+fn main() -> ExitCode {
+    match args::parse(std::env::args_os().skip(1)) {
+        Ok(args::Command::Help) => {
+            print!("{}", args::USAGE);
+            ExitCode::SUCCESS
+        }
+        Ok(command) => match cli::run(command) {
+            Ok(()) => ExitCode::SUCCESS,
+            Err(failure) => {
+                eprintln!("sosecrets: {}", failure.message);
+                ExitCode::from(failure.code)
+            }
+        },
+        Err(error) => {
+            eprintln!("sosecrets: {}\n\n{}", error, args::USAGE);
+            ExitCode::from(2)
+        }
+    }
+}
+
+#[cfg(not(unix))]
+fn main() -> ExitCode {
+    eprintln!("sosecrets: envelope files are only supported on Unix");
+    ExitCode::FAILURE
+}
+
+#[cfg(unix)]
+mod cli {
+    use std::{
+        ffi::{OsStr, OsString},
+        fmt::Display,
+        fs::{self, File, OpenOptions},
+        io::{self, IsTerminal, Read},
+        os::unix::{ffi::OsStrExt, fs::OpenOptionsExt, process::CommandExt},
+        path::{Path, PathBuf},
+        process,
+    };
+
+    use sosecrets_rs::{
+        envelope::{Envelope, EnvelopeKey, Kdf},
+        fingerprint::FingerprintKey,
+        prelude::{typenum::U1, RTSecret},
+        runtime::traits::RTExposeSecret,
+    };
+    use zeroize::Zeroizing;
+
+    use crate::{
+        args::{Command, KeySource},
+        terminal,
+    };
+
+    /// The maximum length of a secret, a passphrase or a key file, in bytes,
+    /// which is the maximum length of an environment variable on Linux.
+    const MAX_INPUT_LEN: usize = 128 * 1024;
+
+    /// The length of a raw key, in bytes.
+    const KEY_LEN: usize = 32;
+
+    /// A failed command.
+    pub(crate) struct Failure {
+        pub(crate) code: u8,
+        pub(crate) message: String,
+    }
+
+    impl Failure {
+        fn new(context: impl Display, error: impl Display) -> Self {
+            Self {
+                code: 1,
+                message: format!("{}: {}", context, error),
+            }
+        }
+    }
+
+    /// The key read from a [`KeySource`].
+    enum Key {
+        Raw(Zeroizing<[u8; KEY_LEN]>),
+        Passphrase(Zeroizing<Vec<u8>>),
+    }
+
+    impl Key {
+        /// Reads the key from `source`, prompting for the passphrase twice if `confirm` is `true`.
+        fn read(source: &KeySource, confirm: bool) -> Result<Self, Failure> {
+            match source {
+                KeySource::KeyFile(path) => {
+                    let bytes = read_file(path)?;
+                    let mut key = Zeroizing::new([0; KEY_LEN]);
+                    if bytes.len() != KEY_LEN {
+                        return Err(Failure::new(
+                            path.display(),
+                            format_args!("a key file must hold exactly {} bytes", KEY_LEN),
+                        ));
+                    }
+                    key.copy_from_slice(&bytes);
+                    Ok(Self::Raw(key))
+                }
+                KeySource::PassphraseFile(path) => {
+                    let file =
+                        File::open(path).map_err(|error| Failure::new(path.display(), error))?;
+                    let passphrase = terminal::read_line(file, MAX_INPUT_LEN)
+                        .map_err(|error| Failure::new(path.display(), error))?;
+                    Ok(Self::Passphrase(passphrase))
+                }
+                KeySource::Prompt => {
+                    let passphrase = prompt("Passphrase: ", confirm)?;
+                    Ok(Self::Passphrase(passphrase))
+                }
+            }
+        }
+
+        fn envelope_key(&self) -> EnvelopeKey<'_> {
+            match self {
+                Self::Raw(key) => EnvelopeKey::raw(key),
+                Self::Passphrase(passphrase) => EnvelopeKey::passphrase(passphrase),
+            }
+        }
+    }
+
+    /// Prompts for a secret on the terminal, twice if `confirm` is `true`.
+    fn prompt(message: &str, confirm: bool) -> Result<Zeroizing<Vec<u8>>, Failure> {
+        let terminal_failure = |error| Failure::new("/dev/tty", error);
+        let value = terminal::prompt(message, MAX_INPUT_LEN).map_err(terminal_failure)?;
+        if value.is_empty() {
+            return Err(Failure::new(
+                message.trim_end_matches(": "),
+                "must not be empty",
+            ));
+        }
+        if confirm {
+            let again = terminal::prompt("Again: ", MAX_INPUT_LEN).map_err(terminal_failure)?;
+            if again != value {
+                return Err(Failure::new(
+                    message.trim_end_matches(": "),
+                    "the values do not match",
+                ));
+            }
+        }
+        Ok(value)
+    }
+
+    /// Reads the whole file at `path` into a zeroizing buffer of [`MAX_INPUT_LEN`] bytes.
+    fn read_file(path: &Path) -> Result<Zeroizing<Vec<u8>>, Failure> {
+        let file = File::open(path).map_err(|error| Failure::new(path.display(), error))?;
+        read_all(file).map_err(|error| Failure::new(path.display(), error))
+    }
+
+    /// Reads `reader` to its end into a zeroizing buffer of [`MAX_INPUT_LEN`] bytes, allocated up front so that it is never reallocated.
+    fn read_all(reader: impl Read) -> io::Result<Zeroizing<Vec<u8>>> {
+        let mut bytes = Zeroizing::new(Vec::with_capacity(MAX_INPUT_LEN + 1));
+        reader
+            .take(MAX_INPUT_LEN as u64 + 1)
+            .read_to_end(&mut bytes)?;
+        if bytes.len() > MAX_INPUT_LEN {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("longer than {} bytes", MAX_INPUT_LEN),
+            ));
+        }
+        Ok(bytes)
+    }
+
+    /// Runs a parsed `command`.
+    pub(crate) fn run(command: Command) -> Result<(), Failure> {
+        match command {
+            Command::Help => Ok(()),
+            Command::Seal {
+                key,
+                label,
+                iterations,
+                force,
+                output,
+            } => seal(&key, &label, iterations, force, &output),
+            Command::Run {
+                key,
+                env,
+                program,
+                args,
+            } => exec(&key, &env, &program, &args),
+            Command::Inspect {
+                key,
+                fingerprint_key,
+                file,
+            } => inspect(&key, fingerprint_key.as_deref(), &file),
+        }
+    }
+
+    fn seal(
+        key: &KeySource,
+        label: &str,
+        iterations: Option<u32>,
+        force: bool,
+        output: &Path,
+    ) -> Result<(), Failure> {
+        let stdin = io::stdin();
+        let mut secret = if stdin.is_terminal() {
+            prompt("Secret: ", true)?
+        } else {
+            read_all(stdin.lock()).map_err(|error| Failure::new("standard input", error))?
+        };
+        if secret.last() == Some(&b'\n') {
+            secret.pop();
+            if secret.last() == Some(&b'\r') {
+                secret.pop();
+            }
+        }
+        let secret = RTSecret::<Vec<u8>, U1>::new(core::mem::take(&mut *secret));
+
+        let key = Key::read(key, true)?;
+        let mut envelope_key = key.envelope_key();
+        if let Some(iterations) = iterations {
+            envelope_key = envelope_key.with_iterations(iterations);
+        }
+
+        let mut options = OpenOptions::new();
+        options.write(true).mode(0o600);
+        if force {
+            options.create(true).truncate(true);
+        } else {
+            options.create_new(true);
+        }
+        let file = options
+            .open(output)
+            .map_err(|error| Failure::new(output.display(), error))?;
+        secret
+            .seal_to_writer(&envelope_key, label, file)
+            .map_err(|error| {
+                let _ = fs::remove_file(output);
+                Failure::new(output.display(), error)
+            })
+    }
+
+    fn exec(
+        key: &KeySource,
+        env: &[(OsString, PathBuf)],
+        program: &OsStr,
+        args: &[OsString],
+    ) -> Result<(), Failure> {
+        let key = Key::read(key, false)?;
+        let envelope_key = key.envelope_key();
+        let mut command = process::Command::new(program);
+        command.args(args);
+        for (name, path) in env {
+            let file = File::open(path).map_err(|error| Failure::new(path.display(), error))?;
+            let secret = RTSecret::<Vec<u8>, U1>::open_from_reader(&envelope_key, file)
+                .map_err(|error| Failure::new(path.display(), error))?;
+            secret.expose_secret(|exposed_secret| {
+                command.env(name, OsStr::from_bytes(&exposed_secret));
+            });
+        }
+        drop(key);
+        // `exec` only returns if the command cannot be executed.
+        let error = command.exec();
+        Err(Failure {
+            code: if error.kind() == io::ErrorKind::NotFound {
+                127
+            } else {
+                126
+            },
+            ..Failure::new(program.to_string_lossy(), error)
+        })
+    }
+
+    fn inspect(
+        key: &KeySource,
+        fingerprint_key: Option<&Path>,
+        file: &Path,
+    ) -> Result<(), Failure> {
+        let bytes = fs::read(file).map_err(|error| Failure::new(file.display(), error))?;
+        let envelope =
+            Envelope::parse(&bytes).map_err(|error| Failure::new(file.display(), error))?;
+        println!("label: {}", envelope.label());
+        println!("version: {}", envelope.version());
+        match envelope.kdf() {
+            Kdf::RawKey => println!("kdf: raw key"),
+            Kdf::Pbkdf2HmacSha256 { iterations } => {
+                println!("kdf: pbkdf2-hmac-sha256, {} iterations", iterations)
+            }
+        }
+        println!("length: {} bytes", envelope.secret_len());
+
+        if let Some(fingerprint_key) = fingerprint_key {
+            let fingerprint_key = read_file(fingerprint_key)?;
+            let key = Key::read(key, false)?;
+            let secret = RTSecret::<Vec<u8>, U1>::open_from_reader(&key.envelope_key(), &*bytes)
+                .map_err(|error| Failure::new(file.display(), error))?;
+            let fingerprint = secret.fingerprint(&FingerprintKey::new(
+                &fingerprint_key,
+                envelope.label().as_bytes(),
+            ));
+            println!("fingerprint: {}", fingerprint);
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,83 @@
+//! Reading secrets from the terminal without echoing them.
+
+use std::{
+    fs::{File, OpenOptions},
+    io::{self, Read, Write},
+    mem::MaybeUninit,
+    os::unix::io::AsRawFd,
+};
+
+use zeroize::{Zeroize, Zeroizing};
+
+/// Restores the settings of the terminal when dropped.
+struct EchoGuard<'a> {
+    tty: &'a File,
+    settings: libc::termios,
+}
+
+impl<'a> EchoGuard<'a> {
+    /// Disables the echo of the terminal `tty`.
+    fn disable(tty: &'a File) -> io::Result<Self> {
+        let mut settings = MaybeUninit::<libc::termios>::uninit();
+        // SAFETY: `settings` is valid for writes, and is initialized by `tcgetattr` if it succeeds.
+        if unsafe { libc::tcgetattr(tty.as_raw_fd(), settings.as_mut_ptr()) } != 0 {
+            return Err(io::Error::last_os_error());
+        }
+        // SAFETY: `tcgetattr` has succeeded.
+        let settings = unsafe { settings.assume_init() };
+        let mut no_echo = settings;
+        no_echo.c_lflag &= !libc::ECHO;
+        set_settings(tty, &no_echo)?;
+        Ok(Self { tty, settings })
+    }
+}
+
+impl Drop for EchoGuard<'_> {
+    fn drop(&mut self) {
+        let _ = set_settings(self.tty, &self.settings);
+    }
+}
+
+/// Applies `settings` to the terminal `tty`, after the pending output has been written.
+fn set_settings(tty: &File, settings: &libc::termios) -> io::Result<()> {
+    // SAFETY: `settings` is a valid `termios`, and `tty` is open.
+    if unsafe { libc::tcsetattr(tty.as_raw_fd(), libc::TCSADRAIN, settings) } == 0 {
+        Ok(())
+    } else {
+        Err(io::Error::last_os_error())
+    }
+}
+
+/// Prints `message` on the terminal, and reads a line from it without echoing it.
+///
+/// The line is read one byte at a time into a buffer of `max_len` bytes, so that it is never copied nor buffered elsewhere.
+pub(crate) fn prompt(message: &str, max_len: usize) -> io::Result<Zeroizing<Vec<u8>>> {
+    let mut tty = OpenOptions::new().read(true).write(true).open("/dev/tty")?;
+    tty.write_all(message.as_bytes())?;
+    let line = {
+        let _guard = EchoGuard::disable(&tty)?;
+        read_line(&tty, max_len)
+    };
+    tty.write_all(b"\n")?;
+    line
+}
+
+/// Reads a line of at most `max_len` bytes from `reader`, without its newline.
+pub(crate) fn read_line(mut reader: impl Read, max_len: usize) -> io::Result<Zeroizing<Vec<u8>>> {
+    let mut line = Zeroizing::new(Vec::with_capacity(max_len));
+    let mut byte = [0];
+    while reader.read(&mut byte)? == 1 && byte[0] != b'\n' {
+        if line.len() == max_len {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                "the line is too long",
+            ));
+        }
+        line.push(byte[0]);
+    }
+    byte.zeroize();
+    if line.last() == Some(&b'\r') {
+        line.pop();
+    }
+    Ok(line)
+}
@@ -0,0 +1,243 @@
+use std::{
+    fs,
+    io::Write,
+    path::PathBuf,
+    process::{Command, Output, Stdio},
+};
+
+const SOSECRETS: &str = env!("CARGO_BIN_EXE_sosecrets");
+
+/// Creates a new empty directory for the files of the test `name`.
+fn test_dir(name: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("sosecrets-cli-{}-{}", name, std::process::id()));
+    let _ = fs::remove_dir_all(&dir);
+    fs::create_dir_all(&dir).unwrap();
+    dir
+}
+
+/// Runs `sosecrets` with `args`, writing `stdin` to its standard input.
+fn sosecrets(args: &[&str], stdin: &[u8]) -> Output {
+    let mut child = Command::new(SOSECRETS)
+        .args(args)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .unwrap();
+    child.stdin.take().unwrap().write_all(stdin).unwrap();
+    child.wait_with_output().unwrap()
+}
+
+fn stdout(output: &Output) -> String {
+    String::from_utf8(output.stdout.clone()).unwrap()
+}
+
+fn stderr(output: &Output) -> String {
+    String::from_utf8(output.stderr.clone()).unwrap()
+}
+
+#[test]
+fn test_seal_run_inspect_with_key_file() {
+    let dir = test_dir("key-file");
+    let key = dir.join("key");
+    let envelope = dir.join("db.env");
+    fs::write(&key, [7; 32]).unwrap();
+    let (key, envelope) = (key.to_str().unwrap(), envelope.to_str().unwrap());
+
+    let output = sosecrets(
+        &[
+            "seal",
+            "--key-file",
+            key,
+            "--label",
+            "db-password",
+            envelope,
+        ],
+        b"hunter2\n",
+    );
+    assert!(output.status.success(), "{}", stderr(&output));
+    assert!(output.stdout.is_empty());
+    let bytes = fs::read(envelope).unwrap();
+    assert!(bytes.starts_with(b"SOSECENV"));
+    assert!(!bytes.windows(7).any(|window| window == b"hunter2"));
+
+    // Sealing does not overwrite an existing file without --force.
+    let output = sosecrets(&["seal", "--key-file", key, envelope], b"other");
+    assert_eq!(output.status.code(), Some(1));
+    assert_eq!(fs::read(envelope).unwrap(), bytes);
+
+    let output = sosecrets(
+        &[
+            "run",
+            "--key-file",
+            key,
+            "--env",
+            "DB_PASSWORD=",
+            "--",
+            "/bin/sh",
+            "-c",
+            r#"test "$DB_PASSWORD" = hunter2 && echo ok"#,
+        ],
+        b"",
+    );
+    // The value of `--env` is split at the first `=`, so the file of `DB_PASSWORD=` is empty.
+    assert_eq!(output.status.code(), Some(1));
+
+    let env = format!("DB_PASSWORD={}", envelope);
+    let output = sosecrets(
+        &[
+            "run",
+            "--key-file",
+            key,
+            "--env",
+            &env,
+            "--",
+            "/bin/sh",
+            "-c",
+            r#"test "$DB_PASSWORD" = hunter2 && echo ok; exit 3"#,
+        ],
+        b"",
+    );
+    assert_eq!(output.status.code(), Some(3), "{}", stderr(&output));
+    assert_eq!(stdout(&output), "ok\n");
+    assert!(stderr(&output).is_empty());
+
+    let output = sosecrets(&["inspect", envelope], b"");
+    assert!(output.status.success(), "{}", stderr(&output));
+    assert_eq!(
+        stdout(&output),
+        "label: db-password\nversion: 1\nkdf: raw key\nlength: 7 bytes\n"
+    );
+
+    let fingerprint_key = dir.join("fingerprint-key");
+    fs::write(&fingerprint_key, b"fingerprint key").unwrap();
+    let output = sosecrets(
+        &[
+            "inspect",
+            "--fingerprint-key-file",
+            fingerprint_key.to_str().unwrap(),
+            "--key-file",
+            key,
+            envelope,
+        ],
+        b"",
+    );
+    assert!(output.status.success(), "{}", stderr(&output));
+    let fingerprint = stdout(&output);
+    let fingerprint = fingerprint.lines().last().unwrap();
+    assert!(fingerprint.starts_with("fingerprint: "));
+    assert!(!fingerprint.contains("hunter2"));
+
+    // The wrong key does not open the envelope, and the command is not run.
+    fs::write(dir.join("wrong-key"), [8; 32]).unwrap();
+    let output = sosecrets(
+        &[
+            "run",
+            "--key-file",
+            dir.join("wrong-key").to_str().unwrap(),
+            "--env",
+            &env,
+            "--",
+            "/bin/sh",
+            "-c",
+            "echo ran",
+        ],
+        b"",
+    );
+    assert_eq!(output.status.code(), Some(1));
+    assert!(output.stdout.is_empty());
+    assert!(
+        stderr(&output).contains("cannot be authenticated"),
+        "{}",
+        stderr(&output)
+    );
+
+    fs::remove_dir_all(dir).unwrap();
+}
+
+#[test]
+fn test_seal_run_with_passphrase_file() {
+    let dir = test_dir("passphrase-file");
+    let passphrase = dir.join("passphrase");
+    let envelope = dir.join("token.env");
+    fs::write(&passphrase, "correct horse battery staple\n").unwrap();
+    let (passphrase, envelope) = (passphrase.to_str().unwrap(), envelope.to_str().unwrap());
+
+    let secret = "multi\nline ünicode secret";
+    let output = sosecrets(
+        &[
+            "seal",
+            "--passphrase-file",
+            passphrase,
+            "--iterations",
+            "1000",
+            envelope,
+        ],
+        secret.as_bytes(),
+    );
+    assert!(output.status.success(), "{}", stderr(&output));
+
+    let output = sosecrets(&["inspect", envelope], b"");
+    assert_eq!(
+        stdout(&output),
+        format!(
+            "label: \nversion: 1\nkdf: pbkdf2-hmac-sha256, 1000 iterations\nlength: {} bytes\n",
+            secret.len()
+        )
+    );
+
+    let env = format!("TOKEN={}", envelope);
+    let output = sosecrets(
+        &[
+            "run",
+            "--passphrase-file",
+            passphrase,
+            "--env",
+            &env,
+            "--",
+            "/usr/bin/env",
+        ],
+        b"",
+    );
+    assert!(output.status.success(), "{}", stderr(&output));
+    assert!(stdout(&output).contains(&format!("TOKEN={}\n", secret)));
+
+    let output = sosecrets(
+        &[
+            "seal",
+            "--passphrase-file",
+            passphrase,
+            "--iterations",
+            "1000",
+            "--force",
+            envelope,
+        ],
+        b"crlf secret\r\n",
+    );
+    assert!(output.status.success(), "{}", stderr(&output));
+    let output = sosecrets(&["inspect", envelope], b"");
+    assert!(stdout(&output).contains("length: 11 bytes\n"));
+
+    fs::remove_dir_all(dir).unwrap();
+}
+
+#[test]
+fn test_usage_errors() {
+    let output = sosecrets(&["--help"], b"");
+    assert!(output.status.success());
+    assert!(stdout(&output).starts_with("Usage:"));
+
+    for args in [
+        &[][..],
+        &["unseal"],
+        &["seal"],
+        &["run", "--env", "A=a", "--"],
+    ] {
+        let output = sosecrets(args, b"");
+        assert_eq!(output.status.code(), Some(2), "{:?}", args);
+        assert!(stderr(&output).contains("Usage:"));
+    }
+
+    let output = sosecrets(&["inspect", "/nonexistent/envelope"], b"");
+    assert_eq!(output.status.code(), Some(1));
+}
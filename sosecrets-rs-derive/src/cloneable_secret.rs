@@ -0,0 +1,20 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::DeriveInput;
+
+use crate::common;
+
+pub(crate) fn expand(input: DeriveInput) -> syn::Result<TokenStream> {
+    let bound = quote!(::sosecrets_rs::traits::CloneableSecret);
+    let fields = common::fields(&input, "CloneableSecret")?;
+    let generics = common::add_trait_bounds(input.generics.clone(), &bound);
+    let assertions = common::assert_fields_implement(&generics, &fields, &bound);
+
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    Ok(quote! {
+        #assertions
+
+        impl #impl_generics #bound for #name #ty_generics #where_clause {}
+    })
+}
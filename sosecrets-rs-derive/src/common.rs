@@ -0,0 +1,54 @@
+use proc_macro2::TokenStream;
+use quote::{quote, quote_spanned};
+use syn::{spanned::Spanned, Data, DeriveInput, Field, GenericParam, Generics};
+
+/// Returns all the fields of the struct or of all the variants of the enum.
+pub(crate) fn fields<'a>(input: &'a DeriveInput, derive: &str) -> syn::Result<Vec<&'a Field>> {
+    match &input.data {
+        Data::Struct(data) => Ok(data.fields.iter().collect()),
+        Data::Enum(data) => Ok(data
+            .variants
+            .iter()
+            .flat_map(|variant| variant.fields.iter())
+            .collect()),
+        Data::Union(data) => Err(syn::Error::new(
+            data.union_token.span,
+            format!("`{}` cannot be derived for unions", derive),
+        )),
+    }
+}
+
+/// Bounds every type parameter by `bound`.
+pub(crate) fn add_trait_bounds(mut generics: Generics, bound: &TokenStream) -> Generics {
+    for param in &mut generics.params {
+        if let GenericParam::Type(type_param) = param {
+            type_param.bounds.push(syn::parse_quote!(#bound));
+        }
+    }
+    generics
+}
+
+/// Asserts that the type of every field implements `bound`, the errors are reported at the type of the offending field.
+pub(crate) fn assert_fields_implement(
+    generics: &Generics,
+    fields: &[&Field],
+    bound: &TokenStream,
+) -> TokenStream {
+    let (impl_generics, _, where_clause) = generics.split_for_impl();
+    let assertions = fields.iter().map(|field| {
+        let ty = &field.ty;
+        quote_spanned! {ty.span()=>
+            __sosecrets_assert_field_implements::<#ty>();
+        }
+    });
+    quote! {
+        const _: () = {
+            fn __sosecrets_assert_field_implements<T: ?::core::marker::Sized + #bound>() {}
+
+            #[allow(dead_code, clippy::extra_unused_type_parameters)]
+            fn __sosecrets_assert_fields #impl_generics () #where_clause {
+                #(#assertions)*
+            }
+        };
+    }
+}
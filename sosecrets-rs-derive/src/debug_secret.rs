@@ -0,0 +1,150 @@
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::{Data, DeriveInput, Field, Fields, Ident};
+
+use crate::common;
+
+/// How a field is formatted by the derived `Debug` implementation.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Visibility {
+    /// Formatted with `DebugSecret::debug_secret`, the default.
+    Redact,
+    /// Formatted with `Debug`.
+    Show,
+}
+
+/// Parses the `#[secret(redact)]` and `#[secret(show)]` attributes of a field.
+fn field_visibility(field: &Field) -> syn::Result<Visibility> {
+    let mut visibility = None;
+    for attr in field
+        .attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("secret"))
+    {
+        attr.parse_nested_meta(|meta| {
+            let parsed = if meta.path.is_ident("redact") {
+                Visibility::Redact
+            } else if meta.path.is_ident("show") {
+                Visibility::Show
+            } else {
+                return Err(meta.error("expected `redact` or `show`"));
+            };
+            if visibility.is_some() {
+                return Err(meta.error("`redact` or `show` can only be specified once per field"));
+            }
+            visibility = Some(parsed);
+            Ok(())
+        })?;
+    }
+    Ok(visibility.unwrap_or(Visibility::Redact))
+}
+
+/// Returns the match arm formatting the fields bound by `pattern` with the name `name`.
+fn fmt_arm(pattern: TokenStream, name: &Ident, fields: &Fields) -> syn::Result<TokenStream> {
+    let name = name.to_string();
+    let bindings: Vec<Ident> = (0..fields.len())
+        .map(|index| format_ident!("__sosecrets_field_{}", index))
+        .collect();
+    let values = fields
+        .iter()
+        .zip(&bindings)
+        .map(|(field, binding)| {
+            Ok(match field_visibility(field)? {
+                Visibility::Redact => quote!(&__SosecretsRedacted::new(#binding)),
+                Visibility::Show => quote!(#binding),
+            })
+        })
+        .collect::<syn::Result<Vec<_>>>()?;
+
+    Ok(match fields {
+        Fields::Named(named) => {
+            let idents = named.named.iter().map(|field| &field.ident);
+            let labels = idents
+                .clone()
+                .map(|ident| ident.as_ref().map(ToString::to_string).unwrap_or_default());
+            quote! {
+                #pattern { #(#idents: #bindings),* } => f
+                    .debug_struct(#name)
+                    #(.field(#labels, #values))*
+                    .finish(),
+            }
+        }
+        Fields::Unnamed(_) => quote! {
+            #pattern ( #(#bindings),* ) => f
+                .debug_tuple(#name)
+                #(.field(#values))*
+                .finish(),
+        },
+        Fields::Unit => quote! {
+            #pattern => f.write_str(#name),
+        },
+    })
+}
+
+pub(crate) fn expand(input: DeriveInput) -> syn::Result<TokenStream> {
+    let bound = quote!(::sosecrets_rs::traits::DebugSecret);
+    let fields = common::fields(&input, "DebugSecret")?;
+    // Report the invalid attributes of all fields at once.
+    if let Some(error) = fields
+        .iter()
+        .filter_map(|field| field_visibility(field).err())
+        .reduce(|mut error, other| {
+            error.combine(other);
+            error
+        })
+    {
+        return Err(error);
+    }
+    let generics = common::add_trait_bounds(input.generics.clone(), &bound);
+    let assertions = common::assert_fields_implement(&generics, &fields, &bound);
+
+    let name = &input.ident;
+    let arms = match &input.data {
+        Data::Struct(data) => vec![fmt_arm(quote!(Self), name, &data.fields)?],
+        Data::Enum(data) => data
+            .variants
+            .iter()
+            .map(|variant| {
+                let variant_name = &variant.ident;
+                fmt_arm(quote!(Self::#variant_name), variant_name, &variant.fields)
+            })
+            .collect::<syn::Result<Vec<_>>>()?,
+        Data::Union(_) => unreachable!("unions are rejected by `common::fields`"),
+    };
+    let body = if arms.is_empty() {
+        // An enum without variants cannot be instantiated.
+        quote!(match *self {})
+    } else {
+        quote!(match self { #(#arms)* })
+    };
+
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    Ok(quote! {
+        #assertions
+
+        const _: () = {
+            /// Formats a field with `DebugSecret::debug_secret`.
+            struct __SosecretsRedacted<T>(::core::marker::PhantomData<T>);
+
+            impl<T: #bound> __SosecretsRedacted<T> {
+                fn new(_: &T) -> Self {
+                    Self(::core::marker::PhantomData)
+                }
+            }
+
+            impl<T: #bound> ::core::fmt::Debug for __SosecretsRedacted<T> {
+                fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                    <T as #bound>::debug_secret(f)
+                }
+            }
+
+            impl #impl_generics ::core::fmt::Debug for #name #ty_generics #where_clause {
+                fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                    #body
+                }
+            }
+        };
+
+        impl #impl_generics #bound for #name #ty_generics #where_clause {}
+    })
+}
@@ -0,0 +1,51 @@
+//! Derive macros for the marker traits of [`sosecrets-rs`](https://docs.rs/sosecrets-rs).
+//!
+//! This crate is not meant to be used directly, enable the "derive" feature of `sosecrets-rs` instead;
+//! the derive macros are then re-exported alongside the traits in `sosecrets_rs::traits`, and `#[secret_struct]` in `sosecrets_rs::runtime`.
+
+use proc_macro::TokenStream;
+use syn::{parse_macro_input, DeriveInput};
+
+mod cloneable_secret;
+mod common;
+mod debug_secret;
+mod secret_struct;
+
+/// Derives `sosecrets_rs::traits::CloneableSecret` for a struct or an enum.
+///
+/// Every field must implement `CloneableSecret` too, and every type parameter is bounded by `CloneableSecret`.
+/// The supertraits of `CloneableSecret` (`Clone`, and `Zeroize` with the "zeroize" feature) still have to be implemented separately.
+#[proc_macro_derive(CloneableSecret)]
+pub fn derive_cloneable_secret(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    cloneable_secret::expand(input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// Derives `sosecrets_rs::traits::DebugSecret` and `core::fmt::Debug` for a struct or an enum.
+///
+/// Every field must implement `DebugSecret` too, and every type parameter is bounded by `DebugSecret`.
+/// Fields are formatted with `DebugSecret::debug_secret`, i.e. as "`[REDACTED]`" by default;
+/// annotate a field with `#[secret(show)]` to format it with its `Debug` implementation instead,
+/// `#[secret(redact)]` spells out the default.
+#[proc_macro_derive(DebugSecret, attributes(secret))]
+pub fn derive_debug_secret(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    debug_secret::expand(input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// Rewrites the fields of a struct annotated with `#[secret(max = N)]` to `RTSecret<T, UN>`, and those annotated with `#[secret]` to `SecrecySecret<T>`.
+///
+/// `N` is either an unsigned integer literal, turned into the corresponding `typenum` constant, or a `typenum` type.
+/// A `Debug` implementation is generated for the struct, in which the secret fields are formatted as `sosecrets_rs::traits::REDACTED`;
+/// the other fields must implement `Debug`, and `Debug` must not be derived for the struct.
+#[proc_macro_attribute]
+pub fn secret_struct(args: TokenStream, input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    secret_struct::expand(args.into(), input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
@@ -0,0 +1,190 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{
+    parse::Parser, punctuated::Punctuated, Data, DeriveInput, Fields, Ident, Index, Lit, Meta,
+    Token, Type,
+};
+
+use crate::common;
+
+/// The secret wrapper a field is rewritten to.
+enum Wrapper {
+    /// `SecrecySecret<T>`, written as `#[secret]`.
+    Unbounded,
+    /// `RTSecret<T, MEC>`, written as `#[secret(max = 3)]` or `#[secret(max = typenum::U3)]`.
+    Bounded(Box<Type>),
+}
+
+/// Parses the `max = ...` value, an integer literal is turned into the corresponding `typenum` constant.
+fn parse_max(value: syn::parse::ParseStream) -> syn::Result<Type> {
+    if value.peek(Lit) {
+        match value.parse::<Lit>()? {
+            Lit::Int(lit) => {
+                lit.base10_parse::<u64>()?;
+                let ident = Ident::new(&format!("U{}", lit.base10_digits()), lit.span());
+                Ok(syn::parse_quote!(::sosecrets_rs::prelude::typenum::consts::#ident))
+            }
+            lit => Err(syn::Error::new(
+                lit.span(),
+                "expected an unsigned integer or a `typenum` type",
+            )),
+        }
+    } else {
+        value.parse()
+    }
+}
+
+/// Parses the `#[secret]` and `#[secret(max = ...)]` attributes of a field and removes them.
+fn take_wrapper(attrs: &mut Vec<syn::Attribute>) -> syn::Result<Option<Wrapper>> {
+    let mut wrapper = None;
+    let mut error: Option<syn::Error> = None;
+    let mut push_error = |new: syn::Error| match &mut error {
+        Some(error) => error.combine(new),
+        None => error = Some(new),
+    };
+    attrs.retain(|attr| {
+        if !attr.path().is_ident("secret") {
+            return true;
+        }
+        if wrapper.is_some() {
+            push_error(syn::Error::new_spanned(
+                attr,
+                "`#[secret]` can only be specified once per field",
+            ));
+            return false;
+        }
+        match &attr.meta {
+            Meta::Path(_) => wrapper = Some(Wrapper::Unbounded),
+            _ => {
+                let mut max = None;
+                let parsed = attr.parse_nested_meta(|meta| {
+                    if meta.path.is_ident("max") {
+                        max = Some(parse_max(meta.value()?)?);
+                        Ok(())
+                    } else {
+                        Err(meta.error("expected `max`"))
+                    }
+                });
+                match (parsed, max) {
+                    (Ok(()), Some(max)) => wrapper = Some(Wrapper::Bounded(Box::new(max))),
+                    (Ok(()), None) => wrapper = Some(Wrapper::Unbounded),
+                    (Err(err), _) => push_error(err),
+                }
+            }
+        }
+        false
+    });
+    match error {
+        Some(error) => Err(error),
+        None => Ok(wrapper),
+    }
+}
+
+pub(crate) fn expand(args: TokenStream, mut input: DeriveInput) -> syn::Result<TokenStream> {
+    let args = Punctuated::<Meta, Token![,]>::parse_terminated.parse2(args)?;
+    if let Some(arg) = args.first() {
+        return Err(syn::Error::new_spanned(
+            arg,
+            "`#[secret_struct]` does not take any argument",
+        ));
+    }
+
+    let data = match &mut input.data {
+        Data::Struct(data) => data,
+        Data::Enum(data) => {
+            return Err(syn::Error::new(
+                data.enum_token.span,
+                "`#[secret_struct]` can only be used on structs",
+            ))
+        }
+        Data::Union(data) => {
+            return Err(syn::Error::new(
+                data.union_token.span,
+                "`#[secret_struct]` can only be used on structs",
+            ))
+        }
+    };
+
+    let mut error: Option<syn::Error> = None;
+    let mut is_secret = Vec::with_capacity(data.fields.len());
+    for field in data.fields.iter_mut() {
+        match take_wrapper(&mut field.attrs) {
+            Ok(Some(wrapper)) => {
+                let ty = &field.ty;
+                field.ty = match wrapper {
+                    Wrapper::Unbounded => {
+                        syn::parse_quote!(::sosecrets_rs::runtime::SecrecySecret<#ty>)
+                    }
+                    Wrapper::Bounded(max) => {
+                        syn::parse_quote!(::sosecrets_rs::runtime::RTSecret<#ty, #max>)
+                    }
+                };
+                is_secret.push(true);
+            }
+            Ok(None) => is_secret.push(false),
+            Err(new) => match &mut error {
+                Some(error) => error.combine(new),
+                None => error = Some(new),
+            },
+        }
+    }
+    if let Some(error) = error {
+        return Err(error);
+    }
+
+    let name = &input.ident;
+    let name_str = name.to_string();
+    let values =
+        data.fields
+            .iter()
+            .zip(&is_secret)
+            .enumerate()
+            .map(|(index, (field, is_secret))| {
+                let member = match &field.ident {
+                    Some(ident) => quote!(#ident),
+                    None => {
+                        let index = Index::from(index);
+                        quote!(#index)
+                    }
+                };
+                if *is_secret {
+                    quote!(&::core::format_args!(
+                        "{}",
+                        ::sosecrets_rs::traits::REDACTED
+                    ))
+                } else {
+                    quote!(&self.#member)
+                }
+            });
+    let body = match &data.fields {
+        Fields::Named(named) => {
+            let labels = named
+                .named
+                .iter()
+                .map(|field| field.ident.as_ref().map(ToString::to_string));
+            quote! {
+                f.debug_struct(#name_str)
+                    #(.field(#labels, #values))*
+                    .finish()
+            }
+        }
+        Fields::Unnamed(_) => quote! {
+            f.debug_tuple(#name_str)
+                #(.field(#values))*
+                .finish()
+        },
+        Fields::Unit => quote!(f.write_str(#name_str)),
+    };
+
+    let generics = common::add_trait_bounds(input.generics.clone(), &quote!(::core::fmt::Debug));
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    Ok(quote! {
+        #input
+
+        impl #impl_generics ::core::fmt::Debug for #name #ty_generics #where_clause {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                #body
+            }
+        }
+    })
+}
@@ -0,0 +1,59 @@
+//! Containers for secret text and bytes.
+//!
+//! They are meant to be built up, e.g. while reading a secret, and then wrapped in
+//! [`Secret`](crate::prelude::Secret), [`RTSecret`](crate::prelude::RTSecret) or [`SecrecySecret`](crate::prelude::SecrecySecret),
+//! whose `expose_secret_mut` methods also allow appending to them in place.
+//!
+//! - [`SecretArray<N>`](SecretArray) stores up to `N` bytes inline, without allocating, for `no_std` targets without `alloc`.
+//! - [`SecretString`] and [`SecretBytes`] (with the "alloc" feature) are heap-allocated; unlike `String` and `Vec<u8>`,
+//!   they never leave copies of the secret behind in freed memory: when they grow, the new buffer is allocated, the secret is copied into it,
+//!   and the old buffer is zeroized before it is freed; when they are cleared or dropped, their whole buffer, including the spare capacity, is zeroized.
+//! - [`ZeroizedOnDrop<T>`](ZeroizedOnDrop) (with the "zeroize" feature) wraps a value which zeroizes itself when it is dropped,
+//!   e.g. [`zeroize::Zeroizing<T>`], so that it is zeroized exactly once.
+//! - [`ProtectedBytes`] (with the "memfd-secret" feature, on Linux on x86-64, AArch64 and RISC-V 64) keeps secret bytes in a `memfd_secret(2)` mapping,
+//!   hidden from the kernel's direct map, or, where that is not available, in an `mlock(2)`ed mapping.
+//!
+//! Example:
+//! ```rust
+//! #[cfg(feature = "alloc")]
+//! {
+//!     use sosecrets_rs::{
+//!         containers::SecretString,
+//!         prelude::{typenum::U2, RTSecret},
+//!         runtime::traits::RTExposeSecret,
+//!     };
+//!
+//!     let mut password = SecretString::new();
+//!     for chunk in ["hunter", "2"] {
+//!         password.push_str(chunk);
+//!     }
+//!     let password = RTSecret::<_, U2>::new(password);
+//!     assert_eq!(password.expose_secret(|exposed_secret| exposed_secret.len()), 7);
+//! }
+//! ```
+
+mod array;
+pub use array::*;
+
+#[cfg(feature = "alloc")]
+mod heap;
+#[cfg(feature = "alloc")]
+pub use heap::*;
+
+#[cfg(feature = "zeroize")]
+mod on_drop;
+#[cfg(feature = "zeroize")]
+pub use on_drop::*;
+
+#[cfg(all(
+    feature = "memfd-secret",
+    target_os = "linux",
+    any(target_arch = "x86_64", target_arch = "aarch64", target_arch = "riscv64")
+))]
+mod protected;
+#[cfg(all(
+    feature = "memfd-secret",
+    target_os = "linux",
+    any(target_arch = "x86_64", target_arch = "aarch64", target_arch = "riscv64")
+))]
+pub use protected::*;
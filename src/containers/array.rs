@@ -0,0 +1,255 @@
+use core::fmt;
+
+#[cfg(feature = "zeroize")]
+use zeroize::Zeroize;
+
+/// The error returned when appending to a [`SecretArray`] beyond its capacity, in which case the `SecretArray` is left unchanged.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CapacityError {
+    /// The capacity of the `SecretArray`, in bytes.
+    pub capacity: usize,
+    /// The number of bytes the `SecretArray` would have needed to hold.
+    pub required: usize,
+}
+
+impl fmt::Display for CapacityError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "`SecretArray` cannot hold {} bytes, its capacity is {} bytes.",
+            self.required, self.capacity
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for CapacityError {}
+
+/// Overwrites `bytes` with zeroes, with `zeroize` if the "zeroize" feature is enabled.
+#[inline(always)]
+fn zeroize_bytes(bytes: &mut [u8]) {
+    #[cfg(feature = "zeroize")]
+    bytes.zeroize();
+    #[cfg(not(feature = "zeroize"))]
+    bytes.fill(0);
+}
+
+/// A fixed-capacity buffer of up to `N` secret bytes, stored inline without allocating, e.g. for passwords on `no_std` targets without `alloc`.
+///
+/// It tracks its length, and the bytes beyond it are always zero: removed bytes are overwritten when it is truncated or cleared,
+/// and, with the "zeroize" feature, its whole buffer is zeroized when it is dropped.
+/// Its `Debug` implementation never shows the bytes.
+///
+/// Example:
+/// ```rust
+/// use sosecrets_rs::{
+///     containers::SecretArray,
+///     prelude::{typenum::U2, RTSecret},
+///     runtime::traits::RTExposeSecret,
+/// };
+///
+/// let mut password = RTSecret::<SecretArray<64>, U2>::new(SecretArray::new());
+/// // Appends in place, without copying the secret out of the `RTSecret`.
+/// password
+///     .expose_secret_mut(|mut exposed_secret| exposed_secret.try_push_str("hunter2"))
+///     .unwrap();
+/// assert_eq!(password.expose_secret(|exposed_secret| exposed_secret.len()), 7);
+/// ```
+pub struct SecretArray<const N: usize> {
+    bytes: [u8; N],
+    len: usize,
+}
+
+impl<const N: usize> SecretArray<N> {
+    /// The capacity of a `SecretArray<N>`, i.e. `N` bytes.
+    pub const CAPACITY: usize = N;
+
+    /// Creates a new empty `SecretArray`.
+    pub const fn new() -> Self {
+        Self {
+            bytes: [0; N],
+            len: 0,
+        }
+    }
+
+    /// Creates a new `SecretArray` holding a copy of `bytes`.
+    ///
+    /// # Errors
+    /// If `bytes` is longer than `N`.
+    pub fn from_slice(bytes: &[u8]) -> Result<Self, CapacityError> {
+        let mut array = Self::new();
+        array.try_extend_from_slice(bytes)?;
+        Ok(array)
+    }
+
+    /// Returns the number of bytes in the `SecretArray`.
+    #[inline(always)]
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if the `SecretArray` is empty.
+    #[inline(always)]
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns the capacity of the `SecretArray`, i.e. `N` bytes.
+    #[inline(always)]
+    pub const fn capacity(&self) -> usize {
+        N
+    }
+
+    /// Returns the number of bytes that can still be appended to the `SecretArray`.
+    #[inline(always)]
+    pub const fn remaining_capacity(&self) -> usize {
+        N - self.len
+    }
+
+    /// Returns the bytes of the `SecretArray`.
+    #[inline(always)]
+    pub fn as_slice(&self) -> &[u8] {
+        &self.bytes[..self.len]
+    }
+
+    /// Returns the bytes of the `SecretArray`, mutably.
+    #[inline(always)]
+    pub fn as_mut_slice(&mut self) -> &mut [u8] {
+        &mut self.bytes[..self.len]
+    }
+
+    /// Returns the bytes of the `SecretArray` as text, or `None` if they are not valid UTF-8.
+    #[inline(always)]
+    pub fn as_str(&self) -> Option<&str> {
+        core::str::from_utf8(self.as_slice()).ok()
+    }
+
+    /// Appends `bytes` to the `SecretArray`.
+    ///
+    /// # Errors
+    /// If the `SecretArray` cannot hold `bytes`, in which case it is left unchanged.
+    pub fn try_extend_from_slice(&mut self, bytes: &[u8]) -> Result<(), CapacityError> {
+        if bytes.len() > self.remaining_capacity() {
+            return Err(CapacityError {
+                capacity: N,
+                required: self.len.saturating_add(bytes.len()),
+            });
+        }
+        self.bytes[self.len..self.len + bytes.len()].copy_from_slice(bytes);
+        self.len += bytes.len();
+        Ok(())
+    }
+
+    /// Appends the UTF-8 bytes of `string` to the `SecretArray`.
+    ///
+    /// # Errors
+    /// If the `SecretArray` cannot hold `string`, in which case it is left unchanged.
+    pub fn try_push_str(&mut self, string: &str) -> Result<(), CapacityError> {
+        self.try_extend_from_slice(string.as_bytes())
+    }
+
+    /// Appends `byte` to the `SecretArray`.
+    ///
+    /// # Errors
+    /// If the `SecretArray` is full, in which case it is left unchanged.
+    pub fn try_push(&mut self, byte: u8) -> Result<(), CapacityError> {
+        self.try_extend_from_slice(&[byte])
+    }
+
+    /// Shortens the `SecretArray` to `len` bytes and overwrites the removed bytes with zeroes.
+    /// It has no effect if `len` is not less than the current length.
+    pub fn truncate(&mut self, len: usize) {
+        if len < self.len {
+            zeroize_bytes(&mut self.bytes[len..self.len]);
+            self.len = len;
+        }
+    }
+
+    /// Overwrites the bytes of the `SecretArray` with zeroes and empties it.
+    pub fn clear(&mut self) {
+        self.truncate(0)
+    }
+}
+
+crate::macros::impl_appendable_secret! {
+    impl<const N: usize> for SecretArray<N> {
+        /// Appends `bytes` to the exposed `SecretArray`, see [`SecretArray::try_extend_from_slice`].
+        fn try_extend_from_slice(&mut self, bytes: &[u8]) -> Result<(), CapacityError>;
+        /// Appends the UTF-8 bytes of `string` to the exposed `SecretArray`, see [`SecretArray::try_push_str`].
+        fn try_push_str(&mut self, string: &str) -> Result<(), CapacityError>;
+        /// Appends `byte` to the exposed `SecretArray`, see [`SecretArray::try_push`].
+        fn try_push(&mut self, byte: u8) -> Result<(), CapacityError>;
+        /// Returns the bytes of the exposed `SecretArray`, mutably, see [`SecretArray::as_mut_slice`].
+        fn as_mut_slice(&mut self) -> &mut [u8];
+        /// Shortens the exposed `SecretArray` to `len` bytes, see [`SecretArray::truncate`].
+        fn truncate(&mut self, len: usize);
+        /// Overwrites the bytes of the exposed `SecretArray` with zeroes and empties it, see [`SecretArray::clear`].
+        fn clear(&mut self);
+    }
+}
+
+impl<const N: usize> Default for SecretArray<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const N: usize> Clone for SecretArray<N> {
+    fn clone(&self) -> Self {
+        Self {
+            bytes: self.bytes,
+            len: self.len,
+        }
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl<const N: usize> Zeroize for SecretArray<N> {
+    fn zeroize(&mut self) {
+        self.bytes.zeroize();
+        self.len = 0;
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl<const N: usize> Drop for SecretArray<N> {
+    /// Zeroizes the whole buffer of the `SecretArray`.
+    fn drop(&mut self) {
+        self.zeroize()
+    }
+}
+
+impl<const N: usize> fmt::Debug for SecretArray<N> {
+    /// Formats the `SecretArray` as "`SecretArray([REDACTED])`".
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "SecretArray({})", crate::traits::REDACTED)
+    }
+}
+
+#[cfg(feature = "cloneable-secret")]
+impl<const N: usize> crate::traits::CloneableSecret for SecretArray<N> {}
+
+#[cfg(feature = "debug-secret")]
+impl<const N: usize> crate::traits::DebugSecret for SecretArray<N> {
+    fn debug_secret_byte_len(&self) -> Option<usize> {
+        Some(self.len)
+    }
+}
+
+#[cfg(feature = "fingerprint")]
+impl<const N: usize> crate::traits::FingerprintSecret for SecretArray<N> {
+    fn fingerprint_bytes(&self) -> &[u8] {
+        self.as_slice()
+    }
+}
+
+#[cfg(feature = "sealed")]
+impl<const N: usize> crate::traits::SealableSecret for SecretArray<N> {
+    fn sealable_bytes(&self) -> &[u8] {
+        self.as_slice()
+    }
+
+    fn from_unsealed_bytes(bytes: &[u8]) -> Self {
+        Self::from_slice(bytes).expect("the sealed bytes of a `SecretArray<N>` are at most `N` bytes long")
+    }
+}
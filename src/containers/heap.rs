@@ -0,0 +1,400 @@
+use alloc::{string::String, vec::Vec};
+use core::fmt;
+
+use zeroize::Zeroize;
+
+#[cfg(feature = "rand-core")]
+use rand_core::CryptoRngCore;
+
+/// The minimal capacity allocated when a container grows, as in `Vec`.
+const MIN_NON_ZERO_CAPACITY: usize = 8;
+
+/// Returns the capacity to grow to for `additional` more bytes, at least twice the current `capacity` as in `Vec`.
+fn grown_capacity(len: usize, capacity: usize, additional: usize) -> usize {
+    let required = len.checked_add(additional).expect("capacity overflow");
+    required
+        .max(capacity.saturating_mul(2))
+        .max(MIN_NON_ZERO_CAPACITY)
+}
+
+/// Ensures that `bytes` can hold `additional` more bytes, moving them to a larger buffer and zeroizing the old one if needed.
+fn reserve_zeroizing(bytes: &mut Vec<u8>, additional: usize) {
+    if bytes.capacity() - bytes.len() >= additional {
+        return;
+    }
+    let mut grown = Vec::with_capacity(grown_capacity(bytes.len(), bytes.capacity(), additional));
+    grown.extend_from_slice(bytes);
+    // Zeroizes the elements and the spare capacity of the old buffer before it is freed.
+    bytes.zeroize();
+    *bytes = grown;
+}
+
+/// A `String` which zeroizes its buffer when it grows, is cleared or is dropped, see the [module documentation](crate::containers).
+///
+/// Its `Debug` implementation never shows the text.
+#[derive(Default)]
+pub struct SecretString(String);
+
+impl SecretString {
+    /// Creates a new empty `SecretString`, which does not allocate.
+    pub const fn new() -> Self {
+        Self(String::new())
+    }
+
+    /// Creates a new empty `SecretString` with a capacity of at least `capacity` bytes.
+    /// Reserving the final capacity upfront avoids any reallocation.
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self(String::with_capacity(capacity))
+    }
+
+    /// Returns the length of the `SecretString` in bytes.
+    #[inline(always)]
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Returns `true` if the `SecretString` is empty.
+    #[inline(always)]
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Returns the capacity of the `SecretString` in bytes.
+    #[inline(always)]
+    pub fn capacity(&self) -> usize {
+        self.0.capacity()
+    }
+
+    /// Returns the text of the `SecretString`.
+    #[inline(always)]
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Returns the bytes of the text of the `SecretString`.
+    #[inline(always)]
+    pub fn as_bytes(&self) -> &[u8] {
+        self.0.as_bytes()
+    }
+
+    /// Reserves capacity for at least `additional` more bytes, zeroizing the previous buffer if it has to be reallocated.
+    pub fn reserve(&mut self, additional: usize) {
+        // SAFETY: Only bytes of the `String` are moved to the new buffer, so it remains valid UTF-8.
+        reserve_zeroizing(unsafe { self.0.as_mut_vec() }, additional)
+    }
+
+    /// Appends `string` to the `SecretString`, zeroizing the previous buffer if it has to be reallocated.
+    pub fn push_str(&mut self, string: &str) {
+        self.reserve(string.len());
+        self.0.push_str(string)
+    }
+
+    /// Appends `ch` to the `SecretString`, zeroizing the previous buffer if it has to be reallocated.
+    pub fn push(&mut self, ch: char) {
+        self.reserve(ch.len_utf8());
+        self.0.push(ch)
+    }
+
+    /// Zeroizes and clears the `SecretString`, keeping its capacity.
+    pub fn clear(&mut self) {
+        self.0.zeroize()
+    }
+}
+
+crate::macros::impl_appendable_secret! {
+    impl for SecretString {
+        /// Reserves capacity in the exposed `SecretString`, see [`SecretString::reserve`].
+        fn reserve(&mut self, additional: usize);
+        /// Appends `string` to the exposed `SecretString`, see [`SecretString::push_str`].
+        fn push_str(&mut self, string: &str);
+        /// Appends `ch` to the exposed `SecretString`, see [`SecretString::push`].
+        fn push(&mut self, ch: char);
+        /// Zeroizes and clears the exposed `SecretString`, see [`SecretString::clear`].
+        fn clear(&mut self);
+    }
+}
+
+impl From<String> for SecretString {
+    /// Takes ownership of the buffer of `string`, without copying it.
+    fn from(string: String) -> Self {
+        Self(string)
+    }
+}
+
+impl From<&str> for SecretString {
+    fn from(string: &str) -> Self {
+        Self(String::from(string))
+    }
+}
+
+impl Clone for SecretString {
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+}
+
+impl Zeroize for SecretString {
+    fn zeroize(&mut self) {
+        self.0.zeroize()
+    }
+}
+
+impl Drop for SecretString {
+    /// Zeroizes the whole buffer of the `SecretString`.
+    fn drop(&mut self) {
+        self.0.zeroize()
+    }
+}
+
+impl fmt::Debug for SecretString {
+    /// Formats the `SecretString` as "`SecretString([REDACTED])`".
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "SecretString({})", crate::traits::REDACTED)
+    }
+}
+
+/// A `Vec<u8>` which zeroizes its buffer when it grows, is cleared or is dropped, see the [module documentation](crate::containers).
+///
+/// Its `Debug` implementation never shows the bytes.
+#[derive(Default)]
+pub struct SecretBytes(Vec<u8>);
+
+impl SecretBytes {
+    /// Creates a new empty `SecretBytes`, which does not allocate.
+    pub const fn new() -> Self {
+        Self(Vec::new())
+    }
+
+    /// Creates a new empty `SecretBytes` with a capacity of at least `capacity` bytes.
+    /// Reserving the final capacity upfront avoids any reallocation.
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self(Vec::with_capacity(capacity))
+    }
+
+    /// Returns the number of bytes in the `SecretBytes`.
+    #[inline(always)]
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Returns `true` if the `SecretBytes` is empty.
+    #[inline(always)]
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Returns the capacity of the `SecretBytes` in bytes.
+    #[inline(always)]
+    pub fn capacity(&self) -> usize {
+        self.0.capacity()
+    }
+
+    /// Returns the bytes of the `SecretBytes`.
+    #[inline(always)]
+    pub fn as_slice(&self) -> &[u8] {
+        &self.0
+    }
+
+    /// Reserves capacity for at least `additional` more bytes, zeroizing the previous buffer if it has to be reallocated.
+    pub fn reserve(&mut self, additional: usize) {
+        reserve_zeroizing(&mut self.0, additional)
+    }
+
+    /// Appends `bytes` to the `SecretBytes`, zeroizing the previous buffer if it has to be reallocated.
+    pub fn extend_from_slice(&mut self, bytes: &[u8]) {
+        self.reserve(bytes.len());
+        self.0.extend_from_slice(bytes)
+    }
+
+    /// Appends `byte` to the `SecretBytes`, zeroizing the previous buffer if it has to be reallocated.
+    pub fn push(&mut self, byte: u8) {
+        self.reserve(1);
+        self.0.push(byte)
+    }
+
+    /// Zeroizes and clears the `SecretBytes`, keeping its capacity.
+    pub fn clear(&mut self) {
+        self.0.zeroize()
+    }
+
+    /// Creates a new `SecretBytes` holding `len` random bytes from the cryptographically secure `rng`,
+    /// which writes them straight into the buffer of the `SecretBytes`.
+    ///
+    /// Example:
+    /// ```rust
+    /// #[cfg(feature = "getrandom")]
+    /// {
+    ///     use sosecrets_rs::{
+    ///         containers::SecretBytes,
+    ///         prelude::{rand_core::OsRng, typenum::U1, RTSecret},
+    ///     };
+    ///
+    ///     let session_token = RTSecret::<_, U1>::new(SecretBytes::generate_with(64, &mut OsRng));
+    /// }
+    /// ```
+    ///
+    /// # Panics
+    /// If `rng` fails, like [`RngCore::fill_bytes`](rand_core::RngCore::fill_bytes).
+    #[cfg(feature = "rand-core")]
+    pub fn generate_with<R: CryptoRngCore + ?Sized>(len: usize, rng: &mut R) -> Self {
+        let mut bytes = Self(alloc::vec![0; len]);
+        rng.fill_bytes(&mut bytes.0);
+        bytes
+    }
+
+    /// Tries to create a new `SecretBytes` holding `len` random bytes from the cryptographically secure `rng`, see [`SecretBytes::generate_with`].
+    ///
+    /// # Errors
+    /// If `rng` fails; the bytes it has already written are zeroized.
+    #[cfg(feature = "rand-core")]
+    pub fn try_generate_with<R: CryptoRngCore + ?Sized>(
+        len: usize,
+        rng: &mut R,
+    ) -> Result<Self, rand_core::Error> {
+        let mut bytes = Self(alloc::vec![0; len]);
+        rng.try_fill_bytes(&mut bytes.0)?;
+        Ok(bytes)
+    }
+
+    /// Creates a new `SecretBytes` holding `len` random bytes from the random number generator of the operating system, see [`SecretBytes::generate_with`].
+    ///
+    /// # Panics
+    /// If the random number generator of the operating system fails.
+    #[cfg(feature = "getrandom")]
+    pub fn generate(len: usize) -> Self {
+        Self::generate_with(len, &mut rand_core::OsRng)
+    }
+}
+
+crate::macros::impl_appendable_secret! {
+    impl for SecretBytes {
+        /// Reserves capacity in the exposed `SecretBytes`, see [`SecretBytes::reserve`].
+        fn reserve(&mut self, additional: usize);
+        /// Appends `bytes` to the exposed `SecretBytes`, see [`SecretBytes::extend_from_slice`].
+        fn extend_from_slice(&mut self, bytes: &[u8]);
+        /// Appends `byte` to the exposed `SecretBytes`, see [`SecretBytes::push`].
+        fn push(&mut self, byte: u8);
+        /// Zeroizes and clears the exposed `SecretBytes`, see [`SecretBytes::clear`].
+        fn clear(&mut self);
+    }
+}
+
+impl From<Vec<u8>> for SecretBytes {
+    /// Takes ownership of the buffer of `bytes`, without copying it.
+    fn from(bytes: Vec<u8>) -> Self {
+        Self(bytes)
+    }
+}
+
+impl From<&[u8]> for SecretBytes {
+    fn from(bytes: &[u8]) -> Self {
+        Self(Vec::from(bytes))
+    }
+}
+
+impl Clone for SecretBytes {
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+}
+
+impl Zeroize for SecretBytes {
+    fn zeroize(&mut self) {
+        self.0.zeroize()
+    }
+}
+
+impl Drop for SecretBytes {
+    /// Zeroizes the whole buffer of the `SecretBytes`.
+    fn drop(&mut self) {
+        self.0.zeroize()
+    }
+}
+
+impl fmt::Debug for SecretBytes {
+    /// Formats the `SecretBytes` as "`SecretBytes([REDACTED])`".
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "SecretBytes({})", crate::traits::REDACTED)
+    }
+}
+
+#[cfg(feature = "cloneable-secret")]
+impl crate::traits::CloneableSecret for SecretString {}
+
+#[cfg(feature = "cloneable-secret")]
+impl crate::traits::CloneableSecret for SecretBytes {}
+
+#[cfg(feature = "debug-secret")]
+impl crate::traits::DebugSecret for SecretString {
+    fn debug_secret_byte_len(&self) -> Option<usize> {
+        Some(self.len())
+    }
+}
+
+#[cfg(feature = "debug-secret")]
+impl crate::traits::DebugSecret for SecretBytes {
+    fn debug_secret_byte_len(&self) -> Option<usize> {
+        Some(self.len())
+    }
+}
+
+#[cfg(feature = "display-secret")]
+impl crate::traits::DisplaySecret for SecretString {
+    fn display_secret_str(&self) -> &str {
+        self.as_str()
+    }
+}
+
+#[cfg(feature = "fingerprint")]
+impl crate::traits::FingerprintSecret for SecretString {
+    fn fingerprint_bytes(&self) -> &[u8] {
+        self.as_bytes()
+    }
+}
+
+#[cfg(feature = "fingerprint")]
+impl crate::traits::FingerprintSecret for SecretBytes {
+    fn fingerprint_bytes(&self) -> &[u8] {
+        self.as_slice()
+    }
+}
+
+#[cfg(feature = "sealed")]
+impl crate::traits::SealableSecret for SecretString {
+    fn sealable_bytes(&self) -> &[u8] {
+        self.as_bytes()
+    }
+
+    fn from_unsealed_bytes(bytes: &[u8]) -> Self {
+        Self::from(core::str::from_utf8(bytes).expect("the sealed bytes of a `SecretString` are valid UTF-8"))
+    }
+}
+
+#[cfg(feature = "sealed")]
+impl crate::traits::SealableSecret for SecretBytes {
+    fn sealable_bytes(&self) -> &[u8] {
+        self.as_slice()
+    }
+
+    fn from_unsealed_bytes(bytes: &[u8]) -> Self {
+        Self::from(bytes)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for SecretString {
+    /// Deserializes a `SecretString` from a string.
+    /// Note that the deserializer may keep its own copies of the input.
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        String::deserialize(deserializer).map(Self)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for SecretBytes {
+    /// Deserializes a `SecretBytes` from a sequence of bytes.
+    /// Note that the deserializer may keep its own copies of the input.
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Vec::<u8>::deserialize(deserializer).map(Self)
+    }
+}
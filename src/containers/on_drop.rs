@@ -0,0 +1,91 @@
+use core::fmt;
+
+use zeroize::{Zeroize, ZeroizeOnDrop};
+
+/// A wrapper for a value which zeroizes itself when it is dropped, i.e. implementing [`ZeroizeOnDrop`],
+/// such as [`zeroize::Zeroizing<T>`] or a type with `#[derive(ZeroizeOnDrop)]`, so that it can be kept in a
+/// [`Secret`](crate::prelude::Secret), an [`RTSecret`](crate::prelude::RTSecret) or a [`SecrecySecret`](crate::prelude::SecrecySecret).
+///
+/// These require their secret value to implement `Zeroize`, and zeroize it when they are dropped,
+/// after which a `ZeroizeOnDrop` value would zeroize itself once more when it is dropped.
+/// Instead, zeroizing a `ZeroizedOnDrop` drops the wrapped value in place, which is then zeroized exactly once, by itself.
+/// It also allows wrapping `ZeroizeOnDrop` types which do not implement `Zeroize`.
+///
+/// The wrapped value is only accessible through [`ZeroizedOnDrop::get`] and [`ZeroizedOnDrop::get_mut`], which return `None` once it has been zeroized.
+/// Its `Debug` implementation never shows the wrapped value.
+///
+/// Example:
+/// ```rust
+/// use sosecrets_rs::{
+///     containers::ZeroizedOnDrop,
+///     prelude::{typenum::U1, ZeroizingRTSecret},
+///     runtime::traits::RTExposeSecret,
+/// };
+/// use zeroize::Zeroizing;
+///
+/// let secret = ZeroizingRTSecret::<_, U1>::new(ZeroizedOnDrop::new(Zeroizing::new([1u8, 2, 3, 4])));
+/// assert_eq!(secret.expose_secret(|exposed_secret| exposed_secret.get().map(|value| value[0])), Some(1));
+/// // The `Zeroizing<[u8; 4]>` has been dropped, and thereby zeroized, right after its last allowed exposure.
+/// assert!(secret.try_expose_secret(|exposed_secret| exposed_secret.is_zeroized()).is_err());
+/// ```
+pub struct ZeroizedOnDrop<T: ZeroizeOnDrop>(Option<T>);
+
+impl<T: ZeroizeOnDrop> ZeroizedOnDrop<T> {
+    /// Creates a new `ZeroizedOnDrop` wrapping `value`.
+    pub const fn new(value: T) -> Self {
+        Self(Some(value))
+    }
+
+    /// Returns the wrapped value, or `None` if it has been zeroized.
+    #[inline(always)]
+    pub fn get(&self) -> Option<&T> {
+        self.0.as_ref()
+    }
+
+    /// Returns the wrapped value mutably, or `None` if it has been zeroized.
+    #[inline(always)]
+    pub fn get_mut(&mut self) -> Option<&mut T> {
+        self.0.as_mut()
+    }
+
+    /// Returns `true` if the wrapped value has been zeroized, i.e. dropped.
+    #[inline(always)]
+    pub fn is_zeroized(&self) -> bool {
+        self.0.is_none()
+    }
+}
+
+impl<T: ZeroizeOnDrop> From<T> for ZeroizedOnDrop<T> {
+    fn from(value: T) -> Self {
+        Self::new(value)
+    }
+}
+
+impl<T: ZeroizeOnDrop + Clone> Clone for ZeroizedOnDrop<T> {
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+}
+
+impl<T: ZeroizeOnDrop> Zeroize for ZeroizedOnDrop<T> {
+    /// Drops the wrapped value in place, which zeroizes itself.
+    fn zeroize(&mut self) {
+        // The assignment drops the previous value where it is stored, rather than a moved-out copy of it.
+        self.0 = None;
+    }
+}
+
+impl<T: ZeroizeOnDrop> ZeroizeOnDrop for ZeroizedOnDrop<T> {}
+
+impl<T: ZeroizeOnDrop> fmt::Debug for ZeroizedOnDrop<T> {
+    /// Formats the `ZeroizedOnDrop` as "`ZeroizedOnDrop([REDACTED])`".
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "ZeroizedOnDrop({})", crate::traits::REDACTED)
+    }
+}
+
+#[cfg(feature = "cloneable-secret")]
+impl<T: ZeroizeOnDrop + Clone> crate::traits::CloneableSecret for ZeroizedOnDrop<T> {}
+
+#[cfg(feature = "debug-secret")]
+impl<T: ZeroizeOnDrop> crate::traits::DebugSecret for ZeroizedOnDrop<T> {}
@@ -0,0 +1,257 @@
+use core::{ffi::c_void, fmt, ptr::NonNull};
+use std::{
+    io,
+    os::unix::io::{AsRawFd, FromRawFd, OwnedFd},
+};
+
+use zeroize::Zeroize;
+
+/// The kind of memory holding the bytes of a [`ProtectedBytes`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ProtectionMode {
+    /// A `memfd_secret(2)` mapping (Linux 5.14+), which is removed from the kernel's direct map,
+    /// so that neither the kernel nor other processes can read it, and which is never swapped out.
+    MemfdSecret,
+    /// An anonymous private mapping locked with `mlock(2)`, so that it is never swapped out, excluded from core dumps and, with `MADV_WIPEONFORK`, zeroed in forked children.
+    /// It is only used if `memfd_secret(2)` is not available, i.e. if it fails with `ENOSYS` (not implemented or disabled by the kernel),
+    /// `EPERM` (e.g. denied by a seccomp filter) or `EINVAL`.
+    Mlock,
+}
+
+impl fmt::Display for ProtectionMode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::MemfdSecret => f.write_str("memfd_secret"),
+            Self::Mlock => f.write_str("mlock"),
+        }
+    }
+}
+
+/// A fixed-length buffer of secret bytes kept outside of the heap, in a `memfd_secret(2)` mapping if the kernel supports it,
+/// and otherwise in an `mlock(2)`ed anonymous mapping; see [`ProtectionMode`].
+///
+/// Wrapped in an [`RTSecret`](crate::prelude::RTSecret) or a [`SecrecySecret`](crate::prelude::SecrecySecret), it is exposed like any other secret value,
+/// and `protection_mode()` reports which kind of memory holds it without exposing it.
+/// The whole mapping is zeroized before it is unmapped, and its `Debug` implementation never shows the bytes.
+///
+/// Example:
+/// ```rust
+/// use sosecrets_rs::{
+///     containers::{ProtectedBytes, ProtectionMode},
+///     prelude::{typenum::U2, RTSecret},
+///     runtime::traits::RTExposeSecret,
+/// };
+///
+/// let secret = RTSecret::<_, U2>::new(ProtectedBytes::from_slice(b"hunter2").unwrap());
+/// assert!(matches!(
+///     secret.protection_mode(),
+///     ProtectionMode::MemfdSecret | ProtectionMode::Mlock
+/// ));
+/// assert_eq!(secret.expose_secret(|exposed_secret| exposed_secret.len()), 7);
+/// ```
+pub struct ProtectedBytes {
+    ptr: NonNull<u8>,
+    len: usize,
+    map_len: usize,
+    mode: ProtectionMode,
+}
+
+// SAFETY: `ProtectedBytes` uniquely owns its mapping, like `Box<[u8]>` owns its allocation.
+unsafe impl Send for ProtectedBytes {}
+
+// SAFETY: `ProtectedBytes` only hands out `&[u8]` through `&self`.
+unsafe impl Sync for ProtectedBytes {}
+
+impl ProtectedBytes {
+    /// Creates a new `ProtectedBytes` of `len` zero bytes, to be filled in place, e.g. with `RTSecret::expose_secret_mut`.
+    ///
+    /// # Errors
+    /// If the `memfd_secret(2)` mapping cannot be created for another reason than `memfd_secret(2)` being unavailable, e.g. `EMFILE` or `ENOMEM`,
+    /// or if `memfd_secret(2)` is unavailable and the `mlock(2)`ed mapping cannot be created, e.g. because `RLIMIT_MEMLOCK` is exceeded.
+    pub fn zeroed(len: usize) -> io::Result<Self> {
+        let map_len = page_aligned(len)?;
+        let (ptr, mode) = match memfd_secret() {
+            Ok(fd) => (map_memfd_secret(&fd, map_len)?, ProtectionMode::MemfdSecret),
+            Err(error)
+                if matches!(
+                    error.raw_os_error(),
+                    Some(libc::ENOSYS | libc::EPERM | libc::EINVAL)
+                ) =>
+            {
+                (map_mlocked(map_len)?, ProtectionMode::Mlock)
+            }
+            Err(error) => return Err(error),
+        };
+        Ok(Self {
+            ptr,
+            len,
+            map_len,
+            mode,
+        })
+    }
+
+    /// Creates a new `ProtectedBytes` holding a copy of `bytes`.
+    ///
+    /// # Errors
+    /// See [`ProtectedBytes::zeroed`].
+    pub fn from_slice(bytes: &[u8]) -> io::Result<Self> {
+        let mut protected = Self::zeroed(bytes.len())?;
+        protected.as_mut_slice().copy_from_slice(bytes);
+        Ok(protected)
+    }
+
+    /// Returns the kind of memory holding the bytes.
+    #[inline(always)]
+    pub const fn mode(&self) -> ProtectionMode {
+        self.mode
+    }
+
+    /// Returns the number of bytes.
+    #[inline(always)]
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if there are no bytes.
+    #[inline(always)]
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns the bytes.
+    #[inline(always)]
+    pub fn as_slice(&self) -> &[u8] {
+        // SAFETY: The mapping is readable, at least `len` bytes long and lives as long as `self`.
+        unsafe { core::slice::from_raw_parts(self.ptr.as_ptr(), self.len) }
+    }
+
+    /// Returns the bytes, mutably.
+    #[inline(always)]
+    pub fn as_mut_slice(&mut self) -> &mut [u8] {
+        // SAFETY: The mapping is writable, at least `len` bytes long, lives as long as `self` and is borrowed mutably.
+        unsafe { core::slice::from_raw_parts_mut(self.ptr.as_ptr(), self.len) }
+    }
+}
+
+crate::macros::impl_appendable_secret! {
+    impl for ProtectedBytes {
+        /// Returns the bytes of the exposed `ProtectedBytes`, mutably, to fill them in place, see [`ProtectedBytes::as_mut_slice`].
+        fn as_mut_slice(&mut self) -> &mut [u8];
+    }
+}
+
+/// Rounds `len` up to a non-zero multiple of the page size.
+fn page_aligned(len: usize) -> io::Result<usize> {
+    // SAFETY: `sysconf` has no preconditions.
+    let page_size = unsafe { libc::sysconf(libc::_SC_PAGESIZE) };
+    let page_size = usize::try_from(page_size).map_err(|_| io::Error::last_os_error())?;
+    len.max(1)
+        .checked_add(page_size - 1)
+        .map(|len| len / page_size * page_size)
+        .ok_or_else(|| io::Error::from(io::ErrorKind::OutOfMemory))
+}
+
+/// Creates a `memfd_secret(2)` file descriptor.
+fn memfd_secret() -> io::Result<OwnedFd> {
+    // SAFETY: `memfd_secret` takes a single flags argument.
+    let fd = unsafe { libc::syscall(libc::SYS_memfd_secret, libc::O_CLOEXEC as libc::c_uint) };
+    if fd < 0 {
+        return Err(io::Error::last_os_error());
+    }
+    // SAFETY: `fd` is a new file descriptor owned by nothing else.
+    Ok(unsafe { OwnedFd::from_raw_fd(fd as libc::c_int) })
+}
+
+/// Maps `map_len` bytes of the `memfd_secret(2)` memory of `fd`, which is closed by the caller; the mapping keeps the memory alive.
+fn map_memfd_secret(fd: &OwnedFd, map_len: usize) -> io::Result<NonNull<u8>> {
+    // SAFETY: `fd` is open, and a shared mapping of it has no other preconditions.
+    let ptr = unsafe {
+        if libc::ftruncate(fd.as_raw_fd(), map_len as libc::off_t) != 0 {
+            return Err(io::Error::last_os_error());
+        }
+        libc::mmap(
+            core::ptr::null_mut(),
+            map_len,
+            libc::PROT_READ | libc::PROT_WRITE,
+            libc::MAP_SHARED,
+            fd.as_raw_fd(),
+            0,
+        )
+    };
+    if ptr == libc::MAP_FAILED {
+        return Err(io::Error::last_os_error());
+    }
+    NonNull::new(ptr.cast()).ok_or_else(io::Error::last_os_error)
+}
+
+/// Maps `map_len` bytes of anonymous memory, locked with `mlock(2)`, excluded from core dumps and wiped in forked children.
+fn map_mlocked(map_len: usize) -> io::Result<NonNull<u8>> {
+    // SAFETY: An anonymous mapping has no preconditions, and it is unmapped if it cannot be locked.
+    unsafe {
+        let ptr = libc::mmap(
+            core::ptr::null_mut(),
+            map_len,
+            libc::PROT_READ | libc::PROT_WRITE,
+            libc::MAP_PRIVATE | libc::MAP_ANONYMOUS,
+            -1,
+            0,
+        );
+        if ptr == libc::MAP_FAILED {
+            return Err(io::Error::last_os_error());
+        }
+        if libc::mlock(ptr, map_len) != 0 {
+            let error = io::Error::last_os_error();
+            libc::munmap(ptr, map_len);
+            return Err(error);
+        }
+        // Best effort: the bytes are still protected from swapping if the advices are not supported,
+        // e.g. `MADV_WIPEONFORK` before Linux 4.14.
+        libc::madvise(ptr, map_len, libc::MADV_DONTDUMP);
+        libc::madvise(ptr, map_len, libc::MADV_WIPEONFORK);
+        NonNull::new(ptr.cast()).ok_or_else(io::Error::last_os_error)
+    }
+}
+
+impl Zeroize for ProtectedBytes {
+    /// Zeroizes the whole mapping, including the bytes beyond the length.
+    fn zeroize(&mut self) {
+        // SAFETY: The mapping is writable, `map_len` bytes long and borrowed mutably.
+        unsafe { core::slice::from_raw_parts_mut(self.ptr.as_ptr(), self.map_len) }.zeroize();
+    }
+}
+
+impl Drop for ProtectedBytes {
+    /// Zeroizes and unmaps the whole mapping.
+    fn drop(&mut self) {
+        self.zeroize();
+        let ptr = self.ptr.as_ptr().cast::<c_void>();
+        // SAFETY: The mapping was created with `map_len` bytes and is not used anymore.
+        unsafe {
+            if self.mode == ProtectionMode::Mlock {
+                libc::munlock(ptr, self.map_len);
+            }
+            libc::munmap(ptr, self.map_len);
+        }
+    }
+}
+
+impl fmt::Debug for ProtectedBytes {
+    /// Formats the `ProtectedBytes` as "`ProtectedBytes([REDACTED])`".
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "ProtectedBytes({})", crate::traits::REDACTED)
+    }
+}
+
+#[cfg(feature = "debug-secret")]
+impl crate::traits::DebugSecret for ProtectedBytes {
+    fn debug_secret_byte_len(&self) -> Option<usize> {
+        Some(self.len)
+    }
+}
+
+#[cfg(feature = "fingerprint")]
+impl crate::traits::FingerprintSecret for ProtectedBytes {
+    fn fingerprint_bytes(&self) -> &[u8] {
+        self.as_slice()
+    }
+}
@@ -0,0 +1,14 @@
+//! Cryptographic primitives shared by the modules which encrypt secrets, kept private to the crate.
+//!
+//! Secrets are encrypted with the ChaCha20-Poly1305 AEAD of [RFC 8439](https://www.rfc-editor.org/rfc/rfc8439), from the `chacha20poly1305` crate.
+
+#[cfg(feature = "envelope")]
+pub(crate) mod pbkdf2;
+pub(crate) mod random;
+
+/// The length of a ChaCha20-Poly1305 key, in bytes.
+pub(crate) const KEY_LEN: usize = 32;
+/// The length of a ChaCha20-Poly1305 nonce, in bytes.
+pub(crate) const NONCE_LEN: usize = 12;
+/// The length of a ChaCha20-Poly1305 authentication tag, in bytes.
+pub(crate) const TAG_LEN: usize = 16;
@@ -0,0 +1,52 @@
+use sha2::Sha256;
+
+/// Derives a 32-byte key from `password` and `salt` with PBKDF2-HMAC-SHA256 ([RFC 8018](https://www.rfc-editor.org/rfc/rfc8018)) and `iterations` iterations.
+pub(crate) fn pbkdf2_hmac_sha256(password: &[u8], salt: &[u8], iterations: u32) -> [u8; 32] {
+    let mut key = [0; 32];
+    pbkdf2::pbkdf2_hmac::<Sha256>(password, salt, iterations, &mut key);
+    key
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// The widely published PBKDF2-HMAC-SHA256 outputs for the inputs of [RFC 6070](https://www.rfc-editor.org/rfc/rfc6070), which only covers SHA-1.
+    #[test]
+    fn test_pbkdf2_hmac_sha256_vectors() {
+        let vectors: [(&[u8], &[u8], u32, &str); 4] = [
+            (
+                b"password",
+                b"salt",
+                1,
+                "120fb6cffcf8b32c43e7225256c4f837a86548c92ccc35480805987cb70be17b",
+            ),
+            (
+                b"password",
+                b"salt",
+                2,
+                "ae4d0c95af6b46d32d0adff928f06dd02a303f8ef3c251dfd6e2d85a95474c43",
+            ),
+            (
+                b"password",
+                b"salt",
+                4096,
+                "c5e478d59288c841aa530db6845c4c8d962893a001ce4e11a4963873aa98134a",
+            ),
+            (
+                b"passwordPASSWORDpassword",
+                b"saltSALTsaltSALTsaltSALTsaltSALTsalt",
+                4096,
+                "348c89dbcbd32b2f32d814b8116e84cf2b17347ebc1800181c4e2a1fb8dd53e1",
+            ),
+        ];
+        for (password, salt, iterations, expected) in vectors {
+            let key = pbkdf2_hmac_sha256(password, salt, iterations);
+            let hex: std::string::String = key
+                .iter()
+                .map(|byte| std::format!("{:02x}", byte))
+                .collect();
+            assert_eq!(hex, expected);
+        }
+    }
+}
@@ -0,0 +1,7 @@
+use rand_core::{OsRng, RngCore};
+use std::io;
+
+/// Fills `bytes` with random bytes from the operating system's CSPRNG, through `getrandom`.
+pub(crate) fn fill_os_random(bytes: &mut [u8]) -> io::Result<()> {
+    OsRng.try_fill_bytes(bytes).map_err(io::Error::from)
+}
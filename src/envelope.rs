@@ -0,0 +1,606 @@
+//! A versioned binary format for persisting encrypted secrets, e.g. as a cache of fetched credentials.
+//!
+//! A secret is written as an envelope with `seal_to_writer()` on [`Secret`](crate::prelude::Secret), [`RTSecret`](crate::prelude::RTSecret)
+//! and [`SecrecySecret`](crate::prelude::SecrecySecret) holding a `Vec<u8>`, which counts as one exposure,
+//! and read back into a new secret with their `open_from_reader()` constructors.
+//! Sealing encrypts a zeroizing copy of the secret in place before writing it, and opening decrypts the ciphertext in place, in the buffer of the new secret,
+//! so that no other plaintext copy of the secret outlives the call.
+//!
+//! The secret is encrypted with ChaCha20-Poly1305 ([RFC 8439](https://www.rfc-editor.org/rfc/rfc8439)), under either a raw 256-bit key,
+//! or a key derived from a passphrase with PBKDF2-HMAC-SHA256 ([RFC 8018](https://www.rfc-editor.org/rfc/rfc8018)), see [`EnvelopeKey`].
+//!
+//! # Format
+//! All the integers are little-endian. Version 1 of the format is:
+//!
+//! | Offset      | Length | Field                                                                                  |
+//! |-------------|--------|----------------------------------------------------------------------------------------|
+//! | 0           | 8      | Magic bytes, `SOSECENV`                                                                |
+//! | 8           | 1      | Version, `1`                                                                           |
+//! | 9           | 1      | KDF: `0` for a raw key, `1` for PBKDF2-HMAC-SHA256                                     |
+//! | 10          | 4      | KDF iterations, from 1 to [`MAX_PBKDF2_ITERATIONS`] for PBKDF2, `0` for a raw key      |
+//! | 14          | 16     | KDF salt, random for PBKDF2, zeros for a raw key                                       |
+//! | 30          | 12     | Nonce, random                                                                          |
+//! | 42          | 2      | Label length `L`, in bytes                                                             |
+//! | 44          | `L`    | Label, UTF-8, not secret                                                               |
+//! | 44 + L      | 4      | Ciphertext length `C`, at most [`MAX_SECRET_LEN`]                                      |
+//! | 48 + L      | `C`    | Ciphertext                                                                             |
+//! | 48 + L + C  | 16     | Authentication tag                                                                     |
+//!
+//! The associated data of the AEAD is the whole header, i.e. the first `48 + L` bytes, so that the label and the KDF parameters are authenticated as well.
+//! [`Envelope::parse`] checks every length before reading, and never panics nor allocates, whatever its input.
+//!
+//! Example:
+//! ```rust
+//! use sosecrets_rs::{
+//!     envelope::EnvelopeKey,
+//!     prelude::{typenum::U2, RTSecret},
+//!     runtime::traits::RTExposeSecret,
+//! };
+//!
+//! let key = EnvelopeKey::passphrase(b"correct horse battery staple").with_iterations(1_000);
+//!
+//! let secret = RTSecret::<Vec<u8>, U2>::new(b"hunter2".to_vec());
+//! let mut file = Vec::new();
+//! secret.seal_to_writer(&key, "db-password", &mut file).unwrap();
+//!
+//! let opened = RTSecret::<Vec<u8>, U2>::open_from_reader(&key, file.as_slice()).unwrap();
+//! assert_eq!(opened.expose_secret(|exposed_secret| exposed_secret.clone()), b"hunter2");
+//! ```
+
+use core::fmt;
+use std::{
+    boxed::Box,
+    io::{self, Read, Write},
+    vec::Vec,
+};
+
+use chacha20poly1305::{AeadInPlace, ChaCha20Poly1305, Key, KeyInit, Nonce, Tag};
+use zeroize::Zeroizing;
+
+use crate::crypto::{
+    pbkdf2::pbkdf2_hmac_sha256, random::fill_os_random, KEY_LEN, NONCE_LEN, TAG_LEN,
+};
+
+/// The magic bytes starting every envelope.
+pub const MAGIC: [u8; 8] = *b"SOSECENV";
+
+/// The version of the format written by this crate.
+pub const VERSION: u8 = 1;
+
+/// The maximum length of a secret in an envelope, in bytes, which bounds the memory used to read an envelope.
+pub const MAX_SECRET_LEN: usize = 1 << 24;
+
+/// The maximum number of PBKDF2 iterations in an envelope, which bounds the time spent to open an untrusted envelope.
+pub const MAX_PBKDF2_ITERATIONS: u32 = 10_000_000;
+
+/// The number of PBKDF2 iterations used by [`EnvelopeKey::passphrase`], as recommended by OWASP for PBKDF2-HMAC-SHA256.
+pub const DEFAULT_PBKDF2_ITERATIONS: u32 = 600_000;
+
+const KDF_RAW_KEY: u8 = 0;
+const KDF_PBKDF2_HMAC_SHA256: u8 = 1;
+const SALT_LEN: usize = 16;
+
+/// The length of the header before the label.
+const FIXED_HEADER_LEN: usize = MAGIC.len() + 1 + 1 + 4 + SALT_LEN + NONCE_LEN + 2;
+
+/// The maximum length of an envelope, in bytes.
+const MAX_ENVELOPE_LEN: usize = FIXED_HEADER_LEN + u16::MAX as usize + 4 + MAX_SECRET_LEN + TAG_LEN;
+
+/// The key derivation function of an envelope.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Kdf {
+    /// The envelope is encrypted under a raw 256-bit key, see [`EnvelopeKey::raw`].
+    RawKey,
+    /// The envelope is encrypted under a key derived from a passphrase with PBKDF2-HMAC-SHA256, see [`EnvelopeKey::passphrase`].
+    Pbkdf2HmacSha256 {
+        /// The number of iterations.
+        iterations: u32,
+    },
+}
+
+/// The key sealing and opening envelopes.
+#[derive(Clone, Copy)]
+pub struct EnvelopeKey<'a>(KeyMaterial<'a>);
+
+#[derive(Clone, Copy)]
+enum KeyMaterial<'a> {
+    Raw(&'a [u8; KEY_LEN]),
+    Passphrase {
+        passphrase: &'a [u8],
+        iterations: u32,
+    },
+}
+
+impl<'a> EnvelopeKey<'a> {
+    /// Creates a new `EnvelopeKey` from a raw 256-bit key, which should be uniformly random.
+    pub const fn raw(key: &'a [u8; KEY_LEN]) -> Self {
+        Self(KeyMaterial::Raw(key))
+    }
+
+    /// Creates a new `EnvelopeKey` from a passphrase, from which the key of every envelope is derived with PBKDF2-HMAC-SHA256,
+    /// a new random salt, and [`DEFAULT_PBKDF2_ITERATIONS`] iterations.
+    ///
+    /// Opening an envelope uses the number of iterations written in it instead.
+    pub const fn passphrase(passphrase: &'a [u8]) -> Self {
+        Self(KeyMaterial::Passphrase {
+            passphrase,
+            iterations: DEFAULT_PBKDF2_ITERATIONS,
+        })
+    }
+
+    /// Sets the number of PBKDF2 iterations used to seal envelopes with a passphrase; it has no effect on a raw key.
+    pub const fn with_iterations(self, iterations: u32) -> Self {
+        match self.0 {
+            KeyMaterial::Passphrase { passphrase, .. } => Self(KeyMaterial::Passphrase {
+                passphrase,
+                iterations,
+            }),
+            KeyMaterial::Raw(_) => self,
+        }
+    }
+
+    /// Returns the KDF used to seal envelopes with this key.
+    pub const fn kdf(&self) -> Kdf {
+        match self.0 {
+            KeyMaterial::Raw(_) => Kdf::RawKey,
+            KeyMaterial::Passphrase { iterations, .. } => Kdf::Pbkdf2HmacSha256 { iterations },
+        }
+    }
+
+    /// Derives the key of an envelope sealed with `kdf` and `salt`.
+    fn derive(
+        &self,
+        kdf: Kdf,
+        salt: &[u8; SALT_LEN],
+    ) -> Result<Zeroizing<[u8; KEY_LEN]>, EnvelopeError> {
+        match (self.0, kdf) {
+            (KeyMaterial::Raw(key), Kdf::RawKey) => Ok(Zeroizing::new(*key)),
+            (KeyMaterial::Passphrase { passphrase, .. }, Kdf::Pbkdf2HmacSha256 { iterations }) => {
+                Ok(Zeroizing::new(pbkdf2_hmac_sha256(
+                    passphrase, salt, iterations,
+                )))
+            }
+            _ => Err(EnvelopeError::KdfMismatch),
+        }
+    }
+}
+
+impl fmt::Debug for EnvelopeKey<'_> {
+    /// Formats the `EnvelopeKey` with its KDF, without its key or passphrase.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("EnvelopeKey")
+            .field("kdf", &self.kdf())
+            .field("key", &format_args!("{}", crate::traits::REDACTED))
+            .finish()
+    }
+}
+
+/// An error of sealing, parsing or opening an envelope.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum EnvelopeError {
+    /// Reading or writing the envelope failed.
+    Io(io::Error),
+    /// The envelope does not start with [`MAGIC`].
+    InvalidMagic,
+    /// The version of the envelope is not supported.
+    UnsupportedVersion(u8),
+    /// The KDF of the envelope is not supported.
+    UnsupportedKdf(u8),
+    /// The KDF parameters of the envelope are invalid, e.g. too many PBKDF2 iterations.
+    InvalidKdfParameters,
+    /// The label of the envelope is not valid UTF-8.
+    InvalidLabel,
+    /// The envelope ends before its last field.
+    Truncated,
+    /// The envelope has bytes after its authentication tag.
+    TrailingBytes,
+    /// The label is longer than 65535 bytes, or the secret is longer than [`MAX_SECRET_LEN`].
+    TooLarge,
+    /// The envelope is sealed with a raw key and opened with a passphrase, or the other way around.
+    KdfMismatch,
+    /// The authentication tag does not verify, i.e. the key or passphrase is wrong, or the envelope has been tampered with.
+    Authentication,
+    /// The secret cannot be exposed to be sealed, e.g. because the [`RTSecret`](crate::runtime::secret::RTSecret) has already been exposed for `MEC` times;
+    /// it holds the [`ExposeSecretError`](crate::runtime::error::ExposeSecretError) of the exposure.
+    Exposure(Box<dyn std::error::Error + Send + Sync>),
+}
+
+impl fmt::Display for EnvelopeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Io(err) => write!(f, "the envelope cannot be read or written: {}", err),
+            Self::InvalidMagic => f.write_str("the envelope does not start with the magic bytes `SOSECENV`"),
+            Self::UnsupportedVersion(version) => write!(f, "the envelope version {} is not supported", version),
+            Self::UnsupportedKdf(kdf) => write!(f, "the envelope KDF {} is not supported", kdf),
+            Self::InvalidKdfParameters => f.write_str("the envelope KDF parameters are invalid"),
+            Self::InvalidLabel => f.write_str("the envelope label is not valid UTF-8"),
+            Self::Truncated => f.write_str("the envelope is truncated"),
+            Self::TrailingBytes => f.write_str("the envelope has trailing bytes"),
+            Self::TooLarge => f.write_str("the envelope label or secret is too large"),
+            Self::KdfMismatch => f.write_str("the envelope is sealed with another kind of key"),
+            Self::Authentication => f.write_str("the envelope cannot be authenticated, the key is wrong or the envelope has been tampered with"),
+            Self::Exposure(err) => err.fmt(f),
+        }
+    }
+}
+
+impl std::error::Error for EnvelopeError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Io(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for EnvelopeError {
+    fn from(err: io::Error) -> Self {
+        Self::Io(err)
+    }
+}
+
+/// A parsed envelope, whose metadata can be inspected without any key.
+#[derive(Debug, Clone)]
+pub struct Envelope<'a> {
+    version: u8,
+    kdf: Kdf,
+    salt: [u8; SALT_LEN],
+    nonce: [u8; NONCE_LEN],
+    label: &'a str,
+    header: &'a [u8],
+    ciphertext: &'a [u8],
+    tag: [u8; TAG_LEN],
+}
+
+/// Reads the fields of an envelope, checking their lengths.
+struct Cursor<'a>(&'a [u8]);
+
+impl<'a> Cursor<'a> {
+    fn take(&mut self, len: usize) -> Result<&'a [u8], EnvelopeError> {
+        if self.0.len() < len {
+            return Err(EnvelopeError::Truncated);
+        }
+        let (taken, rest) = self.0.split_at(len);
+        self.0 = rest;
+        Ok(taken)
+    }
+
+    fn take_array<const N: usize>(&mut self) -> Result<[u8; N], EnvelopeError> {
+        let mut array = [0; N];
+        array.copy_from_slice(self.take(N)?);
+        Ok(array)
+    }
+}
+
+impl<'a> Envelope<'a> {
+    /// Parses the envelope in `bytes`, see the [module documentation](self) for its format.
+    ///
+    /// # Errors
+    /// If `bytes` is not exactly one well-formed envelope of a supported version.
+    pub fn parse(bytes: &'a [u8]) -> Result<Self, EnvelopeError> {
+        let mut cursor = Cursor(bytes);
+        if cursor.take_array::<8>()? != MAGIC {
+            return Err(EnvelopeError::InvalidMagic);
+        }
+        let [version] = cursor.take_array::<1>()?;
+        if version != VERSION {
+            return Err(EnvelopeError::UnsupportedVersion(version));
+        }
+        let [kdf] = cursor.take_array::<1>()?;
+        let iterations = u32::from_le_bytes(cursor.take_array()?);
+        let salt = cursor.take_array::<SALT_LEN>()?;
+        let kdf = match kdf {
+            KDF_RAW_KEY if iterations == 0 && salt == [0; SALT_LEN] => Kdf::RawKey,
+            KDF_PBKDF2_HMAC_SHA256 if (1..=MAX_PBKDF2_ITERATIONS).contains(&iterations) => {
+                Kdf::Pbkdf2HmacSha256 { iterations }
+            }
+            KDF_RAW_KEY | KDF_PBKDF2_HMAC_SHA256 => {
+                return Err(EnvelopeError::InvalidKdfParameters)
+            }
+            kdf => return Err(EnvelopeError::UnsupportedKdf(kdf)),
+        };
+        let nonce = cursor.take_array::<NONCE_LEN>()?;
+        let label_len = u16::from_le_bytes(cursor.take_array()?);
+        let label = core::str::from_utf8(cursor.take(label_len.into())?)
+            .map_err(|_| EnvelopeError::InvalidLabel)?;
+        let ciphertext_len = u32::from_le_bytes(cursor.take_array()?) as usize;
+        if ciphertext_len > MAX_SECRET_LEN {
+            return Err(EnvelopeError::TooLarge);
+        }
+        let header = &bytes[..bytes.len() - cursor.0.len()];
+        let ciphertext = cursor.take(ciphertext_len)?;
+        let tag = cursor.take_array::<TAG_LEN>()?;
+        if !cursor.0.is_empty() {
+            return Err(EnvelopeError::TrailingBytes);
+        }
+        Ok(Self {
+            version,
+            kdf,
+            salt,
+            nonce,
+            label,
+            header,
+            ciphertext,
+            tag,
+        })
+    }
+
+    /// Returns the version of the format of the envelope.
+    pub const fn version(&self) -> u8 {
+        self.version
+    }
+
+    /// Returns the KDF of the envelope.
+    pub const fn kdf(&self) -> Kdf {
+        self.kdf
+    }
+
+    /// Returns the label of the envelope, which is authenticated when the envelope is opened.
+    pub const fn label(&self) -> &'a str {
+        self.label
+    }
+
+    /// Returns the length of the secret in the envelope, in bytes.
+    pub const fn secret_len(&self) -> usize {
+        self.ciphertext.len()
+    }
+
+    /// Decrypts the secret of the envelope into a new buffer, in place.
+    pub(crate) fn open(&self, key: &EnvelopeKey<'_>) -> Result<Vec<u8>, EnvelopeError> {
+        let key = key.derive(self.kdf, &self.salt)?;
+        let mut buffer = Vec::with_capacity(self.ciphertext.len());
+        buffer.extend_from_slice(self.ciphertext);
+        ChaCha20Poly1305::new(Key::from_slice(&*key))
+            .decrypt_in_place_detached(
+                Nonce::from_slice(&self.nonce),
+                self.header,
+                &mut buffer,
+                Tag::from_slice(&self.tag),
+            )
+            .map_err(|_| EnvelopeError::Authentication)?;
+        Ok(buffer)
+    }
+}
+
+/// Reads an envelope from `reader` and decrypts its secret into a new buffer.
+pub(crate) fn open_from_reader(
+    key: &EnvelopeKey<'_>,
+    reader: impl Read,
+) -> Result<Vec<u8>, EnvelopeError> {
+    let mut bytes = Vec::new();
+    reader
+        .take(MAX_ENVELOPE_LEN as u64 + 1)
+        .read_to_end(&mut bytes)?;
+    if bytes.len() > MAX_ENVELOPE_LEN {
+        return Err(EnvelopeError::TooLarge);
+    }
+    Envelope::parse(&bytes)?.open(key)
+}
+
+/// Seals `secret` into an envelope written to `writer`, with a random salt and nonce.
+pub(crate) fn seal_to_writer(
+    secret: &[u8],
+    key: &EnvelopeKey<'_>,
+    label: &str,
+    writer: impl Write,
+) -> Result<(), EnvelopeError> {
+    let mut salt = [0; SALT_LEN];
+    if let KeyMaterial::Passphrase { .. } = key.0 {
+        fill_os_random(&mut salt)?;
+    }
+    let mut nonce = [0; NONCE_LEN];
+    fill_os_random(&mut nonce)?;
+    seal_with(secret, key, label, &salt, &nonce, writer)
+}
+
+/// Seals `secret` into an envelope written to `writer`, with the given salt and nonce.
+fn seal_with(
+    secret: &[u8],
+    key: &EnvelopeKey<'_>,
+    label: &str,
+    salt: &[u8; SALT_LEN],
+    nonce: &[u8; NONCE_LEN],
+    mut writer: impl Write,
+) -> Result<(), EnvelopeError> {
+    let label_len = u16::try_from(label.len()).map_err(|_| EnvelopeError::TooLarge)?;
+    if secret.len() > MAX_SECRET_LEN {
+        return Err(EnvelopeError::TooLarge);
+    }
+    let (kdf, iterations) = match key.kdf() {
+        Kdf::RawKey => (KDF_RAW_KEY, 0),
+        Kdf::Pbkdf2HmacSha256 { iterations } => {
+            if !(1..=MAX_PBKDF2_ITERATIONS).contains(&iterations) {
+                return Err(EnvelopeError::InvalidKdfParameters);
+            }
+            (KDF_PBKDF2_HMAC_SHA256, iterations)
+        }
+    };
+
+    let mut header = Vec::with_capacity(FIXED_HEADER_LEN + label.len() + 4);
+    header.extend_from_slice(&MAGIC);
+    header.push(VERSION);
+    header.push(kdf);
+    header.extend_from_slice(&iterations.to_le_bytes());
+    header.extend_from_slice(salt);
+    header.extend_from_slice(nonce);
+    header.extend_from_slice(&label_len.to_le_bytes());
+    header.extend_from_slice(label.as_bytes());
+    header.extend_from_slice(&(secret.len() as u32).to_le_bytes());
+
+    let key = key.derive(key.kdf(), salt)?;
+    let mut ciphertext = Zeroizing::new(Vec::from(secret));
+    let tag = ChaCha20Poly1305::new(Key::from_slice(&*key))
+        .encrypt_in_place_detached(Nonce::from_slice(nonce), &header, &mut ciphertext)
+        .map_err(|_| EnvelopeError::TooLarge)?;
+    writer.write_all(&header)?;
+    writer.write_all(&ciphertext)?;
+    writer.write_all(&tag)?;
+    writer.flush()?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::{collection::vec, prelude::*};
+
+    const RAW_KEY_GOLDEN: &[u8] = include_bytes!("../tests/golden/envelope_v1_raw_key.bin");
+    const PASSPHRASE_GOLDEN: &[u8] = include_bytes!("../tests/golden/envelope_v1_passphrase.bin");
+    const RAW_KEY: [u8; KEY_LEN] = [
+        0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24,
+        25, 26, 27, 28, 29, 30, 31,
+    ];
+
+    fn passphrase_secret() -> Vec<u8> {
+        (0..100u32).map(|index| (index * 7 % 256) as u8).collect()
+    }
+
+    #[test]
+    fn test_seal_reproduces_golden_files() {
+        let mut raw_key_envelope = Vec::new();
+        seal_with(
+            b"hunter2",
+            &EnvelopeKey::raw(&RAW_KEY),
+            "db-password",
+            &[0; SALT_LEN],
+            &core::array::from_fn(|index| 0x40 + index as u8),
+            &mut raw_key_envelope,
+        )
+        .unwrap();
+        assert_eq!(raw_key_envelope, RAW_KEY_GOLDEN);
+
+        let mut passphrase_envelope = Vec::new();
+        seal_with(
+            &passphrase_secret(),
+            &EnvelopeKey::passphrase(b"correct horse battery staple").with_iterations(1_000),
+            "api-token-ünicode",
+            &core::array::from_fn(|index| 0x10 + index as u8),
+            &core::array::from_fn(|index| 0x50 + index as u8),
+            &mut passphrase_envelope,
+        )
+        .unwrap();
+        assert_eq!(passphrase_envelope, PASSPHRASE_GOLDEN);
+    }
+
+    #[test]
+    fn test_parse_golden_files() {
+        let envelope = Envelope::parse(RAW_KEY_GOLDEN).unwrap();
+        assert_eq!(envelope.version(), 1);
+        assert_eq!(envelope.kdf(), Kdf::RawKey);
+        assert_eq!(envelope.label(), "db-password");
+        assert_eq!(envelope.secret_len(), 7);
+        assert_eq!(
+            envelope.open(&EnvelopeKey::raw(&RAW_KEY)).unwrap(),
+            b"hunter2"
+        );
+
+        let envelope = Envelope::parse(PASSPHRASE_GOLDEN).unwrap();
+        assert_eq!(envelope.kdf(), Kdf::Pbkdf2HmacSha256 { iterations: 1_000 });
+        assert_eq!(envelope.label(), "api-token-ünicode");
+        assert_eq!(
+            envelope
+                .open(&EnvelopeKey::passphrase(b"correct horse battery staple"))
+                .unwrap(),
+            passphrase_secret()
+        );
+        assert!(matches!(
+            envelope.open(&EnvelopeKey::passphrase(b"wrong")),
+            Err(EnvelopeError::Authentication)
+        ));
+        assert!(matches!(
+            envelope.open(&EnvelopeKey::raw(&RAW_KEY)),
+            Err(EnvelopeError::KdfMismatch)
+        ));
+    }
+
+    /// Every truncation and every single-bit corruption of an envelope is rejected, without panicking.
+    #[test]
+    fn test_corrupted_envelopes_are_rejected() {
+        for len in 0..RAW_KEY_GOLDEN.len() {
+            assert!(matches!(
+                Envelope::parse(&RAW_KEY_GOLDEN[..len]),
+                Err(EnvelopeError::Truncated)
+            ));
+        }
+        let mut extended = RAW_KEY_GOLDEN.to_vec();
+        extended.push(0);
+        assert!(matches!(
+            Envelope::parse(&extended),
+            Err(EnvelopeError::TrailingBytes)
+        ));
+
+        let key = EnvelopeKey::raw(&RAW_KEY);
+        let mut corrupted = RAW_KEY_GOLDEN.to_vec();
+        for index in 0..corrupted.len() * 8 {
+            corrupted[index / 8] ^= 1 << (index % 8);
+            let result = Envelope::parse(&corrupted).and_then(|envelope| envelope.open(&key));
+            assert!(result.is_err(), "bit {} is not authenticated", index);
+            corrupted[index / 8] ^= 1 << (index % 8);
+        }
+        assert!(Envelope::parse(&corrupted).unwrap().open(&key).is_ok());
+
+        for bytes in [&b"SOSECENV\x02"[..], b"SOSECENV\x01\x07", b"NOTANENV\x01"] {
+            let mut bytes = bytes.to_vec();
+            bytes.resize(RAW_KEY_GOLDEN.len(), 0);
+            assert!(!matches!(
+                Envelope::parse(&bytes),
+                Ok(_) | Err(EnvelopeError::Truncated)
+            ));
+        }
+    }
+
+    #[test]
+    fn test_seal_limits() {
+        let key = EnvelopeKey::raw(&RAW_KEY);
+        let label = "l".repeat(usize::from(u16::MAX) + 1);
+        assert!(matches!(
+            seal_to_writer(b"", &key, &label, io::sink()),
+            Err(EnvelopeError::TooLarge)
+        ));
+        assert!(matches!(
+            seal_to_writer(
+                b"",
+                &EnvelopeKey::passphrase(b"passphrase").with_iterations(0),
+                "",
+                io::sink()
+            ),
+            Err(EnvelopeError::InvalidKdfParameters)
+        ));
+    }
+
+    proptest! {
+        /// `Envelope::parse` and `open_from_reader` never panic, whatever their input.
+        #[test]
+        fn test_open_arbitrary_bytes(bytes in vec(any::<u8>(), 0..512)) {
+            if let Ok(envelope) = Envelope::parse(&bytes) {
+                assert!(envelope.secret_len() < bytes.len());
+            }
+            let result = open_from_reader(&EnvelopeKey::raw(&RAW_KEY), bytes.as_slice());
+            assert!(result.is_err());
+        }
+
+        /// Arbitrary corruptions, truncations and extensions of the golden files, which get past the magic, are rejected without panicking.
+        #[test]
+        fn test_open_corrupted_golden_files(
+            golden in prop_oneof![Just(RAW_KEY_GOLDEN), Just(PASSPHRASE_GOLDEN)],
+            corruptions in vec((any::<usize>(), any::<u8>()), 0..8),
+            len in 0..256usize,
+            extension in vec(any::<u8>(), 0..8),
+        ) {
+            let mut bytes = golden.to_vec();
+            for (index, mask) in corruptions {
+                bytes[index % golden.len()] ^= mask;
+            }
+            bytes.truncate(len);
+            bytes.extend_from_slice(&extension);
+
+            let result = open_from_reader(&EnvelopeKey::raw(&RAW_KEY), bytes.as_slice());
+            if bytes == RAW_KEY_GOLDEN {
+                assert_eq!(result.unwrap(), b"hunter2");
+            } else {
+                assert!(result.is_err());
+            }
+        }
+    }
+}
@@ -0,0 +1,108 @@
+//! Fingerprints identifying a secret without revealing it.
+//!
+//! A [`Fingerprint`] is a short, salted and keyed hash (truncated HMAC-SHA256) of the bytes of a secret,
+//! computed with `fingerprint()` on [`Secret`](crate::prelude::Secret), [`RTSecret`](crate::prelude::RTSecret) and [`SecrecySecret`](crate::prelude::SecrecySecret)
+//! holding a value implementing [`FingerprintSecret`](crate::traits::FingerprintSecret).
+//! Two secrets have the same fingerprint under the same [`FingerprintKey`] if and only if they are (up to a negligible probability) equal,
+//! while the fingerprint reveals nothing about the secret to anyone who does not know the key.
+
+use core::fmt;
+
+use hmac::{Hmac, Mac};
+use sha2::Sha256;
+
+/// Separates the fingerprints of `sosecrets-rs` from any other use of the same key.
+const DOMAIN_SEPARATOR: &[u8] = b"sosecrets-rs fingerprint v1\0";
+
+/// The key and salt used to compute a [`Fingerprint`].
+///
+/// The same key and salt must be used on all the hosts whose secrets are compared.
+/// The key should be kept secret, otherwise anyone knowing it can test guesses of low-entropy secrets against their fingerprints.
+#[derive(Clone, Copy)]
+pub struct FingerprintKey<'a> {
+    key: &'a [u8],
+    salt: &'a [u8],
+}
+
+impl<'a> FingerprintKey<'a> {
+    /// Creates a new `FingerprintKey` from the HMAC `key` and a `salt`, e.g. the name of the environment or of the secret.
+    pub const fn new(key: &'a [u8], salt: &'a [u8]) -> Self {
+        Self { key, salt }
+    }
+
+    /// Computes the fingerprint of `bytes`.
+    pub(crate) fn fingerprint(&self, bytes: &[u8]) -> Fingerprint {
+        let mut mac = <Hmac<Sha256> as Mac>::new_from_slice(self.key)
+            .expect("HMAC accepts keys of any length");
+        mac.update(DOMAIN_SEPARATOR);
+        mac.update(&(self.salt.len() as u64).to_le_bytes());
+        mac.update(self.salt);
+        mac.update(bytes);
+        let tag = mac.finalize().into_bytes();
+        let mut fingerprint = [0; Fingerprint::LEN];
+        fingerprint.copy_from_slice(&tag[..Fingerprint::LEN]);
+        Fingerprint(fingerprint)
+    }
+}
+
+impl fmt::Debug for FingerprintKey<'_> {
+    /// Formats the `FingerprintKey` without its key.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("FingerprintKey")
+            .field("key", &format_args!("{}", crate::traits::REDACTED))
+            .field("salt", &self.salt)
+            .finish()
+    }
+}
+
+/// A short identifier of a secret, see the [module documentation](self).
+///
+/// It is displayed as [`Fingerprint::LEN`] bytes in lowercase hexadecimal.
+///
+/// Example:
+/// ```rust
+/// use sosecrets_rs::{
+///     fingerprint::FingerprintKey,
+///     prelude::{typenum::U2, RTSecret, Secret},
+///     runtime::traits::RTExposeSecret,
+/// };
+///
+/// let key = FingerprintKey::new(b"fingerprint key shared by the hosts", b"production");
+///
+/// let secret_on_host_one = Secret::<[u8; 4], U2>::new(*b"abcd");
+/// let (_, fingerprint_on_host_one) = secret_on_host_one.fingerprint(&key);
+///
+/// let secret_on_host_two = RTSecret::<[u8; 4], U2>::new(*b"abcd");
+/// let fingerprint_on_host_two = secret_on_host_two.fingerprint(&key);
+///
+/// assert_eq!(fingerprint_on_host_one, fingerprint_on_host_two);
+/// assert_eq!(format!("{}", fingerprint_on_host_one).len(), 16);
+/// assert_eq!(secret_on_host_two.exposure_count(), 1);
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Fingerprint([u8; Fingerprint::LEN]);
+
+impl Fingerprint {
+    /// The length of a `Fingerprint` in bytes.
+    pub const LEN: usize = 8;
+
+    /// Returns the bytes of the `Fingerprint`.
+    #[inline(always)]
+    pub const fn as_bytes(&self) -> &[u8; Fingerprint::LEN] {
+        &self.0
+    }
+}
+
+impl fmt::Display for Fingerprint {
+    /// Formats the `Fingerprint` in lowercase hexadecimal, e.g. "`3f9c0a8e5d1b7264`".
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.iter().try_for_each(|byte| write!(f, "{:02x}", byte))
+    }
+}
+
+impl fmt::Debug for Fingerprint {
+    /// Formats the `Fingerprint` as "`Fingerprint(3f9c0a8e5d1b7264)`".
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Fingerprint({})", self)
+    }
+}
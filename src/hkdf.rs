@@ -0,0 +1,121 @@
+//! Derivation of sub-keys from a master key secret with HKDF-SHA256 ([RFC 5869](https://www.rfc-editor.org/rfc/rfc5869)).
+//!
+//! `derive_key()` on [`Secret`](crate::prelude::Secret), [`RTSecret`](crate::prelude::RTSecret) and [`SecrecySecret`](crate::prelude::SecrecySecret)
+//! holding a value implementing `AsRef<[u8]>` derives a new `Secret<[u8; N], MEC2>` from the master key, the salt and the info of [`HkdfParams`],
+//! which consumes one exposure of the master key per derivation.
+//! The derived bytes are written directly into the new `Secret`.
+//!
+//! Example:
+//! ```rust
+//! use sosecrets_rs::{
+//!     hkdf::HkdfParams,
+//!     prelude::{typenum::{U1, U2}, RTSecret, Secret},
+//!     traits::ExposeSecret,
+//! };
+//!
+//! let master_key = RTSecret::<[u8; 32], U2>::new([7; 32]);
+//! let tenant_a: Secret<[u8; 32], U1> = master_key.derive_key(&HkdfParams::new(b"salt", b"tenant-a"));
+//! let tenant_b: Secret<[u8; 32], U1> = master_key.derive_key(&HkdfParams::new(b"salt", b"tenant-b"));
+//! assert_eq!(master_key.exposure_count(), 2);
+//!
+//! let (_, tenant_a) = tenant_a.expose_secret(|exposed_secret| *exposed_secret);
+//! let (_, tenant_b) = tenant_b.expose_secret(|exposed_secret| *exposed_secret);
+//! assert_ne!(tenant_a, tenant_b);
+//! ```
+
+use hkdf::Hkdf;
+use sha2::Sha256;
+
+/// The length of the output of SHA-256, in bytes.
+const HASH_LEN: usize = 32;
+
+/// The maximum length of a derived key, in bytes, i.e. 255 blocks of 32 bytes, the length of a SHA-256 hash.
+pub const MAX_KEY_LEN: usize = 255 * HASH_LEN;
+
+/// The salt and the info of an HKDF derivation.
+///
+/// The salt should be random and may be public, while the info binds the derived key to its purpose, e.g. the name of a tenant;
+/// different infos derive independent keys from the same master key.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct HkdfParams<'a> {
+    salt: &'a [u8],
+    info: &'a [u8],
+}
+
+impl<'a> HkdfParams<'a> {
+    /// Creates a new `HkdfParams` from a `salt`, which may be empty, and an `info`.
+    pub const fn new(salt: &'a [u8], info: &'a [u8]) -> Self {
+        Self { salt, info }
+    }
+
+    /// Derives `okm.len()` bytes from the master key `ikm` into `okm`.
+    pub(crate) fn derive<const N: usize>(&self, ikm: &[u8], okm: &mut [u8; N]) {
+        #[allow(clippy::let_unit_value)]
+        let () = KeyLen::<N>::VALID;
+
+        Hkdf::<Sha256>::new(Some(self.salt), ikm)
+            .expand(self.info, okm)
+            .expect("the length of the derived key is checked at compile time");
+    }
+}
+
+/// Rejects the derived keys longer than [`MAX_KEY_LEN`] at compile time.
+struct KeyLen<const N: usize>;
+
+impl<const N: usize> KeyLen<N> {
+    const VALID: () = assert!(N <= MAX_KEY_LEN, "HKDF-SHA256 derives at most 8160 bytes");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn unhex<const N: usize>(hex: &str) -> [u8; N] {
+        assert_eq!(hex.len(), 2 * N);
+        let mut bytes = [0; N];
+        for (byte, digits) in bytes.iter_mut().zip(hex.as_bytes().chunks(2)) {
+            *byte = u8::from_str_radix(core::str::from_utf8(digits).unwrap(), 16).unwrap();
+        }
+        bytes
+    }
+
+    fn derive<const N: usize>(ikm: &[u8], salt: &[u8], info: &[u8]) -> [u8; N] {
+        let mut okm = [0; N];
+        HkdfParams::new(salt, info).derive(ikm, &mut okm);
+        okm
+    }
+
+    /// The test cases 1 to 3 of [RFC 5869](https://www.rfc-editor.org/rfc/rfc5869#appendix-A), which use SHA-256.
+    #[test]
+    fn test_rfc_5869_vectors() {
+        let ikm = [0x0b; 22];
+        let salt: [u8; 13] = core::array::from_fn(|i| i as u8);
+        let info: [u8; 10] = core::array::from_fn(|i| 0xf0 + i as u8);
+        assert_eq!(
+            derive::<42>(&ikm, &salt, &info),
+            unhex("3cb25f25faacd57a90434f64d0362f2a2d2d0a90cf1a5a4c5db02d56ecc4c5bf34007208d5b887185865")
+        );
+
+        let ikm: [u8; 80] = core::array::from_fn(|i| i as u8);
+        let salt: [u8; 80] = core::array::from_fn(|i| 0x60 + i as u8);
+        let info: [u8; 80] = core::array::from_fn(|i| 0xb0 + i as u8);
+        assert_eq!(
+            derive::<82>(&ikm, &salt, &info),
+            unhex("b11e398dc80327a1c8e7f78c596a49344f012eda2d4efad8a050cc4c19afa97c59045a99cac7827271cb41c65e590e09da3275600c2f09b8367793a9aca3db71cc30c58179ec3e87c14c01d5c1f3434f1d87")
+        );
+
+        assert_eq!(
+            derive::<42>(&[0x0b; 22], b"", b""),
+            unhex("8da4e775a563c18f715f802a063c5a31b8a11f5c5ee1879ec3454e5f3c738d2d9d201395faa4b61a96c8")
+        );
+    }
+
+    /// A key shorter than a block is the prefix of the longer keys derived with the same parameters.
+    #[test]
+    fn test_prefix() {
+        let long = derive::<{ 2 * HASH_LEN + 1 }>(b"master key", b"salt", b"info");
+        let short = derive::<7>(b"master key", b"salt", b"info");
+        assert_eq!(short, long[..7]);
+        assert_eq!(derive::<0>(b"master key", b"salt", b"info"), []);
+    }
+}
@@ -4,10 +4,26 @@
 // Apply #![recursion_limit = "256"] only if #![cfg(target_pointer_width = "128")]
 #![recursion_limit = "2048"]
 
-#[cfg(feature = "alloc")]
+#[cfg(any(feature = "alloc", feature = "std"))]
 extern crate alloc;
 
+#[cfg(feature = "std")]
+extern crate std;
+
 // #[cfg(feature = "runtime-secret")]
+pub mod containers;
+#[cfg(feature = "envelope")]
+pub mod envelope;
+#[cfg(any(feature = "sealed", feature = "envelope"))]
+mod crypto;
+#[cfg(feature = "fingerprint")]
+pub mod fingerprint;
+#[cfg(feature = "hkdf")]
+pub mod hkdf;
+#[cfg(feature = "password")]
+pub mod password;
+#[cfg(feature = "password-hash")]
+pub mod password_hash;
 pub mod runtime;
 
 mod macros;
@@ -17,5 +33,5 @@ pub mod traits;
 pub mod types;
 
 pub mod prelude {
-    pub use crate::{runtime::*, secret::*, types::*};
+    pub use crate::{containers::*, runtime::*, secret::*, types::*};
 }
@@ -10,6 +10,23 @@ macro_rules! impl_cloneable_secret_for_numbers {
 #[cfg(feature = "cloneable-secret")]
 pub(crate) use impl_cloneable_secret_for_numbers;
 
+#[cfg(feature = "cloneable-secret")]
+macro_rules! impl_cloneable_secret_for_tuples {
+    ($(($($t:ident),+)),*) => {
+        $(
+            impl<
+                $(
+                    #[cfg(feature = "zeroize")] $t: Clone + zeroize::Zeroize,
+                    #[cfg(not(feature = "zeroize"))] $t: Clone,
+                )+
+            > $crate::traits::CloneableSecret for ($($t,)+) {}
+        )*
+    };
+}
+
+#[cfg(feature = "cloneable-secret")]
+pub(crate) use impl_cloneable_secret_for_tuples;
+
 #[cfg(feature = "debug-secret")]
 macro_rules! impl_debug_secret_for_numbers {
     ($($t:ty),*) => {
@@ -22,6 +39,23 @@ macro_rules! impl_debug_secret_for_numbers {
 #[cfg(feature = "debug-secret")]
 pub(crate) use impl_debug_secret_for_numbers;
 
+#[cfg(feature = "debug-secret")]
+macro_rules! impl_debug_secret_for_tuples {
+    ($(($($t:ident),+)),*) => {
+        $(
+            impl<
+                $(
+                    #[cfg(feature = "zeroize")] $t: core::fmt::Debug + zeroize::Zeroize,
+                    #[cfg(not(feature = "zeroize"))] $t: core::fmt::Debug,
+                )+
+            > $crate::traits::DebugSecret for ($($t,)+) {}
+        )*
+    };
+}
+
+#[cfg(feature = "debug-secret")]
+pub(crate) use impl_debug_secret_for_tuples;
+
 macro_rules! impl_sealed_trait_for_uint {
     ($($t:ty),*) => {
         $(
@@ -31,6 +65,44 @@ macro_rules! impl_sealed_trait_for_uint {
 }
 pub(crate) use impl_sealed_trait_for_uint;
 
+/// Seals `$container` as an `AppendableSecret` and forwards the listed methods of it to the mutably exposed secrets,
+/// i.e. `ExposedSecretMut` and `RTExposedSecretMut`, which otherwise only dereference to a shared reference to it.
+macro_rules! impl_appendable_secret {
+    (
+        impl$(<const $n:ident: usize>)? for $container:ty {
+            $(
+                $(#[$attr:meta])*
+                fn $method:ident(&mut self $(, $arg:ident: $arg_ty:ty)*) $(-> $ret:ty)?;
+            )*
+        }
+    ) => {
+        impl$(<const $n: usize>)? $crate::traits::__private::SealedTrait for $container {}
+
+        impl$(<const $n: usize>)? $crate::traits::AppendableSecret for $container {}
+
+        impl<'brand $(, const $n: usize)?> $crate::secret::ExposedSecretMut<'brand, &'brand mut $container> {
+            $(
+                $(#[$attr])*
+                #[inline(always)]
+                pub fn $method(&mut self $(, $arg: $arg_ty)*) $(-> $ret)? {
+                    self.0.$method($($arg),*)
+                }
+            )*
+        }
+
+        impl<'brand $(, const $n: usize)?> $crate::runtime::secret::RTExposedSecretMut<'brand, &'brand mut $container> {
+            $(
+                $(#[$attr])*
+                #[inline(always)]
+                pub fn $method(&mut self $(, $arg: $arg_ty)*) $(-> $ret)? {
+                    self.0.$method($($arg),*)
+                }
+            )*
+        }
+    };
+}
+pub(crate) use impl_appendable_secret;
+
 macro_rules! impl_choose_int {
     // Entry point
     ($($arg:ident => $out:ty;)*) => {
@@ -57,10 +129,24 @@ macro_rules! impl_choose_int {
             type AtomicOutput = <$out as $crate::traits::AsAtomic>::Output;
             const ZERO: Self::Output = Self::Output::MIN;
             const ONE: Self::Output = 1;
+            type Label = Option<&'static str>;
+            const NO_LABEL: Self::Label = None;
 
             fn cast_unsigned_to_self_type<T: $crate::prelude::typenum::uint::Unsigned>(_: $crate::traits::__private::SealedToken) -> Self::Output {
                 <T as $crate::prelude::typenum::uint::Unsigned>::USIZE as Self::Output
             }
+
+            fn cast_self_type_to_usize(value: Self::Output, _: $crate::traits::__private::SealedToken) -> usize {
+                value as usize
+            }
+
+            fn to_label(label: &'static str, _: $crate::traits::__private::SealedToken) -> Self::Label {
+                Some(label)
+            }
+
+            fn from_label(label: Self::Label, _: $crate::traits::__private::SealedToken) -> Option<&'static str> {
+                label
+            }
         }
 
         impl_choose_int!{
@@ -0,0 +1,431 @@
+//! Generators of human-usable passwords and passphrases, which are written directly into a secret.
+//!
+//! - A [`PasswordPolicy`] generates passwords of a given length, drawing each character uniformly from the enabled character classes,
+//!   and only keeping the passwords which contain at least one character of each enabled class.
+//! - A [`PassphrasePolicy`] generates diceware-style passphrases of a given number of words, each drawn uniformly from the
+//!   [BIP-39 English word list](https://github.com/bitcoin/bips/blob/master/bip-0039/english.txt) of 2048 words (11 bits of entropy per word).
+//!
+//! Both return a [`GeneratedPassword`], which reports the estimated entropy of the policy in bits, and is turned into a
+//! [`Secret<String, MEC>`](crate::prelude::Secret) or an [`RTSecret<String, MEC>`](crate::prelude::RTSecret) without copying the password.
+//! The password is built in a buffer allocated once with its final capacity, so that it is never reallocated,
+//! and the rejected candidates and the random bytes they are drawn from are zeroized.
+//!
+//! Example:
+//! ```rust
+//! use sosecrets_rs::{
+//!     password::{PassphrasePolicy, PasswordPolicy},
+//!     prelude::{typenum::U1, RTSecret, Secret},
+//! };
+//! # use sosecrets_rs::{runtime::traits::RTExposeSecret, traits::ExposeSecret};
+//!
+//! #[cfg(feature = "getrandom")]
+//! {
+//!     let policy = PasswordPolicy::new(20).with_symbols(false).with_min_entropy_bits(100);
+//!     let password = policy.generate().unwrap();
+//!     assert!(password.entropy_bits() > 100.0);
+//!     let password: Secret<String, U1> = password.into_secret();
+//!     let (_, exposed_length) = password.expose_secret(|password| password.len());
+//!     assert_eq!(exposed_length, 20);
+//!
+//!     let passphrase = PassphrasePolicy::new(6).generate().unwrap();
+//!     assert_eq!(passphrase.entropy_bits(), 66.0);
+//!     let passphrase: RTSecret<String, U1> = passphrase.into_rt_secret();
+//!     passphrase.expose_secret(|passphrase| assert_eq!(passphrase.split('-').count(), 6));
+//! }
+//! ```
+
+mod words;
+
+use alloc::string::String;
+use core::fmt;
+
+use rand_core::CryptoRngCore;
+use typenum::{IsLessOrEqual, True, Unsigned, U0};
+use zeroize::{Zeroize, Zeroizing};
+
+use crate::{
+    prelude::{RTSecret, Secret},
+    runtime::traits::ExhaustionPolicy,
+    traits::ChooseMinimallyRepresentableUInt,
+};
+
+/// The lowercase letters.
+pub const LOWERCASE: &str = "abcdefghijklmnopqrstuvwxyz";
+/// The uppercase letters.
+pub const UPPERCASE: &str = "ABCDEFGHIJKLMNOPQRSTUVWXYZ";
+/// The decimal digits.
+pub const DIGITS: &str = "0123456789";
+/// The ASCII punctuation characters, without the quotes, the backslash and the backtick which often need escaping.
+pub const SYMBOLS: &str = "!#$%&()*+,-./:;<=>?@[]^_{|}~";
+/// The characters which are easily confused with each other, removed by [`PasswordPolicy::with_ambiguous`].
+pub const AMBIGUOUS: &str = "0O1Il|";
+
+/// The number of words of the passphrase word list.
+pub const WORD_LIST_LEN: usize = 2048;
+
+/// The length of the longest word of the passphrase word list, in bytes.
+const MAX_WORD_LEN: usize = 8;
+
+/// The character classes, in the order of the bits of [`Alphabet::required`].
+const CLASSES: [&str; 4] = [LOWERCASE, UPPERCASE, DIGITS, SYMBOLS];
+
+/// A policy generating random passwords, see the [module documentation](self).
+///
+/// All four character classes are enabled by default, i.e. 90 characters.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PasswordPolicy {
+    length: usize,
+    classes: [bool; 4],
+    ambiguous: bool,
+    min_entropy_bits: u32,
+}
+
+impl PasswordPolicy {
+    /// Creates a new `PasswordPolicy` generating passwords of `length` characters, from all character classes.
+    pub const fn new(length: usize) -> Self {
+        Self {
+            length,
+            classes: [true; 4],
+            ambiguous: true,
+            min_entropy_bits: 0,
+        }
+    }
+
+    /// Enables or disables the lowercase letters, [`LOWERCASE`].
+    pub const fn with_lowercase(mut self, enabled: bool) -> Self {
+        self.classes[0] = enabled;
+        self
+    }
+
+    /// Enables or disables the uppercase letters, [`UPPERCASE`].
+    pub const fn with_uppercase(mut self, enabled: bool) -> Self {
+        self.classes[1] = enabled;
+        self
+    }
+
+    /// Enables or disables the digits, [`DIGITS`].
+    pub const fn with_digits(mut self, enabled: bool) -> Self {
+        self.classes[2] = enabled;
+        self
+    }
+
+    /// Enables or disables the symbols, [`SYMBOLS`].
+    pub const fn with_symbols(mut self, enabled: bool) -> Self {
+        self.classes[3] = enabled;
+        self
+    }
+
+    /// Keeps or removes the [`AMBIGUOUS`] characters from the enabled character classes.
+    pub const fn with_ambiguous(mut self, enabled: bool) -> Self {
+        self.ambiguous = enabled;
+        self
+    }
+
+    /// Sets the minimum estimated entropy, in bits, below which no password is generated.
+    pub const fn with_min_entropy_bits(mut self, bits: u32) -> Self {
+        self.min_entropy_bits = bits;
+        self
+    }
+
+    /// Returns the estimated entropy of the passwords generated by this policy, in bits,
+    /// i.e. the base 2 logarithm of the number of passwords containing every enabled character class,
+    /// or `0.0` if this policy cannot generate any password.
+    pub fn entropy_bits(&self) -> f64 {
+        let alphabet = Alphabet::new(self);
+        let required_classes = alphabet.required.count_ones() as usize;
+        if required_classes == 0 || self.length < required_classes {
+            return 0.0;
+        }
+        // By inclusion-exclusion, the number of passwords containing every class is the sum over the subsets `S` of classes of
+        // `(-1)^|S| * (len - |S|)^length`, where `|S|` is the number of characters of `S`, computed relative to `len^length`.
+        let len = alphabet.len as f64;
+        let length = self.length as f64;
+        let mut ratio = 0.0;
+        for subset in 0..16u8 {
+            if subset & !alphabet.required != 0 {
+                continue;
+            }
+            let excluded: usize = (0..4)
+                .filter(|class| subset & (1 << class) != 0)
+                .map(|class| alphabet.class_lens[class])
+                .sum();
+            let term = ((len - excluded as f64) / len).powf(length);
+            if subset.count_ones() % 2 == 0 {
+                ratio += term;
+            } else {
+                ratio -= term;
+            }
+        }
+        length * len.log2() + ratio.log2()
+    }
+
+    /// Generates a password from the cryptographically secure `rng`.
+    ///
+    /// # Errors
+    /// - [`PasswordError::NoCharacterClass`] if every character class is disabled.
+    /// - [`PasswordError::TooShort`] if the length is shorter than the number of enabled character classes.
+    /// - [`PasswordError::InsufficientEntropy`] if [`PasswordPolicy::entropy_bits`] is below the minimum entropy.
+    /// - [`PasswordError::Rng`] if `rng` fails.
+    pub fn generate_with<R: CryptoRngCore + ?Sized>(
+        &self,
+        rng: &mut R,
+    ) -> Result<GeneratedPassword, PasswordError> {
+        let alphabet = Alphabet::new(self);
+        if alphabet.required == 0 {
+            return Err(PasswordError::NoCharacterClass);
+        }
+        let minimum = alphabet.required.count_ones() as usize;
+        if self.length < minimum {
+            return Err(PasswordError::TooShort {
+                length: self.length,
+                minimum,
+            });
+        }
+        let entropy_bits = check_entropy(self.entropy_bits(), self.min_entropy_bits)?;
+
+        let mut password = Zeroizing::new(String::with_capacity(self.length));
+        loop {
+            let mut classes = 0;
+            for _ in 0..self.length {
+                let index = uniform_index(rng, alphabet.len)?;
+                classes |= alphabet.classes[index];
+                password.push(char::from(alphabet.chars[index]));
+            }
+            if classes == alphabet.required {
+                break;
+            }
+            // Clears the rejected candidate, keeping the capacity of the buffer.
+            password.zeroize();
+        }
+        Ok(GeneratedPassword {
+            password,
+            entropy_bits,
+        })
+    }
+
+    /// Generates a password from the random number generator of the operating system, see [`PasswordPolicy::generate_with`].
+    #[cfg(feature = "getrandom")]
+    pub fn generate(&self) -> Result<GeneratedPassword, PasswordError> {
+        self.generate_with(&mut rand_core::OsRng)
+    }
+}
+
+/// A policy generating random passphrases, see the [module documentation](self).
+///
+/// The words are separated by `-` by default.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PassphrasePolicy {
+    words: usize,
+    separator: char,
+    min_entropy_bits: u32,
+}
+
+impl PassphrasePolicy {
+    /// Creates a new `PassphrasePolicy` generating passphrases of `words` words.
+    pub const fn new(words: usize) -> Self {
+        Self {
+            words,
+            separator: '-',
+            min_entropy_bits: 0,
+        }
+    }
+
+    /// Sets the separator of the words.
+    pub const fn with_separator(mut self, separator: char) -> Self {
+        self.separator = separator;
+        self
+    }
+
+    /// Sets the minimum estimated entropy, in bits, below which no passphrase is generated.
+    pub const fn with_min_entropy_bits(mut self, bits: u32) -> Self {
+        self.min_entropy_bits = bits;
+        self
+    }
+
+    /// Returns the estimated entropy of the passphrases generated by this policy, in bits, i.e. 11 bits per word.
+    pub fn entropy_bits(&self) -> f64 {
+        self.words as f64 * (WORD_LIST_LEN as f64).log2()
+    }
+
+    /// Generates a passphrase from the cryptographically secure `rng`.
+    ///
+    /// # Errors
+    /// - [`PasswordError::TooShort`] if the number of words is zero.
+    /// - [`PasswordError::InsufficientEntropy`] if [`PassphrasePolicy::entropy_bits`] is below the minimum entropy.
+    /// - [`PasswordError::Rng`] if `rng` fails.
+    pub fn generate_with<R: CryptoRngCore + ?Sized>(
+        &self,
+        rng: &mut R,
+    ) -> Result<GeneratedPassword, PasswordError> {
+        if self.words == 0 {
+            return Err(PasswordError::TooShort {
+                length: 0,
+                minimum: 1,
+            });
+        }
+        let entropy_bits = check_entropy(self.entropy_bits(), self.min_entropy_bits)?;
+
+        let capacity = self.words * MAX_WORD_LEN + (self.words - 1) * self.separator.len_utf8();
+        let mut passphrase = Zeroizing::new(String::with_capacity(capacity));
+        for word in 0..self.words {
+            if word > 0 {
+                passphrase.push(self.separator);
+            }
+            passphrase.push_str(words::WORDS[uniform_index(rng, WORD_LIST_LEN)?]);
+        }
+        Ok(GeneratedPassword {
+            password: passphrase,
+            entropy_bits,
+        })
+    }
+
+    /// Generates a passphrase from the random number generator of the operating system, see [`PassphrasePolicy::generate_with`].
+    #[cfg(feature = "getrandom")]
+    pub fn generate(&self) -> Result<GeneratedPassword, PasswordError> {
+        self.generate_with(&mut rand_core::OsRng)
+    }
+}
+
+/// A generated password or passphrase, which is zeroized when dropped unless it is turned into a secret.
+pub struct GeneratedPassword {
+    password: Zeroizing<String>,
+    entropy_bits: f64,
+}
+
+impl GeneratedPassword {
+    /// Returns the estimated entropy of the policy which generated the password, in bits.
+    pub fn entropy_bits(&self) -> f64 {
+        self.entropy_bits
+    }
+
+    /// Moves the password into a new [`Secret`].
+    pub fn into_secret<MEC: Unsigned>(mut self) -> Secret<String, MEC>
+    where
+        U0: IsLessOrEqual<MEC, Output = True>,
+    {
+        Secret::new(core::mem::take(&mut *self.password))
+    }
+
+    /// Moves the password into a new [`RTSecret`].
+    pub fn into_rt_secret<MEC: ChooseMinimallyRepresentableUInt, EP: ExhaustionPolicy>(
+        mut self,
+    ) -> RTSecret<String, MEC, EP> {
+        RTSecret::new(core::mem::take(&mut *self.password))
+    }
+}
+
+impl fmt::Debug for GeneratedPassword {
+    /// Formats the `GeneratedPassword` without its password.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("GeneratedPassword")
+            .field("password", &format_args!("{}", crate::traits::REDACTED))
+            .field("entropy_bits", &self.entropy_bits)
+            .finish()
+    }
+}
+
+/// The error returned when a password or passphrase cannot be generated.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum PasswordError {
+    /// Every character class of the [`PasswordPolicy`] is disabled.
+    NoCharacterClass,
+    /// The password is shorter than the number of its required character classes, or the passphrase has no word.
+    TooShort {
+        /// The length of the password, or the number of words of the passphrase.
+        length: usize,
+        /// The minimum length.
+        minimum: usize,
+    },
+    /// The estimated entropy of the policy is below its minimum entropy.
+    InsufficientEntropy {
+        /// The estimated entropy, in bits.
+        estimated_bits: f64,
+        /// The minimum entropy, in bits.
+        required_bits: u32,
+    },
+    /// The random number generator failed.
+    Rng(rand_core::Error),
+}
+
+impl fmt::Display for PasswordError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NoCharacterClass => f.write_str("the password policy enables no character class"),
+            Self::TooShort { length, minimum } => write!(f, "the password length {} is shorter than the minimum {}", length, minimum),
+            Self::InsufficientEntropy { estimated_bits, required_bits } => write!(f, "the password policy has an estimated entropy of {:.1} bits, below the minimum of {} bits", estimated_bits, required_bits),
+            Self::Rng(err) => write!(f, "the random number generator failed: {}", err),
+        }
+    }
+}
+
+impl std::error::Error for PasswordError {}
+
+/// The characters of the enabled character classes of a [`PasswordPolicy`].
+struct Alphabet {
+    chars: [u8; 128],
+    /// The bit of the character class of each character.
+    classes: [u8; 128],
+    len: usize,
+    /// The number of characters of each character class.
+    class_lens: [usize; 4],
+    /// The bits of the character classes which are enabled and not empty.
+    required: u8,
+}
+
+impl Alphabet {
+    fn new(policy: &PasswordPolicy) -> Self {
+        let mut alphabet = Self {
+            chars: [0; 128],
+            classes: [0; 128],
+            len: 0,
+            class_lens: [0; 4],
+            required: 0,
+        };
+        for (class, chars) in CLASSES.iter().enumerate() {
+            if !policy.classes[class] {
+                continue;
+            }
+            for byte in chars.bytes() {
+                if !policy.ambiguous && AMBIGUOUS.as_bytes().contains(&byte) {
+                    continue;
+                }
+                alphabet.chars[alphabet.len] = byte;
+                alphabet.classes[alphabet.len] = 1 << class;
+                alphabet.len += 1;
+                alphabet.class_lens[class] += 1;
+                alphabet.required |= 1 << class;
+            }
+        }
+        alphabet
+    }
+}
+
+/// Returns `estimated_bits` if it is at least `required_bits`.
+fn check_entropy(estimated_bits: f64, required_bits: u32) -> Result<f64, PasswordError> {
+    if estimated_bits < f64::from(required_bits) {
+        return Err(PasswordError::InsufficientEntropy {
+            estimated_bits,
+            required_bits,
+        });
+    }
+    Ok(estimated_bits)
+}
+
+/// Draws an index uniformly from `0..len` with `rng`, rejecting the random values which would bias it towards small indices.
+fn uniform_index<R: CryptoRngCore + ?Sized>(
+    rng: &mut R,
+    len: usize,
+) -> Result<usize, PasswordError> {
+    let len = len as u64;
+    // The largest multiple of `len` not greater than 2^32.
+    let zone = (1 << 32) - (1 << 32) % len;
+    let mut bytes = Zeroizing::new([0; 4]);
+    loop {
+        rng.try_fill_bytes(&mut *bytes).map_err(PasswordError::Rng)?;
+        let value = u64::from(u32::from_le_bytes(*bytes));
+        if value < zone {
+            return Ok((value % len) as usize);
+        }
+    }
+}
@@ -0,0 +1,205 @@
+//! Hashing of passwords into [PHC strings](https://github.com/P-H-C/phc-string-format/blob/master/phc-sf-spec.md) with Argon2id ([RFC 9106](https://www.rfc-editor.org/rfc/rfc9106)),
+//! and verification of passwords against them.
+//!
+//! `hash_password_with()` and `verify_password()` on [`Secret`](crate::prelude::Secret), [`RTSecret`](crate::prelude::RTSecret) and [`SecrecySecret`](crate::prelude::SecrecySecret)
+//! holding a value implementing `AsRef<[u8]>`, e.g. a `String`, each consume one exposure of the password.
+//! A hash is a PHC string such as `$argon2id$v=19$m=19456,t=2,p=1$<salt>$<hash>`, which records the cost parameters and the random salt,
+//! so it can be stored as is, and a password is verified with the parameters of its stored hash.
+//!
+//! The hashes are compared in constant time, and the memory of Argon2, which is derived from the password, is zeroized after each hashing.
+//!
+//! Example:
+//! ```rust
+//! use sosecrets_rs::{
+//!     password_hash::Argon2Params,
+//!     prelude::{typenum::U1, RTSecret, Secret},
+//! };
+//!
+//! #[cfg(feature = "getrandom")]
+//! {
+//!     // On sign-up, the hash of the password is stored.
+//!     let password = Secret::<String, U1>::new("correct horse battery staple".to_owned());
+//!     let (_, stored_hash) = password.hash_password(&Argon2Params::DEFAULT);
+//!     let stored_hash = stored_hash.unwrap();
+//!     assert!(stored_hash.starts_with("$argon2id$v=19$m=19456,t=2,p=1$"));
+//!
+//!     // On login, the candidate password is verified against the stored hash.
+//!     let candidate = RTSecret::<String, U1>::new("correct horse battery staple".to_owned());
+//!     assert!(candidate.verify_password(&stored_hash).unwrap());
+//! }
+//! ```
+
+use alloc::{format, string::String, vec};
+
+use argon2::{
+    password_hash::{Output, PasswordHash, SaltString},
+    Algorithm, Argon2, Block, Params, Version,
+};
+use core::fmt;
+use rand_core::CryptoRngCore;
+use subtle::ConstantTimeEq;
+use zeroize::Zeroizing;
+
+/// The length of the random salt of a hash, in bytes.
+pub const SALT_LEN: usize = 16;
+
+/// The length of a hash, in bytes.
+pub const HASH_LEN: usize = 32;
+
+/// The maximum length of a hash in a PHC string which can be verified, in bytes.
+const MAX_HASH_LEN: usize = 64;
+
+/// The maximum length of a salt in a PHC string which can be verified, in bytes.
+const MAX_SALT_LEN: usize = 64;
+
+/// The cost parameters of Argon2id.
+///
+/// [`Argon2Params::DEFAULT`] follows the minimum recommended by OWASP: 19 MiB of memory, 2 iterations and 1 degree of parallelism.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Argon2Params {
+    memory_kib: u32,
+    iterations: u32,
+    parallelism: u32,
+}
+
+impl Argon2Params {
+    /// The default cost parameters, see [`Argon2Params`].
+    pub const DEFAULT: Self = Self::new(19 * 1024, 2, 1);
+
+    /// Creates new `Argon2Params` using `memory_kib` KiB of memory, `iterations` passes over it, and `parallelism` lanes.
+    ///
+    /// They are validated when hashing: the memory must be at least 8 KiB per lane, and the iterations and the parallelism at least 1.
+    pub const fn new(memory_kib: u32, iterations: u32, parallelism: u32) -> Self {
+        Self {
+            memory_kib,
+            iterations,
+            parallelism,
+        }
+    }
+
+    /// Returns the memory used, in KiB.
+    pub const fn memory_kib(&self) -> u32 {
+        self.memory_kib
+    }
+
+    /// Returns the number of iterations.
+    pub const fn iterations(&self) -> u32 {
+        self.iterations
+    }
+
+    /// Returns the degree of parallelism.
+    pub const fn parallelism(&self) -> u32 {
+        self.parallelism
+    }
+
+    /// Hashes `password` with a random salt from `rng` into a PHC string.
+    pub(crate) fn hash<R: CryptoRngCore + ?Sized>(
+        &self,
+        password: &[u8],
+        rng: &mut R,
+    ) -> Result<String, PasswordHashError> {
+        let params = Params::new(
+            self.memory_kib,
+            self.iterations,
+            self.parallelism,
+            Some(HASH_LEN),
+        )
+        .map_err(|_| PasswordHashError::InvalidParams)?;
+        let mut salt = [0; SALT_LEN];
+        rng.try_fill_bytes(&mut salt).map_err(PasswordHashError::Rng)?;
+        let mut hash = [0; HASH_LEN];
+        hash_into(params, password, &salt, &mut hash)?;
+
+        let salt = SaltString::encode_b64(&salt).map_err(|_| PasswordHashError::InvalidParams)?;
+        let hash = Output::new(&hash).map_err(|_| PasswordHashError::InvalidParams)?;
+        Ok(format!(
+            "${}$v={}$m={},t={},p={}${}${}",
+            argon2::ARGON2ID_IDENT,
+            Version::V0x13 as u32,
+            self.memory_kib,
+            self.iterations,
+            self.parallelism,
+            salt.as_str(),
+            hash
+        ))
+    }
+}
+
+impl Default for Argon2Params {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+/// Verifies `password` against the PHC string `stored_hash`, returning whether they match.
+pub(crate) fn verify(password: &[u8], stored_hash: &str) -> Result<bool, PasswordHashError> {
+    let stored_hash = PasswordHash::new(stored_hash).map_err(|_| PasswordHashError::InvalidHash)?;
+    if stored_hash.algorithm != argon2::ARGON2ID_IDENT
+        || stored_hash.version.is_some_and(|version| version != Version::V0x13 as u32)
+    {
+        return Err(PasswordHashError::UnsupportedHash);
+    }
+    let params = Params::try_from(&stored_hash).map_err(|_| PasswordHashError::InvalidHash)?;
+    let (salt, expected) = match (&stored_hash.salt, &stored_hash.hash) {
+        (Some(salt), Some(expected)) => (salt, expected.as_bytes()),
+        _ => return Err(PasswordHashError::InvalidHash),
+    };
+    let mut salt_buffer = [0; MAX_SALT_LEN];
+    let salt = salt
+        .decode_b64(&mut salt_buffer)
+        .map_err(|_| PasswordHashError::InvalidHash)?;
+    if expected.len() > MAX_HASH_LEN {
+        return Err(PasswordHashError::InvalidHash);
+    }
+    let mut computed = Zeroizing::new([0; MAX_HASH_LEN]);
+    let computed = &mut computed[..expected.len()];
+    hash_into(params, password, salt, computed)?;
+    Ok(computed.ct_eq(expected).into())
+}
+
+/// Hashes `password` with Argon2id, `params` and `salt` into `hash`, zeroizing the memory of Argon2 afterwards.
+fn hash_into(
+    params: Params,
+    password: &[u8],
+    salt: &[u8],
+    hash: &mut [u8],
+) -> Result<(), PasswordHashError> {
+    let mut memory = Zeroizing::new(vec![Block::default(); params.block_count()]);
+    Argon2::new(Algorithm::Argon2id, Version::V0x13, params)
+        .hash_password_into_with_memory(password, salt, hash, &mut *memory)
+        .map_err(|error| match error {
+            argon2::Error::PwdTooLong => PasswordHashError::PasswordTooLong,
+            _ => PasswordHashError::InvalidParams,
+        })
+}
+
+/// The error returned when a password cannot be hashed or verified.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum PasswordHashError {
+    /// The cost parameters are invalid.
+    InvalidParams,
+    /// The password is longer than 2^32 - 1 bytes.
+    PasswordTooLong,
+    /// The stored hash is not a valid PHC string of Argon2.
+    InvalidHash,
+    /// The stored hash is not an Argon2id hash of version 19.
+    UnsupportedHash,
+    /// The random number generator failed.
+    Rng(rand_core::Error),
+}
+
+impl fmt::Display for PasswordHashError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidParams => f.write_str("the Argon2 cost parameters are invalid"),
+            Self::PasswordTooLong => f.write_str("the password is too long"),
+            Self::InvalidHash => f.write_str("the stored password hash is not a valid Argon2 PHC string"),
+            Self::UnsupportedHash => f.write_str("the stored password hash is not an Argon2id hash of version 19"),
+            Self::Rng(err) => write!(f, "the random number generator failed: {}", err),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for PasswordHashError {}
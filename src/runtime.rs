@@ -1,4 +1,16 @@
+#[cfg(all(feature = "command", unix))]
+pub mod command;
 pub mod error;
+#[cfg(all(feature = "keyring", target_os = "linux"))]
+pub mod keyring;
+pub mod non_panicking;
+#[cfg(feature = "sealed")]
+pub mod sealed;
+pub use non_panicking::*;
 pub mod secret;
 pub use secret::*;
 pub mod traits;
+
+/// Wraps the fields of a struct annotated with `#[secret(max = N)]` in `RTSecret`, see [`RTSecret`] and [`SecrecySecret`].
+#[cfg(feature = "derive")]
+pub use sosecrets_rs_derive::secret_struct;
@@ -0,0 +1,275 @@
+//! Passing runtime secrets to child processes.
+//!
+//! [`SecretCommandExt`] extends [`std::process::Command`] to pass the secret of an [`RTSecret`] or a [`SecrecySecret`] holding bytes,
+//! i.e. a value implementing `AsRef<[u8]>`, to the child process:
+//! - on a file descriptor inherited by the child, with [`SecretCommandExt::secret_fd`];
+//! - on the standard input of the child, with [`SecretCommandExt::spawn_with_secret_stdin`].
+//!
+//! Each of them counts as one exposure of the secret.
+//! The bytes of the secret are written straight from the exposed secret into the kernel, without any intermediate buffer in the parent process.
+//!
+//! Note: There is no way to pass a secret in an environment variable, since [`Command`] keeps its own copy of the value of every environment variable,
+//! which the standard library never zeroizes, and the environment of a process can be read by other processes of the same user, e.g. from `/proc/<pid>/environ`.
+//!
+//! Example:
+//! ```rust
+//! use std::process::{Command, Stdio};
+//!
+//! use sosecrets_rs::{
+//!     prelude::{typenum::U2, RTSecret},
+//!     runtime::command::SecretCommandExt,
+//! };
+//!
+//! let secret = RTSecret::<_, U2>::new(*b"hunter2");
+//! let output = Command::new("/bin/sh")
+//!     .args(["-c", "wc -c <&3"])
+//!     .secret_fd(3, &secret)
+//!     .unwrap()
+//!     .output()
+//!     .unwrap();
+//! assert_eq!(String::from_utf8(output.stdout).unwrap().trim(), "7");
+//!
+//! let child = Command::new("/bin/sh")
+//!     .args(["-c", "wc -c"])
+//!     .stdout(Stdio::piped())
+//!     .spawn_with_secret_stdin(&secret)
+//!     .unwrap();
+//! let output = child.wait_with_output().unwrap();
+//! assert_eq!(String::from_utf8(output.stdout).unwrap().trim(), "7");
+//! assert!(secret.is_exhausted());
+//! ```
+
+use core::{
+    convert::Infallible,
+    fmt::{self, Debug},
+};
+use std::{
+    io::{self, Write},
+    os::unix::{
+        io::{AsRawFd, OwnedFd, RawFd},
+        net::UnixStream,
+        process::CommandExt,
+    },
+    process::{Child, Command, Stdio},
+};
+
+use typenum::{IsGreater, True, Unsigned, U0};
+#[cfg(feature = "zeroize")]
+use zeroize::Zeroize;
+
+use crate::{
+    runtime::{
+        error::ExposeSecretError,
+        secret::{RTSecret, SecrecySecret},
+        traits::ExhaustionPolicy,
+    },
+    traits::{
+        ChooseMinimallyRepresentableUInt,
+        __private::{SealedToken, SealedTrait},
+    },
+};
+
+/// A runtime secret which can be passed to a child process with [`SecretCommandExt`].
+///
+/// This is a 'Sealed' trait, it is implemented by [`RTSecret`] and [`SecrecySecret`] holding a value implementing `AsRef<[u8]>`.
+pub trait ChildProcessSecret: SealedTrait {
+    /// The error returned if the secret cannot be exposed, e.g. [`ExposeSecretError`] for `RTSecret`.
+    type Error: fmt::Display + Debug;
+
+    /// Exposes the bytes of the secret, counting one exposure.
+    #[doc(hidden)]
+    #[track_caller]
+    fn try_expose_bytes<ReturnType>(
+        &self,
+        scope: impl FnOnce(&[u8]) -> ReturnType,
+        _: SealedToken,
+    ) -> Result<ReturnType, Self::Error>;
+}
+
+impl<
+        #[cfg(feature = "zeroize")] T: Zeroize + AsRef<[u8]>,
+        #[cfg(not(feature = "zeroize"))] T: AsRef<[u8]>,
+        MEC: ChooseMinimallyRepresentableUInt,
+        EP: ExhaustionPolicy,
+    > SealedTrait for RTSecret<T, MEC, EP>
+{
+}
+
+impl<
+        #[cfg(feature = "zeroize")] T: Zeroize + AsRef<[u8]>,
+        #[cfg(not(feature = "zeroize"))] T: AsRef<[u8]>,
+        MEC: ChooseMinimallyRepresentableUInt + Unsigned + IsGreater<U0, Output = True> + Debug,
+        EP: ExhaustionPolicy,
+    > ChildProcessSecret for RTSecret<T, MEC, EP>
+{
+    type Error = ExposeSecretError<MEC>;
+
+    #[track_caller]
+    fn try_expose_bytes<ReturnType>(
+        &self,
+        scope: impl FnOnce(&[u8]) -> ReturnType,
+        _: SealedToken,
+    ) -> Result<ReturnType, Self::Error> {
+        self.checked_expose_secret(|exposed_secret| scope((*exposed_secret).as_ref()))
+    }
+}
+
+impl<
+        #[cfg(feature = "zeroize")] T: Zeroize + AsRef<[u8]>,
+        #[cfg(not(feature = "zeroize"))] T: AsRef<[u8]>,
+    > ChildProcessSecret for SecrecySecret<T>
+{
+    type Error = Infallible;
+
+    fn try_expose_bytes<ReturnType>(
+        &self,
+        scope: impl FnOnce(&[u8]) -> ReturnType,
+        _: SealedToken,
+    ) -> Result<ReturnType, Self::Error> {
+        Ok(scope(self.inner().as_ref()))
+    }
+}
+
+/// An error of [`SecretCommandExt::secret_fd`] and [`SecretCommandExt::spawn_with_secret_stdin`].
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum CommandSecretError<E> {
+    /// The secret cannot be exposed, e.g. because the `RTSecret` has already been exposed for `MEC` times.
+    Exposure(E),
+    /// The secret cannot be passed to the child process, e.g. because the command cannot be spawned.
+    Io(io::Error),
+}
+
+impl<E: fmt::Display> fmt::Display for CommandSecretError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Exposure(err) => err.fmt(f),
+            Self::Io(err) => write!(
+                f,
+                "the secret cannot be passed to the child process: {}",
+                err
+            ),
+        }
+    }
+}
+
+impl<E: std::error::Error + 'static> std::error::Error for CommandSecretError<E> {}
+
+/// An extension trait for [`std::process::Command`] passing secrets to the child process, see the [module documentation](self).
+///
+/// This is a 'Sealed' trait, it is only implemented by [`Command`].
+///
+/// Neither method copies the secret out of the exposed secret.
+pub trait SecretCommandExt: SealedTrait {
+    /// Passes the secret to the child process on the file descriptor `fd`, which counts as one exposure of the secret.
+    ///
+    /// The secret is written right away into one end of a Unix socket pair, whose other end becomes the file descriptor `fd` of the child process;
+    /// it reads like a pipe, i.e. the child process reads the bytes of the secret followed by the end of file.
+    /// The file descriptor is only inherited by the child processes of this `Command`, and only the first one spawned can read the secret.
+    ///
+    /// `fd` should be greater than 2, i.e. not one of the standard streams, and distinct from the `fd` of any other call on the same `Command`.
+    ///
+    /// # Returns
+    /// - `Ok`: The `Command`, for chaining.
+    /// - `Err`: [`CommandSecretError::Exposure`] if the secret cannot be exposed,
+    ///   or [`CommandSecretError::Io`] if the socket pair cannot be created, or the secret does not fit in its buffer, i.e. about 200 KiB on Linux.
+    #[track_caller]
+    fn secret_fd<S: ChildProcessSecret>(
+        &mut self,
+        fd: RawFd,
+        secret: &S,
+    ) -> Result<&mut Self, CommandSecretError<S::Error>>;
+
+    /// Spawns the child process with a piped standard input, writes the secret to it and closes it, which counts as one exposure of the secret.
+    ///
+    /// The secret is written before this returns, so the child process must read its standard input
+    /// before filling the pipes of its standard output and error, if they are piped, with more than their capacity.
+    /// If the child process closes its standard input before reading the whole secret, the rest of it is silently discarded.
+    ///
+    /// # Returns
+    /// - `Ok`: The spawned child process, whose `stdin` is `None`.
+    /// - `Err`: [`CommandSecretError::Exposure`] if the secret cannot be exposed, in which case the command is not spawned,
+    ///   or [`CommandSecretError::Io`] if the command cannot be spawned or the secret cannot be written, in which case the child process is killed.
+    #[track_caller]
+    fn spawn_with_secret_stdin<S: ChildProcessSecret>(
+        &mut self,
+        secret: &S,
+    ) -> Result<Child, CommandSecretError<S::Error>>;
+}
+
+impl SealedTrait for Command {}
+
+impl SecretCommandExt for Command {
+    #[track_caller]
+    fn secret_fd<S: ChildProcessSecret>(
+        &mut self,
+        fd: RawFd,
+        secret: &S,
+    ) -> Result<&mut Self, CommandSecretError<S::Error>> {
+        let (reader, mut writer) = UnixStream::pair().map_err(CommandSecretError::Io)?;
+        // A secret larger than the buffer of the socket fails instead of blocking, since nothing reads it yet.
+        writer
+            .set_nonblocking(true)
+            .map_err(CommandSecretError::Io)?;
+        secret
+            .try_expose_bytes(|bytes| writer.write_all(bytes), SealedToken {})
+            .map_err(CommandSecretError::Exposure)?
+            .map_err(|err| match err.kind() {
+                io::ErrorKind::WouldBlock => CommandSecretError::Io(io::Error::new(
+                    io::ErrorKind::InvalidInput,
+                    "the secret does not fit in the buffer of the socket",
+                )),
+                _ => CommandSecretError::Io(err),
+            })?;
+        drop(writer);
+
+        let reader = OwnedFd::from(reader);
+        let inherit = move || {
+            let reader_fd = reader.as_raw_fd();
+            // SAFETY: `dup2` and `fcntl` are async-signal-safe, and `reader_fd` is open until the `Command` is dropped.
+            // The duplicate made by `dup2` does not have the flag `FD_CLOEXEC`, which is cleared if `fd` is `reader_fd` itself.
+            let result = unsafe {
+                if reader_fd == fd {
+                    libc::fcntl(fd, libc::F_SETFD, 0)
+                } else {
+                    libc::dup2(reader_fd, fd)
+                }
+            };
+            if result == -1 {
+                Err(io::Error::last_os_error())
+            } else {
+                Ok(())
+            }
+        };
+        // SAFETY: The closure only calls async-signal-safe functions, and does not allocate.
+        unsafe { self.pre_exec(inherit) };
+        Ok(self)
+    }
+
+    #[track_caller]
+    fn spawn_with_secret_stdin<S: ChildProcessSecret>(
+        &mut self,
+        secret: &S,
+    ) -> Result<Child, CommandSecretError<S::Error>> {
+        secret
+            .try_expose_bytes(
+                |bytes| {
+                    let mut child = self.stdin(Stdio::piped()).spawn()?;
+                    let mut stdin = child.stdin.take().expect("the standard input is piped");
+                    match stdin.write_all(bytes) {
+                        Ok(()) => Ok(child),
+                        Err(err) if err.kind() == io::ErrorKind::BrokenPipe => Ok(child),
+                        Err(err) => {
+                            drop(stdin);
+                            let _ = child.kill();
+                            let _ = child.wait();
+                            Err(err)
+                        }
+                    }
+                },
+                SealedToken {},
+            )
+            .map_err(CommandSecretError::Exposure)?
+            .map_err(CommandSecretError::Io)
+    }
+}
@@ -1,3 +1,5 @@
+use core::panic::Location;
+
 use crate::traits::ChooseMinimallyRepresentableUInt;
 
 /// An error representing that the secret has been exposed more times than allowed.
@@ -5,20 +7,146 @@ use crate::traits::ChooseMinimallyRepresentableUInt;
 #[non_exhaustive]
 pub enum ExposeSecretError<MEC: ChooseMinimallyRepresentableUInt> {
     ExposeMoreThanMaximallyAllow(ExposeMoreThanMaximallyAllowError<MEC>),
+    InsufficientExposureBudget(InsufficientExposureBudgetError<MEC>),
+    ZeroizedOnExhaustion(ZeroizedOnExhaustionError<MEC>),
+    TamperedCiphertext(TamperedCiphertextError<MEC>),
 }
 
 /// An error representing that the secret has been exposed more times than allowed.
 #[derive(Debug)]
+#[non_exhaustive]
 pub struct ExposeMoreThanMaximallyAllowError<MEC: ChooseMinimallyRepresentableUInt> {
     pub mec: <MEC as ChooseMinimallyRepresentableUInt>::Output,
     pub ec: <MEC as ChooseMinimallyRepresentableUInt>::Output,
+    /// An optional label identifying the secret, see [`RTSecret::with_label`](crate::runtime::RTSecret::with_label).
+    pub label: Option<&'static str>,
+    /// The location of the failing call.
+    pub location: &'static Location<'static>,
 }
 
 impl<MEC: ChooseMinimallyRepresentableUInt> core::fmt::Display
     for ExposeMoreThanMaximallyAllowError<MEC>
 {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        write!(f, "`Secret` is exposed more than what it is maximally allowed to; it is exposed for {} times and it is only allowed to be exposed for {} times", self.ec, self.mec)
+        write!(f, "`Secret` is exposed more than what it is maximally allowed to; it is exposed for {} times and it is only allowed to be exposed for {} times", self.ec, self.mec)?;
+        fmt_label_and_location(f, self.label, self.location)
+    }
+}
+
+/// An error representing that the remaining exposure budget of the secret is too small to be split as requested.
+#[derive(Debug)]
+#[non_exhaustive]
+pub struct InsufficientExposureBudgetError<MEC: ChooseMinimallyRepresentableUInt> {
+    pub mec: <MEC as ChooseMinimallyRepresentableUInt>::Output,
+    pub ec: <MEC as ChooseMinimallyRepresentableUInt>::Output,
+    pub requested: u64,
+    /// An optional label identifying the secret, see [`RTSecret::with_label`](crate::runtime::RTSecret::with_label).
+    pub label: Option<&'static str>,
+    /// The location of the failing call.
+    pub location: &'static Location<'static>,
+}
+
+impl<MEC: ChooseMinimallyRepresentableUInt> core::fmt::Display
+    for InsufficientExposureBudgetError<MEC>
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "`Secret` cannot hand out {} exposures; it is exposed for {} times and it is only allowed to be exposed for {} times", self.requested, self.ec, self.mec)?;
+        fmt_label_and_location(f, self.label, self.location)
+    }
+}
+
+/// An error representing that the secret has been zeroized after being exposed for the maximally allowed number of times.
+#[derive(Debug)]
+#[non_exhaustive]
+pub struct ZeroizedOnExhaustionError<MEC: ChooseMinimallyRepresentableUInt> {
+    pub mec: <MEC as ChooseMinimallyRepresentableUInt>::Output,
+    pub ec: <MEC as ChooseMinimallyRepresentableUInt>::Output,
+    /// An optional label identifying the secret, see [`RTSecret::with_label`](crate::runtime::RTSecret::with_label).
+    pub label: Option<&'static str>,
+    /// The location of the failing call.
+    pub location: &'static Location<'static>,
+}
+
+impl<MEC: ChooseMinimallyRepresentableUInt> core::fmt::Display
+    for ZeroizedOnExhaustionError<MEC>
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "`Secret` has been zeroized after being exposed for {} times and it is only allowed to be exposed for {} times", self.ec, self.mec)?;
+        fmt_label_and_location(f, self.label, self.location)
+    }
+}
+
+/// An error representing that the authentication tag of a sealed secret does not verify, i.e. that its memory has been tampered with,
+/// see [`SealedSecret`](crate::runtime::sealed::SealedSecret).
+#[derive(Debug)]
+#[non_exhaustive]
+pub struct TamperedCiphertextError<MEC: ChooseMinimallyRepresentableUInt> {
+    pub mec: <MEC as ChooseMinimallyRepresentableUInt>::Output,
+    pub ec: <MEC as ChooseMinimallyRepresentableUInt>::Output,
+    /// An optional label identifying the secret, see [`RTSecret::with_label`](crate::runtime::RTSecret::with_label).
+    pub label: Option<&'static str>,
+    /// The location of the failing call.
+    pub location: &'static Location<'static>,
+}
+
+impl<MEC: ChooseMinimallyRepresentableUInt> core::fmt::Display
+    for TamperedCiphertextError<MEC>
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "`Secret` cannot be unsealed because the authentication tag of its ciphertext does not verify, its memory has been tampered with; it is exposed for {} times and it is only allowed to be exposed for {} times", self.ec, self.mec)?;
+        fmt_label_and_location(f, self.label, self.location)
+    }
+}
+
+impl<MEC: ChooseMinimallyRepresentableUInt> ExposeSecretError<MEC> {
+    /// Attaches a label identifying the secret to the error, replacing the label of the secret attached with [`RTSecret::with_label`](crate::runtime::RTSecret::with_label), if any.
+    ///
+    /// Example:
+    /// ```rust
+    /// use sosecrets_rs::{
+    ///     prelude::{typenum::U1, RTSecret},
+    ///     runtime::traits::RTExposeSecret,
+    /// };
+    ///
+    /// let secret = RTSecret::<i32, U1>::new(69);
+    /// let _ = secret.expose_secret(|exposed_secret| *exposed_secret);
+    ///
+    /// let err = secret
+    ///     .try_expose_secret(|exposed_secret| *exposed_secret)
+    ///     .map_err(|err| err.with_label("db_password"))
+    ///     .unwrap_err();
+    /// assert_eq!(err.label(), Some("db_password"));
+    /// assert_eq!(err.location().file(), file!());
+    /// ```
+    pub fn with_label(mut self, label: &'static str) -> Self {
+        match &mut self {
+            Self::ExposeMoreThanMaximallyAllow(err) => err.label = Some(label),
+            Self::InsufficientExposureBudget(err) => err.label = Some(label),
+            Self::ZeroizedOnExhaustion(err) => err.label = Some(label),
+            Self::TamperedCiphertext(err) => err.label = Some(label),
+        }
+        self
+    }
+
+    /// Returns the label identifying the secret, if one has been attached with [`RTSecret::with_label`](crate::runtime::RTSecret::with_label)
+    /// or [`ExposeSecretError::with_label`].
+    pub fn label(&self) -> Option<&'static str> {
+        match self {
+            Self::ExposeMoreThanMaximallyAllow(err) => err.label,
+            Self::InsufficientExposureBudget(err) => err.label,
+            Self::ZeroizedOnExhaustion(err) => err.label,
+            Self::TamperedCiphertext(err) => err.label,
+        }
+    }
+
+    /// Returns the location of the failing call.
+    pub fn location(&self) -> &'static Location<'static> {
+        match self {
+            Self::ExposeMoreThanMaximallyAllow(err) => err.location,
+            Self::InsufficientExposureBudget(err) => err.location,
+            Self::ZeroizedOnExhaustion(err) => err.location,
+            Self::TamperedCiphertext(err) => err.location,
+        }
     }
 }
 
@@ -26,6 +154,59 @@ impl<MEC: ChooseMinimallyRepresentableUInt> core::fmt::Display for ExposeSecretE
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         match self {
             Self::ExposeMoreThanMaximallyAllow(err) => err.fmt(f),
+            Self::InsufficientExposureBudget(err) => err.fmt(f),
+            Self::ZeroizedOnExhaustion(err) => err.fmt(f),
+            Self::TamperedCiphertext(err) => err.fmt(f),
+        }
+    }
+}
+
+/// Formats an optional label identifying a secret as "` (label: `{label}`)`", or nothing.
+pub(crate) struct Label(pub(crate) Option<&'static str>);
+
+impl core::fmt::Display for Label {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self.0 {
+            Some(label) => write!(f, " (label: `{}`)", label),
+            None => Ok(()),
         }
     }
 }
+
+fn fmt_label_and_location(
+    f: &mut core::fmt::Formatter<'_>,
+    label: Option<&'static str>,
+    location: &'static Location<'static>,
+) -> core::fmt::Result {
+    write!(f, "{} at {}", Label(label), location)
+}
+
+#[cfg(feature = "std")]
+impl<MEC: ChooseMinimallyRepresentableUInt + core::fmt::Debug> std::error::Error
+    for ExposeSecretError<MEC>
+{
+}
+
+#[cfg(feature = "std")]
+impl<MEC: ChooseMinimallyRepresentableUInt + core::fmt::Debug> std::error::Error
+    for ExposeMoreThanMaximallyAllowError<MEC>
+{
+}
+
+#[cfg(feature = "std")]
+impl<MEC: ChooseMinimallyRepresentableUInt + core::fmt::Debug> std::error::Error
+    for InsufficientExposureBudgetError<MEC>
+{
+}
+
+#[cfg(feature = "std")]
+impl<MEC: ChooseMinimallyRepresentableUInt + core::fmt::Debug> std::error::Error
+    for ZeroizedOnExhaustionError<MEC>
+{
+}
+
+#[cfg(feature = "std")]
+impl<MEC: ChooseMinimallyRepresentableUInt + core::fmt::Debug> std::error::Error
+    for TamperedCiphertextError<MEC>
+{
+}
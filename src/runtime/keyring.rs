@@ -0,0 +1,341 @@
+//! Runtime secrets kept in the Linux kernel keyrings.
+//!
+//! A [`KeyringSecret`] refers to a `"user"` key in one of the calling thread's [`Keyring`]s, e.g. its session keyring,
+//! so that the secret can outlive the process and be shared with the processes sharing the same keyring.
+//! The secret material stays in the kernel: every exposure reads it into a zeroizing scratch buffer,
+//! which only lives as long as the closure of `expose_secret`, and the exposures are counted like those of [`RTSecret`](crate::prelude::RTSecret).
+//! It only needs the `add_key(2)` and `keyctl(2)` system calls, available on any stock Linux kernel.
+
+use core::{
+    cell::Cell,
+    ffi::{c_char, c_long, c_void},
+    fmt::{self, Debug},
+    panic::Location,
+};
+use std::{ffi::CString, io, vec::Vec};
+
+use typenum::{IsGreater, True, Unsigned, U0};
+use zeroize::Zeroizing;
+
+use crate::{
+    runtime::{
+        error::{ExposeMoreThanMaximallyAllowError, ExposeSecretError},
+        secret::RTExposedSecret,
+    },
+    traits::{__private::SealedToken, ChooseMinimallyRepresentableUInt},
+};
+
+/// The type of the keys holding the secrets.
+const KEY_TYPE: &[u8] = b"user\0";
+
+/// A keyring of the calling thread, in which a [`KeyringSecret`] is added or searched for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Keyring {
+    /// The thread keyring, only available to the calling thread.
+    Thread,
+    /// The process keyring, shared by the threads of the calling process.
+    Process,
+    /// The session keyring, inherited by the child processes, e.g. those started by the same login session.
+    Session,
+    /// The user keyring, shared by all the processes of the same user.
+    User,
+    /// The user session keyring, the session keyring of the processes of the same user which have no other.
+    UserSession,
+}
+
+impl Keyring {
+    /// Returns the special key serial number of the keyring.
+    const fn id(self) -> i32 {
+        match self {
+            Self::Thread => libc::KEY_SPEC_THREAD_KEYRING,
+            Self::Process => libc::KEY_SPEC_PROCESS_KEYRING,
+            Self::Session => libc::KEY_SPEC_SESSION_KEYRING,
+            Self::User => libc::KEY_SPEC_USER_KEYRING,
+            Self::UserSession => libc::KEY_SPEC_USER_SESSION_KEYRING,
+        }
+    }
+}
+
+/// An error of [`KeyringSecret::try_expose_secret`].
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum KeyringError<MEC: ChooseMinimallyRepresentableUInt> {
+    /// The `KeyringSecret` has already been exposed for `MEC` times.
+    Exposure(ExposeSecretError<MEC>),
+    /// The kernel failed to read the key, e.g. because it has been revoked or it has expired.
+    Kernel(io::Error),
+}
+
+impl<MEC: ChooseMinimallyRepresentableUInt> fmt::Display for KeyringError<MEC> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Exposure(err) => err.fmt(f),
+            Self::Kernel(err) => write!(f, "the key of the `KeyringSecret` cannot be read: {}", err),
+        }
+    }
+}
+
+impl<MEC: ChooseMinimallyRepresentableUInt + Debug + 'static> std::error::Error
+    for KeyringError<MEC>
+{
+}
+
+/// A runtime secret kept in a Linux kernel keyring, see the [module documentation](self).
+///
+/// Dropping a `KeyringSecret` leaves its key in the keyring, where it lives until it is revoked with [`KeyringSecret::revoke`],
+/// it expires after [`KeyringSecret::set_timeout`], or the keyring is destroyed.
+///
+/// Example:
+/// ```rust,no_run
+/// use sosecrets_rs::{
+///     prelude::typenum::U2,
+///     runtime::keyring::{Keyring, KeyringSecret},
+/// };
+///
+/// let secret = KeyringSecret::<U2>::add(Keyring::Session, "db-password", b"hunter2").unwrap();
+/// secret.set_timeout(60).unwrap();
+///
+/// // E.g. in a child process started by the same session.
+/// let found = KeyringSecret::<U2>::find(Keyring::Session, "db-password").unwrap();
+/// assert!(found.expose_secret(|exposed_secret| &*exposed_secret == b"hunter2"));
+/// found.revoke().unwrap();
+/// ```
+pub struct KeyringSecret<MEC: ChooseMinimallyRepresentableUInt> {
+    /// The serial number of the key, which is not secret.
+    serial: i32,
+    /// The number of times the payload of the key has been read.
+    ec: Cell<<MEC as ChooseMinimallyRepresentableUInt>::Output>,
+}
+
+impl<MEC: ChooseMinimallyRepresentableUInt + Unsigned> KeyringSecret<MEC> {
+    /// Adds `payload` to `keyring` as a `"user"` key described by `description`, replacing the payload of an existing key with the same description.
+    ///
+    /// # Errors
+    /// If `description` contains a NUL byte, or the kernel refuses to add the key, e.g. because `payload` is larger than 32 KiB
+    /// or the key quota of the user is exceeded.
+    pub fn add(keyring: Keyring, description: &str, payload: &[u8]) -> io::Result<Self> {
+        let description = c_string(description)?;
+        // SAFETY: The strings are NUL-terminated, and `payload` is valid for `payload.len()` bytes.
+        let serial = unsafe {
+            libc::syscall(
+                libc::SYS_add_key,
+                KEY_TYPE.as_ptr().cast::<c_char>(),
+                description.as_ptr(),
+                payload.as_ptr().cast::<c_void>(),
+                payload.len(),
+                keyring.id(),
+            )
+        };
+        Self::from_result(serial)
+    }
+
+    /// Finds the `"user"` key described by `description` in `keyring`, or in the keyrings linked to it.
+    ///
+    /// # Errors
+    /// If `description` contains a NUL byte, or no such key can be found, e.g. because it has been revoked or it has expired.
+    pub fn find(keyring: Keyring, description: &str) -> io::Result<Self> {
+        let description = c_string(description)?;
+        // SAFETY: The strings are NUL-terminated.
+        let serial = unsafe {
+            libc::syscall(
+                libc::SYS_keyctl,
+                libc::KEYCTL_SEARCH,
+                keyring.id(),
+                KEY_TYPE.as_ptr().cast::<c_char>(),
+                description.as_ptr(),
+                0 as libc::c_int,
+            )
+        };
+        Self::from_result(serial)
+    }
+
+    /// Creates a `KeyringSecret` from the result of `add_key(2)` or `keyctl(2)`.
+    fn from_result(serial: c_long) -> io::Result<Self> {
+        if serial < 0 {
+            return Err(io::Error::last_os_error());
+        }
+        Ok(Self {
+            serial: serial as i32,
+            ec: Cell::new(MEC::ZERO),
+        })
+    }
+
+    /// Returns the serial number of the key, e.g. to inspect it with `keyctl(1)`.
+    #[inline(always)]
+    pub const fn serial(&self) -> i32 {
+        self.serial
+    }
+
+    /// Retrieves the current exposure count of the `KeyringSecret`, i.e. the number of times its key has been read,
+    /// see [`RTSecret::exposure_count`](crate::prelude::RTSecret::exposure_count).
+    #[inline(always)]
+    pub fn exposure_count(&self) -> <MEC as ChooseMinimallyRepresentableUInt>::Output {
+        self.ec.get()
+    }
+
+    /// Returns `true` if the `KeyringSecret` has been exposed for `MEC` times,
+    /// see [`RTSecret::is_exhausted`](crate::prelude::RTSecret::is_exhausted).
+    #[inline(always)]
+    pub fn is_exhausted(&self) -> bool {
+        self.ec.get() >= MEC::cast_unsigned_to_self_type::<MEC>(SealedToken {})
+    }
+
+    /// Sets the key to expire in `seconds` seconds, after which it cannot be read anymore; `0` removes the expiry.
+    ///
+    /// # Errors
+    /// If the kernel refuses to set the timeout, e.g. because the key has been revoked.
+    pub fn set_timeout(&self, seconds: u32) -> io::Result<()> {
+        // SAFETY: `KEYCTL_SET_TIMEOUT` takes a key serial number and a number of seconds.
+        let result = unsafe {
+            libc::syscall(
+                libc::SYS_keyctl,
+                libc::KEYCTL_SET_TIMEOUT,
+                self.serial(),
+                seconds,
+            )
+        };
+        check(result)
+    }
+
+    /// Revokes the key, so that it cannot be read anymore by any process, and consumes the `KeyringSecret`.
+    ///
+    /// # Errors
+    /// If the kernel refuses to revoke the key, e.g. because it has already been revoked.
+    pub fn revoke(self) -> io::Result<()> {
+        // SAFETY: `KEYCTL_REVOKE` takes a key serial number.
+        let result = unsafe { libc::syscall(libc::SYS_keyctl, libc::KEYCTL_REVOKE, self.serial()) };
+        check(result)
+    }
+}
+
+impl<MEC> KeyringSecret<MEC>
+where
+    MEC: ChooseMinimallyRepresentableUInt + Unsigned + IsGreater<U0, Output = True> + Debug,
+{
+    /// Exposes the secret with runtime checking that the exposure count is not more than the maximally allowed exposure count represented by the type parameter `MEC`,
+    /// like [`RTExposeSecret::expose_secret`](crate::runtime::traits::RTExposeSecret::expose_secret).
+    /// The key is read into a scratch buffer, which is zeroized as soon as `scope` returns.
+    /// Only the successful reads of the key count as exposures, so that a key which cannot be read, e.g. because it has been revoked, does not use up the budget.
+    /// Note: It is impossible to return the 'exposed secret' as the return value of the closure.
+    ///
+    /// # Panics
+    /// If the `KeyringSecret` has already been exposed for `MEC` times, or the key cannot be read.
+    #[inline(always)]
+    #[track_caller]
+    pub fn expose_secret<ReturnType, ClosureType>(&self, scope: ClosureType) -> ReturnType
+    where
+        for<'brand> ClosureType: FnOnce(RTExposedSecret<'brand, &'brand [u8]>) -> ReturnType,
+    {
+        match self.try_expose_secret(scope) {
+            Ok(returned_value) => returned_value,
+            Err(error) => panic!("{}", error),
+        }
+    }
+
+    /// Tries to expose the secret with runtime checking, see [`KeyringSecret::expose_secret`].
+    ///
+    /// # Returns
+    /// - `Ok`: The value returned by the closure.
+    /// - `Err`: If the `KeyringSecret` has already been exposed for `MEC` times, or the key cannot be read.
+    #[inline(always)]
+    #[track_caller]
+    pub fn try_expose_secret<ReturnType, ClosureType>(
+        &self,
+        scope: ClosureType,
+    ) -> Result<ReturnType, KeyringError<MEC>>
+    where
+        for<'brand> ClosureType: FnOnce(RTExposedSecret<'brand, &'brand [u8]>) -> ReturnType,
+    {
+        let ec = self.ec.get();
+        let mec = MEC::cast_unsigned_to_self_type::<MEC>(SealedToken {});
+        if ec >= mec {
+            return Err(KeyringError::Exposure(
+                ExposeSecretError::ExposeMoreThanMaximallyAllow(
+                    ExposeMoreThanMaximallyAllowError {
+                        mec,
+                        ec,
+                        label: None,
+                        location: Location::caller(),
+                    },
+                ),
+            ));
+        }
+        let payload = read_key(self.serial).map_err(KeyringError::Kernel)?;
+        self.ec.set(ec + MEC::ONE);
+        Ok(scope(RTExposedSecret::new(payload.as_slice())))
+    }
+}
+
+impl<MEC: ChooseMinimallyRepresentableUInt> Debug for KeyringSecret<MEC> {
+    /// Formats the `KeyringSecret` with the serial number of its key, which is not secret.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("KeyringSecret")
+            .field("serial", &self.serial)
+            .finish()
+    }
+}
+
+/// Reads the payload of the key `serial` into a zeroizing buffer.
+fn read_key(serial: i32) -> io::Result<Zeroizing<Vec<u8>>> {
+    let mut payload = Zeroizing::new(Vec::<u8>::new());
+    loop {
+        // SAFETY: `payload` is valid for `payload.len()` bytes, the kernel writes at most as many.
+        let len = unsafe {
+            libc::syscall(
+                libc::SYS_keyctl,
+                libc::KEYCTL_READ,
+                serial,
+                payload.as_mut_ptr().cast::<c_char>(),
+                payload.len(),
+            )
+        };
+        if len < 0 {
+            return Err(io::Error::last_os_error());
+        }
+        let len = len as usize;
+        if len <= payload.len() {
+            payload.truncate(len);
+            return Ok(payload);
+        }
+        // The key is larger than the buffer, e.g. on the first call or if it has been updated in the meantime;
+        // the previous buffer is zeroized when it is replaced.
+        payload = Zeroizing::new(std::vec![0; len]);
+    }
+}
+
+fn c_string(description: &str) -> io::Result<CString> {
+    CString::new(description).map_err(|err| io::Error::new(io::ErrorKind::InvalidInput, err))
+}
+
+fn check(result: c_long) -> io::Result<()> {
+    if result < 0 {
+        Err(io::Error::last_os_error())
+    } else {
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use typenum::U2;
+
+    use super::*;
+
+    #[test]
+    fn test_round_trip_budget_and_revoke() {
+        let description = "sosecrets-rs-test_round_trip_budget_and_revoke";
+        let secret = KeyringSecret::<U2>::add(Keyring::Process, description, b"hunter2").unwrap();
+        assert!(secret.expose_secret(|exposed_secret| &*exposed_secret == b"hunter2"));
+        assert_eq!(secret.exposure_count(), 1);
+
+        let found = KeyringSecret::<U2>::find(Keyring::Process, description).unwrap();
+        secret.revoke().unwrap();
+        assert!(matches!(
+            found.try_expose_secret(|exposed_secret| exposed_secret.len()),
+            Err(KeyringError::Kernel(_))
+        ));
+        // A key which cannot be read does not use up the budget.
+        assert_eq!(found.exposure_count(), 0);
+        assert!(!found.is_exhausted());
+    }
+}
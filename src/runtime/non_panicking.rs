@@ -0,0 +1,349 @@
+use core::fmt::Debug;
+
+use crate::{
+    runtime::{
+        error,
+        secret::{RTExposedSecret, RTExposedSecretMut, RTSecret, RetainOnExhaustion},
+        traits::ExhaustionPolicy,
+    },
+    traits::{AppendableSecret, ChooseMinimallyRepresentableUInt},
+};
+use typenum::{IsGreater, True, Unsigned, U0};
+#[cfg(feature = "zeroize")]
+use zeroize::Zeroize;
+
+#[cfg(all(feature = "alloc", feature = "zeroize"))]
+use alloc::boxed::Box;
+
+#[cfg(feature = "cloneable-secret")]
+use crate::traits::CloneableSecret;
+
+#[cfg(feature = "debug-secret")]
+use crate::traits::{DebugSecret, DebugSecretMetadata};
+
+#[cfg(feature = "display-secret")]
+use crate::traits::DisplaySecret;
+
+#[cfg(feature = "fingerprint")]
+use crate::{
+    fingerprint::{Fingerprint, FingerprintKey},
+    traits::FingerprintSecret,
+};
+
+/// A runtime secret which can **never** panic when it is exposed, for environments where a `panic!` is unacceptable.
+///
+/// It is a thin wrapper around [`RTSecret<T, MEC, EP>`] which does not implement [`RTExposeSecret`],
+/// hence the panicking [`RTExposeSecret::expose_secret`] method is not available and the secret can only be exposed with
+/// [`NonPanickingRTSecret::try_expose_secret`].
+///
+/// Example:
+/// ```rust
+/// use sosecrets_rs::prelude::{typenum::U1, NonPanickingRTSecret};
+///
+/// let secret = NonPanickingRTSecret::<i32, U1>::new(69);
+/// assert_eq!(secret.try_expose_secret(|exposed_secret| *exposed_secret).unwrap(), 69);
+/// assert!(secret.try_expose_secret(|exposed_secret| *exposed_secret).is_err());
+/// ```
+///
+/// Example (this does **NOT** compile):
+/// ```compile_fail
+/// use sosecrets_rs::{
+///     prelude::{typenum::U1, NonPanickingRTSecret},
+///     runtime::traits::RTExposeSecret,
+/// };
+///
+/// let secret = NonPanickingRTSecret::<i32, U1>::new(69);
+/// let _ = secret.expose_secret(|exposed_secret| *exposed_secret);
+/// ```
+pub struct NonPanickingRTSecret<
+    #[cfg(feature = "zeroize")] T: Zeroize,
+    #[cfg(not(feature = "zeroize"))] T,
+    MEC: ChooseMinimallyRepresentableUInt,
+    EP: ExhaustionPolicy = RetainOnExhaustion,
+>(RTSecret<T, MEC, EP>);
+
+impl<
+        #[cfg(feature = "zeroize")] T: Zeroize,
+        #[cfg(not(feature = "zeroize"))] T,
+        MEC: ChooseMinimallyRepresentableUInt,
+        EP: ExhaustionPolicy,
+    > NonPanickingRTSecret<T, MEC, EP>
+{
+    /// Creates a new `NonPanickingRTSecret` with the provided secret value `t`.
+    ///
+    /// # Parameters
+    /// - `t`: The secret value.
+    ///
+    /// # Returns
+    /// The newly created `NonPanickingRTSecret`.
+    #[inline(always)]
+    pub const fn new(t: T) -> Self {
+        Self(RTSecret::new(t))
+    }
+
+    /// Creates a new `NonPanickingRTSecret` with the secret value returned by the provided closure `f`.
+    ///
+    /// # Parameters
+    /// - `f`: A closure that returns the secret value.
+    ///
+    /// # Returns
+    /// The newly created `NonPanickingRTSecret`.
+    #[inline(always)]
+    pub fn new_with(f: impl FnOnce() -> T) -> Self {
+        Self(RTSecret::new_with(f))
+    }
+
+    /// Attaches a label identifying the secret to every error of the `NonPanickingRTSecret`, see [`RTSecret::with_label`].
+    #[inline(always)]
+    pub fn with_label(self, label: &'static str) -> Self {
+        Self(self.0.with_label(label))
+    }
+
+    /// Returns the label identifying the secret, see [`RTSecret::label`].
+    #[inline(always)]
+    pub fn label(&self) -> Option<&'static str> {
+        self.0.label()
+    }
+
+    /// Retrieves the current exposure count of the secret, see [`RTSecret::exposure_count`].
+    #[inline(always)]
+    pub fn exposure_count(&self) -> <MEC as ChooseMinimallyRepresentableUInt>::Output {
+        self.0.exposure_count()
+    }
+}
+
+impl<
+        #[cfg(feature = "zeroize")] T: Zeroize,
+        #[cfg(not(feature = "zeroize"))] T,
+        MEC: ChooseMinimallyRepresentableUInt + Unsigned,
+        EP: ExhaustionPolicy,
+    > NonPanickingRTSecret<T, MEC, EP>
+{
+    /// The maximum number of times the `NonPanickingRTSecret` is allowed to be exposed, i.e. the runtime value of `MEC`.
+    pub const MAX_EXPOSURES: usize = MEC::USIZE;
+
+    /// Returns the maximum number of times the `NonPanickingRTSecret` is allowed to be exposed, see [`RTSecret::max_exposures`].
+    #[inline(always)]
+    pub const fn max_exposures(&self) -> usize {
+        self.0.max_exposures()
+    }
+
+    /// Returns the number of times the `NonPanickingRTSecret` can still be exposed, see [`RTSecret::remaining_exposures`].
+    #[inline(always)]
+    pub fn remaining_exposures(&self) -> usize {
+        self.0.remaining_exposures()
+    }
+
+    /// Returns `true` if the `NonPanickingRTSecret` cannot be exposed anymore, see [`RTSecret::is_exhausted`].
+    #[inline(always)]
+    pub fn is_exhausted(&self) -> bool {
+        self.0.is_exhausted()
+    }
+}
+
+impl<
+        #[cfg(feature = "zeroize")] T: Zeroize,
+        #[cfg(not(feature = "zeroize"))] T,
+        // `IsGreater<U0, Output = True>` so that `NonPanickingRTSecret<T, U0>` cannot call `.try_expose_secret()`
+        MEC: ChooseMinimallyRepresentableUInt + Unsigned + IsGreater<U0, Output = True> + Debug,
+        EP: ExhaustionPolicy,
+    > NonPanickingRTSecret<T, MEC, EP>
+{
+    /// Return the `Result` containing `Ok(scope(exposed_secret))`, with runtime checking that the exposure count is not more than the maximally allowed exposure count represented by the type parameter `MEC`.
+    /// Note: It is impossible to return the 'exposed secret' as the return value of the closure.
+    ///
+    /// # Parameters
+    /// - `scope`: A closure that takes the exposed secret and returns a value of the `ReturnType`.
+    ///
+    /// # Returns
+    /// - `Ok`: The value returned by the closure.
+    /// - `Err`: If the exposure count exceeds the maximum allowed, returns an `ExposeSecretError` carrying the location of the failing call.
+    #[inline(always)]
+    #[track_caller]
+    pub fn try_expose_secret<ReturnType, ClosureType>(
+        &self,
+        scope: ClosureType,
+    ) -> Result<ReturnType, error::ExposeSecretError<MEC>>
+    where
+        for<'brand> ClosureType: FnOnce(RTExposedSecret<'brand, &'brand T>) -> ReturnType,
+    {
+        self.0.checked_expose_secret(scope)
+    }
+
+    /// Tries to mutably expose the secret with runtime checking, see [`RTSecret::try_expose_secret_mut`].
+    #[inline(always)]
+    #[track_caller]
+    pub fn try_expose_secret_mut<ReturnType, ClosureType>(
+        &mut self,
+        scope: ClosureType,
+    ) -> Result<ReturnType, error::ExposeSecretError<MEC>>
+    where
+        T: AppendableSecret,
+        for<'brand> ClosureType: FnOnce(RTExposedSecretMut<'brand, &'brand mut T>) -> ReturnType,
+    {
+        self.0.checked_expose_secret_mut(scope)
+    }
+}
+
+#[cfg(all(
+    feature = "memfd-secret",
+    target_os = "linux",
+    any(target_arch = "x86_64", target_arch = "aarch64", target_arch = "riscv64")
+))]
+impl<MEC, EP> NonPanickingRTSecret<crate::containers::ProtectedBytes, MEC, EP>
+where
+    MEC: ChooseMinimallyRepresentableUInt,
+    EP: ExhaustionPolicy,
+{
+    /// Returns the kind of memory holding the secret bytes, see [`RTSecret::protection_mode`].
+    #[inline(always)]
+    pub fn protection_mode(&self) -> crate::containers::ProtectionMode {
+        self.0.protection_mode()
+    }
+}
+
+#[cfg(all(feature = "alloc", feature = "zeroize"))]
+impl<T: ?Sized, MEC, EP> NonPanickingRTSecret<Box<T>, MEC, EP>
+where
+    Box<T>: Zeroize,
+    MEC: ChooseMinimallyRepresentableUInt + Unsigned + IsGreater<U0, Output = True> + Debug,
+    EP: ExhaustionPolicy,
+{
+    /// Tries to expose the unsized secret value owned by the `Box`, e.g. `[u8]` or `str`, without exposing the `Box` itself,
+    /// see [`RTSecret::try_expose_unsized_secret`].
+    #[inline(always)]
+    #[track_caller]
+    pub fn try_expose_unsized_secret<ReturnType, ClosureType>(
+        &self,
+        scope: ClosureType,
+    ) -> Result<ReturnType, error::ExposeSecretError<MEC>>
+    where
+        for<'brand> ClosureType: FnOnce(RTExposedSecret<'brand, &'brand T>) -> ReturnType,
+    {
+        self.0.checked_expose_unsized_secret(scope)
+    }
+}
+
+impl<
+        #[cfg(feature = "zeroize")] T: Zeroize,
+        #[cfg(not(feature = "zeroize"))] T,
+        MEC: ChooseMinimallyRepresentableUInt,
+        EP: ExhaustionPolicy,
+    > From<RTSecret<T, MEC, EP>> for NonPanickingRTSecret<T, MEC, EP>
+{
+    /// Converts an `RTSecret` into a `NonPanickingRTSecret`, keeping its exposure count.
+    #[inline(always)]
+    fn from(secret: RTSecret<T, MEC, EP>) -> Self {
+        Self(secret)
+    }
+}
+
+#[cfg(feature = "cloneable-secret")]
+impl<T, MEC, EP> Clone for NonPanickingRTSecret<T, MEC, EP>
+where
+    T: CloneableSecret,
+    MEC: ChooseMinimallyRepresentableUInt + Unsigned,
+    EP: ExhaustionPolicy,
+{
+    #[inline(always)]
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+}
+
+#[cfg(feature = "cloneable-secret")]
+impl<T, MEC, EP> NonPanickingRTSecret<T, MEC, EP>
+where
+    T: CloneableSecret,
+    MEC: ChooseMinimallyRepresentableUInt + Unsigned,
+    EP: ExhaustionPolicy,
+{
+    /// Splits the remaining exposure budget of the `NonPanickingRTSecret` into two new `NonPanickingRTSecret`s, see [`RTSecret::split`].
+    ///
+    /// # Type Parameters
+    /// - `A`: The maximum exposure count of the first returned `NonPanickingRTSecret`.
+    /// - `B`: The maximum exposure count of the second returned `NonPanickingRTSecret`.
+    ///
+    /// # Returns
+    /// - `Ok`: `(NonPanickingRTSecret<T, A, EP>, NonPanickingRTSecret<T, B, EP>)`, both holding a clone of the secret value.
+    /// - `Err`: If `A` + `B` exceeds the remaining exposure budget, returns an `ExposeSecretError`; the exposure count of `self` is left untouched.
+    #[allow(clippy::type_complexity)]
+    #[track_caller]
+    pub fn split<A, B>(
+        &self,
+    ) -> Result<
+        (
+            NonPanickingRTSecret<T, A, EP>,
+            NonPanickingRTSecret<T, B, EP>,
+        ),
+        error::ExposeSecretError<MEC>,
+    >
+    where
+        A: ChooseMinimallyRepresentableUInt + Unsigned,
+        B: ChooseMinimallyRepresentableUInt + Unsigned,
+    {
+        let (secret_one, secret_two) = self.0.split::<A, B>()?;
+        Ok((secret_one.into(), secret_two.into()))
+    }
+}
+
+#[cfg(feature = "debug-secret")]
+impl<T, MEC, EP> core::fmt::Debug for NonPanickingRTSecret<T, MEC, EP>
+where
+    T: DebugSecret,
+    MEC: ChooseMinimallyRepresentableUInt + Unsigned,
+    EP: ExhaustionPolicy,
+{
+    /// Formats the `NonPanickingRTSecret` exactly like the `RTSecret` it wraps.
+    #[inline(always)]
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+#[cfg(feature = "debug-secret")]
+impl<T, MEC, EP> NonPanickingRTSecret<T, MEC, EP>
+where
+    T: DebugSecret,
+    MEC: ChooseMinimallyRepresentableUInt + Unsigned,
+    EP: ExhaustionPolicy,
+{
+    /// Returns a value whose `Debug` output is the one of the `NonPanickingRTSecret` followed by the chosen `metadata`, see [`RTSecret::debug_with_metadata`].
+    pub fn debug_with_metadata(
+        &self,
+        metadata: DebugSecretMetadata,
+    ) -> impl core::fmt::Debug + '_ {
+        self.0.debug_with_metadata(metadata)
+    }
+}
+
+#[cfg(feature = "display-secret")]
+impl<T, MEC, EP> core::fmt::Display for NonPanickingRTSecret<T, MEC, EP>
+where
+    T: DisplaySecret,
+    MEC: ChooseMinimallyRepresentableUInt + Unsigned,
+    EP: ExhaustionPolicy,
+{
+    /// Formats the `NonPanickingRTSecret` exactly like the `RTSecret` it wraps, which may count as an exposure.
+    #[inline(always)]
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+#[cfg(feature = "fingerprint")]
+impl<T, MEC, EP> NonPanickingRTSecret<T, MEC, EP>
+where
+    T: FingerprintSecret,
+    MEC: ChooseMinimallyRepresentableUInt + Unsigned + IsGreater<U0, Output = True> + Debug,
+    EP: ExhaustionPolicy,
+{
+    /// Tries to compute the [`Fingerprint`] of the secret with `key`, see [`RTSecret::try_fingerprint`].
+    #[track_caller]
+    pub fn try_fingerprint(
+        &self,
+        key: &FingerprintKey<'_>,
+    ) -> Result<Fingerprint, error::ExposeSecretError<MEC>> {
+        self.0.try_fingerprint(key)
+    }
+}
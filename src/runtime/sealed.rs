@@ -0,0 +1,357 @@
+//! Runtime secrets kept encrypted in memory.
+//!
+//! A [`SealedSecret`] keeps the bytes of its secret value encrypted with ChaCha20-Poly1305, under a random key generated once per process,
+//! so that a heap dump or a read of `/proc/<pid>/mem` does not reveal the secret by itself.
+//! Every exposure decrypts the bytes into a zeroizing scratch buffer, rebuilds the secret value from them with [`SealableSecret::from_unsealed_bytes`],
+//! and zeroizes both as soon as the closure of `expose_secret` returns; the exposures are counted like those of [`RTSecret`](crate::runtime::secret::RTSecret).
+//! With the [`ZeroizeOnExhaustion`](crate::runtime::secret::ZeroizeOnExhaustion) policy, the ciphertext itself is zeroized as soon as the last allowed exposure has decrypted it.
+//!
+//! If the authentication tag of the ciphertext does not verify, i.e. the memory of the `SealedSecret` has been tampered with,
+//! the exposure fails with [`ExposeSecretError::TamperedCiphertext`].
+//!
+//! Every nonce is drawn from the operating system's random number generator rather than from a counter,
+//! so that a child process created with `fork()`, which inherits the process key, does not reuse the nonces of its parent.
+//!
+//! Note: The process key itself is kept in plaintext in memory, so sealing raises the bar for an attacker who can read the memory of the process,
+//! e.g. by making them find and pair the key with each ciphertext, rather than making it impossible.
+
+use core::{
+    cell::{Cell, UnsafeCell},
+    fmt::{self, Debug},
+    marker::PhantomData,
+    panic::Location,
+};
+use std::{io, sync::OnceLock, vec::Vec};
+
+use chacha20poly1305::{AeadInPlace, ChaCha20Poly1305, Key, KeyInit, Nonce, Tag};
+use typenum::{IsGreater, True, Unsigned, U0};
+use zeroize::{Zeroize, Zeroizing};
+
+use crate::{
+    crypto::{random::fill_os_random, KEY_LEN, NONCE_LEN, TAG_LEN},
+    runtime::{
+        error::{
+            ExposeMoreThanMaximallyAllowError, ExposeSecretError, TamperedCiphertextError,
+            ZeroizedOnExhaustionError,
+        },
+        secret::{RTExposedSecret, RetainOnExhaustion},
+        traits::ExhaustionPolicy,
+    },
+    traits::{ChooseMinimallyRepresentableUInt, SealableSecret, __private},
+};
+
+/// Binds the ciphertexts of `SealedSecret` to their purpose.
+const ASSOCIATED_DATA: &[u8] = b"sosecrets-rs sealed secret v1";
+
+/// The key of the `SealedSecret`s of the process, generated when the first one is created.
+static PROCESS_KEY: OnceLock<[u8; KEY_LEN]> = OnceLock::new();
+
+/// Returns the process key, generating it if it does not exist yet.
+fn process_key() -> io::Result<&'static [u8; KEY_LEN]> {
+    if let Some(key) = PROCESS_KEY.get() {
+        return Ok(key);
+    }
+    let mut key = Zeroizing::new([0; KEY_LEN]);
+    fill_os_random(&mut *key)?;
+    // If another thread has set the key in the meantime, its key is used instead.
+    let _ = PROCESS_KEY.set(*key);
+    Ok(PROCESS_KEY
+        .get()
+        .expect("the process key has just been set"))
+}
+
+/// A runtime secret kept encrypted in memory, see the [module documentation](self).
+///
+/// The type parameter `EP` decides what happens to the ciphertext once the `SealedSecret` has been exposed for `MEC` times,
+/// like that of [`RTSecret`](crate::runtime::secret::RTSecret); it defaults to [`RetainOnExhaustion`].
+///
+/// Example:
+/// ```rust
+/// use sosecrets_rs::{
+///     prelude::typenum::U2,
+///     runtime::{sealed::SealedSecret, secret::ZeroizeOnExhaustion},
+/// };
+///
+/// let secret = SealedSecret::<String, U2>::new(String::from("hunter2")).unwrap();
+/// assert_eq!(secret.expose_secret(|exposed_secret| exposed_secret.len()), 7);
+/// assert!(secret
+///     .try_expose_secret(|exposed_secret| exposed_secret.as_str() == "hunter2")
+///     .unwrap());
+/// assert!(secret.try_expose_secret(|exposed_secret| exposed_secret.len()).is_err());
+///
+/// let secret = SealedSecret::<String, U2, ZeroizeOnExhaustion>::new(String::from("hunter2")).unwrap();
+/// assert_eq!(secret.expose_secret(|exposed_secret| exposed_secret.len()), 7);
+/// assert_eq!(secret.expose_secret(|exposed_secret| exposed_secret.len()), 7);
+/// // The ciphertext has been zeroized.
+/// assert!(secret.try_expose_secret(|exposed_secret| exposed_secret.len()).is_err());
+/// ```
+pub struct SealedSecret<
+    T: SealableSecret,
+    MEC: ChooseMinimallyRepresentableUInt,
+    EP: ExhaustionPolicy = RetainOnExhaustion,
+> {
+    /// The ciphertext of the bytes of the secret value, zeroized on drop and, if `EP` requires so, on exhaustion.
+    ciphertext: UnsafeCell<Vec<u8>>,
+    nonce: [u8; NONCE_LEN],
+    tag: [u8; TAG_LEN],
+    /// The number of exposures of the `SealedSecret`.
+    ec: Cell<<MEC as ChooseMinimallyRepresentableUInt>::Output>,
+    /// An optional label identifying the secret in the errors and panic messages, see [`SealedSecret::with_label`].
+    label: <MEC as ChooseMinimallyRepresentableUInt>::Label,
+    _marker: PhantomData<(fn() -> T, EP)>,
+}
+
+impl<T, MEC, EP> SealedSecret<T, MEC, EP>
+where
+    T: SealableSecret,
+    MEC: ChooseMinimallyRepresentableUInt + Unsigned,
+    EP: ExhaustionPolicy,
+{
+    /// Creates a new `SealedSecret` by encrypting the bytes of `value`, which is then zeroized.
+    ///
+    /// # Errors
+    /// If the process key or the nonce cannot be generated because the operating system's random number generator cannot be read,
+    /// or the bytes of `value` are too long to be encrypted, i.e. longer than 256 GiB.
+    pub fn new(mut value: T) -> io::Result<Self> {
+        let mut nonce = [0; NONCE_LEN];
+        let key = match process_key().and_then(|key| fill_os_random(&mut nonce).map(|()| key)) {
+            Ok(key) => key,
+            Err(error) => {
+                value.zeroize();
+                return Err(error);
+            }
+        };
+        // The copy of the bytes is encrypted in place, and zeroized if it cannot be.
+        let mut ciphertext = Zeroizing::new(Vec::from(value.sealable_bytes()));
+        value.zeroize();
+        let tag = ChaCha20Poly1305::new(Key::from_slice(key))
+            .encrypt_in_place_detached(Nonce::from_slice(&nonce), ASSOCIATED_DATA, &mut ciphertext)
+            .map_err(|_| {
+                io::Error::new(
+                    io::ErrorKind::InvalidInput,
+                    "the secret is too long to be sealed",
+                )
+            })?;
+        Ok(Self {
+            ciphertext: UnsafeCell::new(core::mem::take(&mut *ciphertext)),
+            nonce,
+            tag: tag.into(),
+            ec: Cell::new(MEC::ZERO),
+            label: MEC::NO_LABEL,
+            _marker: PhantomData,
+        })
+    }
+
+    /// Attaches a label identifying the secret to the errors and panic messages of its exposures, see [`RTSecret::with_label`](crate::runtime::secret::RTSecret::with_label).
+    #[inline(always)]
+    pub fn with_label(mut self, label: &'static str) -> Self {
+        self.label = MEC::to_label(label, __private::SealedToken {});
+        self
+    }
+
+    /// Returns the label identifying the secret, see [`RTSecret::label`](crate::runtime::secret::RTSecret::label).
+    #[inline(always)]
+    pub fn label(&self) -> Option<&'static str> {
+        MEC::from_label(self.label, __private::SealedToken {})
+    }
+
+    /// Retrieves the current exposure count of the `SealedSecret`, see [`RTSecret::exposure_count`](crate::runtime::secret::RTSecret::exposure_count).
+    #[inline(always)]
+    pub fn exposure_count(&self) -> <MEC as ChooseMinimallyRepresentableUInt>::Output {
+        self.ec.get()
+    }
+
+    /// Returns `true` if the `SealedSecret` has been exposed for `MEC` times, see [`RTSecret::is_exhausted`](crate::runtime::secret::RTSecret::is_exhausted).
+    #[inline(always)]
+    pub fn is_exhausted(&self) -> bool {
+        self.ec.get() >= MEC::cast_unsigned_to_self_type::<MEC>(__private::SealedToken {})
+    }
+}
+
+impl<T, MEC, EP> SealedSecret<T, MEC, EP>
+where
+    T: SealableSecret,
+    MEC: ChooseMinimallyRepresentableUInt + Unsigned + IsGreater<U0, Output = True> + Debug,
+    EP: ExhaustionPolicy,
+{
+    /// Exposes the secret with runtime checking that the exposure count is not more than the maximally allowed exposure count represented by the type parameter `MEC`,
+    /// like [`RTExposeSecret::expose_secret`](crate::runtime::traits::RTExposeSecret::expose_secret).
+    /// The secret value is decrypted and rebuilt for the duration of `scope`, and zeroized as soon as it returns.
+    /// Note: It is impossible to return the 'exposed secret' as the return value of the closure.
+    ///
+    /// # Panics
+    /// If the `SealedSecret` has already been exposed for `MEC` times, or its ciphertext has been tampered with.
+    #[inline(always)]
+    #[track_caller]
+    pub fn expose_secret<ReturnType, ClosureType>(&self, scope: ClosureType) -> ReturnType
+    where
+        for<'brand> ClosureType: FnOnce(RTExposedSecret<'brand, &'brand T>) -> ReturnType,
+    {
+        match self.try_expose_secret(scope) {
+            Ok(returned_value) => returned_value,
+            Err(error) => panic!("{}", error),
+        }
+    }
+
+    /// Tries to expose the secret with runtime checking, see [`SealedSecret::expose_secret`].
+    /// An exposure whose ciphertext has been tampered with is counted as well.
+    ///
+    /// # Returns
+    /// - `Ok`: The value returned by the closure.
+    /// - `Err`: [`ExposeSecretError::TamperedCiphertext`] if the ciphertext has been tampered with,
+    ///   or the same errors as [`RTExposeSecret::try_expose_secret`](crate::runtime::traits::RTExposeSecret::try_expose_secret).
+    #[inline(always)]
+    #[track_caller]
+    pub fn try_expose_secret<ReturnType, ClosureType>(
+        &self,
+        scope: ClosureType,
+    ) -> Result<ReturnType, ExposeSecretError<MEC>>
+    where
+        for<'brand> ClosureType: FnOnce(RTExposedSecret<'brand, &'brand T>) -> ReturnType,
+    {
+        let location = Location::caller();
+        let ec = self.ec.get();
+        let mec = MEC::cast_unsigned_to_self_type::<MEC>(__private::SealedToken {});
+        if ec >= mec {
+            if EP::ZEROIZE_ON_EXHAUSTION {
+                return Err(ExposeSecretError::ZeroizedOnExhaustion(
+                    ZeroizedOnExhaustionError {
+                        mec,
+                        ec,
+                        label: self.label(),
+                        location,
+                    },
+                ));
+            }
+            return Err(ExposeSecretError::ExposeMoreThanMaximallyAllow(
+                ExposeMoreThanMaximallyAllowError {
+                    mec,
+                    ec,
+                    label: self.label(),
+                    location,
+                },
+            ));
+        }
+        self.ec.set(ec + MEC::ONE);
+        let key = PROCESS_KEY
+            .get()
+            .expect("the process key exists once a `SealedSecret` has been created");
+        // SAFETY: The ciphertext is only borrowed within this block, which does not call `scope`,
+        // and `SealedSecret` is `!Sync`, so no other reference to it is alive.
+        let mut plaintext = unsafe {
+            let ciphertext = &mut *self.ciphertext.get();
+            let plaintext = Zeroizing::new(ciphertext.clone());
+            if EP::ZEROIZE_ON_EXHAUSTION && self.is_exhausted() {
+                ciphertext.zeroize();
+            }
+            plaintext
+        };
+        ChaCha20Poly1305::new(Key::from_slice(key))
+            .decrypt_in_place_detached(
+                Nonce::from_slice(&self.nonce),
+                ASSOCIATED_DATA,
+                &mut plaintext,
+                Tag::from_slice(&self.tag),
+            )
+            .map_err(|_| {
+                ExposeSecretError::TamperedCiphertext(TamperedCiphertextError {
+                    mec,
+                    ec: self.ec.get(),
+                    label: self.label(),
+                    location,
+                })
+            })?;
+        let value = Zeroizing::new(T::from_unsealed_bytes(&plaintext));
+        Ok(scope(RTExposedSecret::new(&*value)))
+    }
+}
+
+impl<T: SealableSecret, MEC: ChooseMinimallyRepresentableUInt, EP: ExhaustionPolicy> Drop
+    for SealedSecret<T, MEC, EP>
+{
+    fn drop(&mut self) {
+        self.ciphertext.get_mut().zeroize();
+    }
+}
+
+impl<T: SealableSecret, MEC: ChooseMinimallyRepresentableUInt, EP: ExhaustionPolicy> Debug
+    for SealedSecret<T, MEC, EP>
+{
+    /// Formats the `SealedSecret` as "`SealedSecret([REDACTED])`", without its ciphertext.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "SealedSecret({})", crate::traits::REDACTED)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::runtime::secret::ZeroizeOnExhaustion;
+    use typenum::U2;
+
+    #[test]
+    fn test_tampered_ciphertext() {
+        let mut secret = SealedSecret::<[u8; 4], U2>::new(*b"abcd").unwrap();
+        assert!(secret.expose_secret(|exposed_secret| &*exposed_secret == b"abcd"));
+
+        secret.tag[0] ^= 1;
+        match secret.try_expose_secret(|exposed_secret| *exposed_secret) {
+            Err(ExposeSecretError::TamperedCiphertext(err)) => {
+                assert_eq!((err.mec, err.ec), (2, 2));
+                assert_eq!(err.location.file(), file!());
+            }
+            _ => panic!("a tampered ciphertext must not be unsealed"),
+        }
+        assert!(secret.is_exhausted());
+    }
+
+    #[test]
+    fn test_zeroize_on_exhaustion() {
+        let secret = SealedSecret::<[u8; 4], U2, ZeroizeOnExhaustion>::new(*b"abcd").unwrap();
+        assert!(secret.expose_secret(|exposed_secret| &*exposed_secret == b"abcd"));
+        assert!(!unsafe { &*secret.ciphertext.get() }.is_empty());
+        assert!(secret.expose_secret(|exposed_secret| &*exposed_secret == b"abcd"));
+        assert!(unsafe { &*secret.ciphertext.get() }.is_empty());
+        match secret.try_expose_secret(|exposed_secret| *exposed_secret) {
+            Err(ExposeSecretError::ZeroizedOnExhaustion(err)) => {
+                assert_eq!((err.mec, err.ec), (2, 2));
+            }
+            _ => panic!("a zeroized ciphertext must not be unsealed"),
+        }
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_nonces_differ_after_fork() {
+        // Creates the process key before forking, so that the child process inherits it.
+        let parent_secret = SealedSecret::<[u8; 4], U2>::new(*b"abcd").unwrap();
+
+        let mut fds = [0; 2];
+        assert_eq!(unsafe { libc::pipe(fds.as_mut_ptr()) }, 0);
+        let pid = unsafe { libc::fork() };
+        assert!(pid >= 0);
+        if pid == 0 {
+            let child_secret = SealedSecret::<[u8; 4], U2>::new(*b"abcd").unwrap();
+            unsafe {
+                libc::write(fds[1], child_secret.nonce.as_ptr().cast(), NONCE_LEN);
+                libc::_exit(0);
+            }
+        }
+        let secret = SealedSecret::<[u8; 4], U2>::new(*b"abcd").unwrap();
+
+        let mut child_nonce = [0; NONCE_LEN];
+        let mut status = 0;
+        unsafe {
+            libc::close(fds[1]);
+            assert_eq!(
+                libc::read(fds[0], child_nonce.as_mut_ptr().cast(), NONCE_LEN),
+                NONCE_LEN as isize
+            );
+            libc::close(fds[0]);
+            assert_eq!(libc::waitpid(pid, &mut status, 0), pid);
+        }
+        assert_ne!(child_nonce, secret.nonce);
+        assert_ne!(child_nonce, parent_secret.nonce);
+        assert_ne!(secret.nonce, parent_secret.nonce);
+    }
+}
@@ -1,44 +1,191 @@
 use core::{
-    cell::Cell,
+    cell::{Cell, UnsafeCell},
     convert::Infallible,
     fmt::Debug,
     marker::PhantomData,
     ops::{Deref, Drop},
+    panic::Location,
 };
 
 use crate::{
-    runtime::{error, traits},
-    traits::{ChooseMinimallyRepresentableUInt, __private},
+    runtime::{error, traits, traits::ExhaustionPolicy},
+    traits::{AppendableSecret, ChooseMinimallyRepresentableUInt, __private},
     types::NumericalZeroSizedType,
 };
 use typenum::{IsGreater, True, Unsigned, U0};
 #[cfg(feature = "zeroize")]
 use zeroize::Zeroize;
 
+#[cfg(all(feature = "alloc", feature = "zeroize"))]
+use alloc::boxed::Box;
+
+#[cfg(all(
+    feature = "memfd-secret",
+    target_os = "linux",
+    any(target_arch = "x86_64", target_arch = "aarch64", target_arch = "riscv64")
+))]
+use crate::containers::{ProtectedBytes, ProtectionMode};
+
 #[cfg(feature = "cloneable-secret")]
 use crate::traits::CloneableSecret;
 
 #[cfg(feature = "debug-secret")]
-use crate::traits::DebugSecret;
+use crate::traits::{DebugSecret, DebugSecretMetadata};
+
+#[cfg(feature = "display-secret")]
+use crate::traits::DisplaySecret;
+
+#[cfg(feature = "envelope")]
+use crate::envelope::{self, EnvelopeError, EnvelopeKey};
+#[cfg(feature = "envelope")]
+use alloc::vec::Vec;
+#[cfg(feature = "envelope")]
+use std::io::{Read, Write};
+
+#[cfg(feature = "fingerprint")]
+use crate::{
+    fingerprint::{Fingerprint, FingerprintKey},
+    traits::FingerprintSecret,
+};
+
+#[cfg(feature = "hkdf")]
+use crate::{hkdf::HkdfParams, prelude::Secret};
+#[cfg(feature = "hkdf")]
+use typenum::IsLessOrEqual;
+
+#[cfg(feature = "password-hash")]
+use crate::password_hash::{self, Argon2Params, PasswordHashError};
+#[cfg(feature = "password-hash")]
+use alloc::string::String;
+
+#[cfg(feature = "rand-core")]
+use rand_core::CryptoRngCore;
 
 /// A runtime secret with optional zeroization for the type `T` and exposure count tracking. It is the runtime version of `Secret<T, MEC, EC>`.
+///
+/// The type parameter `EP` decides what happens to the secret value once the `RTSecret` has been exposed for `MEC` times,
+/// it defaults to [`RetainOnExhaustion`], see also [`ZeroizeOnExhaustion`].
 pub struct RTSecret<
     #[cfg(feature = "zeroize")] T: Zeroize,
     #[cfg(not(feature = "zeroize"))] T,
     MEC: ChooseMinimallyRepresentableUInt,
+    EP: ExhaustionPolicy = RetainOnExhaustion,
 >(
     /// `T` is the type of the value that is meant to be kept as a secret,
-    T,
+    UnsafeCell<T>,
     /// The type of the exposure counter, can be either `u8`, `u16`, `u32` or `u64`.
     Cell<<MEC as ChooseMinimallyRepresentableUInt>::Output>,
+    /// The number of exposures in progress, zero-sized unless `EP` needs it.
+    Cell<<EP as ExhaustionPolicy>::ActiveExposures>,
+    /// An optional label identifying the secret in the errors and panic messages, see [`RTSecret::with_label`].
+    <MEC as ChooseMinimallyRepresentableUInt>::Label,
+    PhantomData<EP>,
 );
 
+/// The default [`ExhaustionPolicy`] of `RTSecret`, the secret value is kept until the `RTSecret` is dropped.
+pub struct RetainOnExhaustion;
+
+impl __private::SealedTrait for RetainOnExhaustion {}
+
+impl ExhaustionPolicy for RetainOnExhaustion {
+    const ZEROIZE_ON_EXHAUSTION: bool = false;
+    type ActiveExposures = NumericalZeroSizedType;
+    const ZERO: Self::ActiveExposures = NumericalZeroSizedType {};
+    const ONE: Self::ActiveExposures = NumericalZeroSizedType {};
+}
+
+/// An [`ExhaustionPolicy`] of `RTSecret` that zeroizes the secret value right after the closure of the last allowed exposure returns,
+/// instead of waiting for the `RTSecret` to be dropped.
+/// Any further attempt to expose the secret fails with [`ExposeSecretError::ZeroizedOnExhaustion`](error::ExposeSecretError::ZeroizedOnExhaustion).
+///
+/// If the last allowed exposure happens within the closure of another exposure, the secret value is zeroized when the outermost closure returns.
+#[cfg(feature = "zeroize")]
+pub struct ZeroizeOnExhaustion;
+
+#[cfg(feature = "zeroize")]
+impl __private::SealedTrait for ZeroizeOnExhaustion {}
+
+#[cfg(feature = "zeroize")]
+impl ExhaustionPolicy for ZeroizeOnExhaustion {
+    const ZEROIZE_ON_EXHAUSTION: bool = true;
+    type ActiveExposures = usize;
+    const ZERO: Self::ActiveExposures = 0;
+    const ONE: Self::ActiveExposures = 1;
+}
+
+/// A convenience alias for `RTSecret` with a secret of type `T` that is zeroized right after it has been exposed for `MEC` times.
+///
+/// Example:
+/// ```rust
+/// use sosecrets_rs::{
+///     prelude::{typenum::U1, ZeroizingRTSecret},
+///     runtime::traits::RTExposeSecret,
+/// };
+///
+/// let secret = ZeroizingRTSecret::<[u8; 4], U1>::new([1, 2, 3, 4]);
+/// assert_eq!(secret.expose_secret(|exposed_secret| exposed_secret[0]), 1);
+/// // The secret value has been zeroized.
+/// assert!(secret.try_expose_secret(|exposed_secret| exposed_secret[0]).is_err());
+/// ```
+#[cfg(feature = "zeroize")]
+pub type ZeroizingRTSecret<T, MEC> = RTSecret<T, MEC, ZeroizeOnExhaustion>;
+
+/// Keeps track of an exposure in progress, see [`ExhaustionPolicy`].
+struct ActiveExposureGuard<
+    'secret,
+    #[cfg(feature = "zeroize")] T: Zeroize,
+    #[cfg(not(feature = "zeroize"))] T,
+    MEC: ChooseMinimallyRepresentableUInt + Unsigned,
+    EP: ExhaustionPolicy,
+>(&'secret RTSecret<T, MEC, EP>);
+
+impl<
+        'secret,
+        #[cfg(feature = "zeroize")] T: Zeroize,
+        #[cfg(not(feature = "zeroize"))] T,
+        MEC: ChooseMinimallyRepresentableUInt + Unsigned,
+        EP: ExhaustionPolicy,
+    > ActiveExposureGuard<'secret, T, MEC, EP>
+{
+    #[inline(always)]
+    fn new(secret: &'secret RTSecret<T, MEC, EP>) -> Self {
+        secret.2.set(secret.2.get() + EP::ONE);
+        Self(secret)
+    }
+}
+
+impl<
+        #[cfg(feature = "zeroize")] T: Zeroize,
+        #[cfg(not(feature = "zeroize"))] T,
+        MEC: ChooseMinimallyRepresentableUInt + Unsigned,
+        EP: ExhaustionPolicy,
+    > Drop for ActiveExposureGuard<'_, T, MEC, EP>
+{
+    #[inline(always)]
+    fn drop(&mut self) {
+        self.0 .2.set(self.0 .2.get() - EP::ONE);
+        self.0.zeroize_if_exhausted();
+    }
+}
+
 /// A wrapper type representing an exposed secret.
 ///
 /// The `RTExposedSecret` struct is a wrapper type representing an exposed secret.
 /// It holds an annotated (`'brand`) [invariant](https://doc.rust-lang.org/nomicon/subtyping.html#variance) lifetime, indicating the lifetime of the wrapper type, which is strictly a subtype of the lifetime of the secret and cannot be coerced to be any other lifetime.
 pub struct RTExposedSecret<'brand, T>(T, PhantomData<fn(&'brand ()) -> &'brand ()>);
 
+impl<'brand, T> RTExposedSecret<'brand, T> {
+    /// Wraps a value exposed outside of an `RTSecret`, e.g. a payload read from a kernel keyring or a decrypted sealed secret, for the duration of a closure.
+    #[cfg(any(
+        all(feature = "keyring", target_os = "linux"),
+        feature = "sealed"
+    ))]
+    #[inline(always)]
+    pub(crate) const fn new(t: T) -> Self {
+        Self(t, PhantomData)
+    }
+}
+
 /// A convenience alias for `RTSecret` with a secret of type `T` that does **not** conduct any exposure count checking, i.e. the secret can be exposed infinitely many times.
 /// It is meant to function almost identically to `secrecy::Secret`, except that the signature of `.expose_secret(...)` method is different.
 pub type SecrecySecret<T> = RTSecret<T, NumericalZeroSizedType>;
@@ -115,7 +262,7 @@ impl<'secret, #[cfg(feature = "zeroize")] T: Zeroize, #[cfg(not(feature = "zeroi
     where
         for<'brand> ClosureType: FnOnce(RTExposedSecret<'brand, &'brand T>) -> ReturnType,
     {
-        scope(RTExposedSecret(&self.0, PhantomData))
+        scope(RTExposedSecret(self.inner(), PhantomData))
     }
 
     /// Exposes the secret **without** any runtime checking that the exposure count is not more than the maximally allowed exposure count represented by the type parameter `MEC`.
@@ -186,11 +333,24 @@ impl<'secret, #[cfg(feature = "zeroize")] T: Zeroize, #[cfg(not(feature = "zeroi
     where
         for<'brand> ClosureType: FnOnce(RTExposedSecret<'brand, &'brand T>) -> ReturnType,
     {
-        Ok(scope(RTExposedSecret(&self.0, PhantomData)))
+        Ok(scope(RTExposedSecret(self.inner(), PhantomData)))
     }
 }
 
-impl<'brand, T> Deref for RTExposedSecret<'brand, &'brand T> {
+impl<'brand, T: ?Sized> Deref for RTExposedSecret<'brand, &'brand T> {
+    type Target = T;
+    fn deref(&self) -> &Self::Target {
+        self.0
+    }
+}
+
+/// A wrapper type representing a mutably exposed secret container, see [`RTSecret::expose_secret_mut`].
+///
+/// It dereferences to a shared reference to the container, and only offers the appending and clearing methods of it mutably, see [`AppendableSecret`].
+/// It holds an annotated (`'brand`) [invariant](https://doc.rust-lang.org/nomicon/subtyping.html#variance) lifetime, so that it cannot be returned from the closure exposing it.
+pub struct RTExposedSecretMut<'brand, T>(pub(crate) T, PhantomData<fn(&'brand ()) -> &'brand ()>);
+
+impl<'brand, T: ?Sized> Deref for RTExposedSecretMut<'brand, &'brand mut T> {
     type Target = T;
     fn deref(&self) -> &Self::Target {
         self.0
@@ -201,7 +361,8 @@ impl<
         #[cfg(feature = "zeroize")] T: Zeroize,
         #[cfg(not(feature = "zeroize"))] T,
         MEC: ChooseMinimallyRepresentableUInt,
-    > RTSecret<T, MEC>
+        EP: ExhaustionPolicy,
+    > RTSecret<T, MEC, EP>
 {
     /// Creates a new `RTSecret` with the provided secret value `t`.
     ///
@@ -213,8 +374,11 @@ impl<
     #[inline(always)]
     pub const fn new(t: T) -> Self {
         Self(
-            t,
+            UnsafeCell::new(t),
             Cell::new(<MEC as ChooseMinimallyRepresentableUInt>::ZERO),
+            Cell::new(EP::ZERO),
+            <MEC as ChooseMinimallyRepresentableUInt>::NO_LABEL,
+            PhantomData,
         )
     }
 
@@ -228,11 +392,44 @@ impl<
     #[inline(always)]
     pub fn new_with(f: impl FnOnce() -> T) -> Self {
         Self(
-            f(),
+            UnsafeCell::new(f()),
             Cell::new(<MEC as ChooseMinimallyRepresentableUInt>::ZERO),
+            Cell::new(EP::ZERO),
+            <MEC as ChooseMinimallyRepresentableUInt>::NO_LABEL,
+            PhantomData,
         )
     }
 
+    /// Attaches a label identifying the secret, which is then carried by every [`ExposeSecretError`](error::ExposeSecretError) of the `RTSecret`
+    /// and shown in the panic messages of its exposures.
+    /// The label of a `SecrecySecret` is discarded, since it can never fail to be exposed.
+    ///
+    /// Example:
+    /// ```rust
+    /// use sosecrets_rs::{
+    ///     prelude::{typenum::U1, RTSecret},
+    ///     runtime::traits::RTExposeSecret,
+    /// };
+    ///
+    /// let secret = RTSecret::<i32, U1>::new(69).with_label("db_password");
+    /// assert_eq!(secret.label(), Some("db_password"));
+    /// let _ = secret.expose_secret(|exposed_secret| *exposed_secret);
+    ///
+    /// let err = secret.try_expose_secret(|exposed_secret| *exposed_secret).unwrap_err();
+    /// assert_eq!(err.label(), Some("db_password"));
+    /// ```
+    #[inline(always)]
+    pub fn with_label(mut self, label: &'static str) -> Self {
+        self.3 = MEC::to_label(label, __private::SealedToken {});
+        self
+    }
+
+    /// Returns the label identifying the secret, if one has been attached with [`RTSecret::with_label`].
+    #[inline(always)]
+    pub fn label(&self) -> Option<&'static str> {
+        MEC::from_label(self.3, __private::SealedToken {})
+    }
+
     /// Retrieves the current exposure count of the secret and returns it as an unsigned integer.
     ///
     /// Note: The actual unsigned integer type returned depends on the type-level value of the type parameter `MEC`,
@@ -256,6 +453,170 @@ impl<
         self.1.set(ec + MEC::ONE);
         true
     }
+
+    #[inline(always)]
+    pub(crate) fn inner(&self) -> &T {
+        // SAFETY: The secret value is only ever mutated through `&mut self`, or by `ActiveExposureGuard`
+        // when no exposure is in progress, and `RTSecret` is `!Sync`.
+        unsafe { &*self.0.get() }
+    }
+}
+
+impl<
+        #[cfg(feature = "zeroize")] T: Zeroize,
+        #[cfg(not(feature = "zeroize"))] T,
+        MEC: ChooseMinimallyRepresentableUInt + Unsigned,
+        EP: ExhaustionPolicy,
+    > RTSecret<T, MEC, EP>
+{
+    /// The maximum number of times the `RTSecret` is allowed to be exposed, i.e. the runtime value of `MEC`.
+    pub const MAX_EXPOSURES: usize = MEC::USIZE;
+
+    /// Returns the maximum number of times the `RTSecret` is allowed to be exposed, see [`RTSecret::MAX_EXPOSURES`].
+    #[inline(always)]
+    pub const fn max_exposures(&self) -> usize {
+        Self::MAX_EXPOSURES
+    }
+
+    /// Returns the number of times the `RTSecret` can still be exposed.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use sosecrets_rs::{
+    ///     prelude::{typenum::U3, RTSecret},
+    ///     runtime::traits::RTExposeSecret,
+    /// };
+    ///
+    /// let secret = RTSecret::<i32, U3>::new(69);
+    /// let _ = secret.expose_secret(|exposed_secret| *exposed_secret);
+    /// assert_eq!(secret.exposure_count(), 1);
+    /// assert_eq!(secret.remaining_exposures(), 2);
+    /// assert!(!secret.is_exhausted());
+    /// ```
+    #[inline(always)]
+    pub fn remaining_exposures(&self) -> usize {
+        Self::MAX_EXPOSURES
+            - MEC::cast_self_type_to_usize(self.exposure_count(), __private::SealedToken {})
+    }
+
+    /// Returns `true` if the `RTSecret` cannot be exposed anymore, i.e. the exposure count has reached `MEC`.
+    #[inline(always)]
+    pub fn is_exhausted(&self) -> bool {
+        self.remaining_exposures() == 0
+    }
+
+    /// Exposes the secret with runtime checking, shared by [`RTExposeSecret::try_expose_secret`](traits::RTExposeSecret::try_expose_secret)
+    /// and [`NonPanickingRTSecret::try_expose_secret`](crate::runtime::non_panicking::NonPanickingRTSecret::try_expose_secret).
+    #[inline(always)]
+    #[track_caller]
+    pub(crate) fn checked_expose_secret<ReturnType, ClosureType>(
+        &self,
+        scope: ClosureType,
+    ) -> Result<ReturnType, error::ExposeSecretError<MEC>>
+    where
+        for<'brand> ClosureType: FnOnce(RTExposedSecret<'brand, &'brand T>) -> ReturnType,
+    {
+        if self.can_expose() {
+            let _guard = ActiveExposureGuard::new(self);
+            Ok(scope(RTExposedSecret(self.inner(), PhantomData)))
+        } else {
+            Err(self.exhausted_error())
+        }
+    }
+
+    /// Mutably exposes the secret with runtime checking, shared by [`RTSecret::expose_secret_mut`], [`RTSecret::try_expose_secret_mut`]
+    /// and [`NonPanickingRTSecret::try_expose_secret_mut`](crate::runtime::non_panicking::NonPanickingRTSecret::try_expose_secret_mut).
+    #[inline(always)]
+    #[track_caller]
+    pub(crate) fn checked_expose_secret_mut<ReturnType, ClosureType>(
+        &mut self,
+        scope: ClosureType,
+    ) -> Result<ReturnType, error::ExposeSecretError<MEC>>
+    where
+        T: AppendableSecret,
+        for<'brand> ClosureType: FnOnce(RTExposedSecretMut<'brand, &'brand mut T>) -> ReturnType,
+    {
+        if self.can_expose() {
+            let _guard = ActiveExposureGuard::new(self);
+            // SAFETY: `self` is borrowed mutably, so no other reference to the secret value is alive,
+            // and `ActiveExposureGuard` only touches the secret value when it is dropped, after `scope` returns.
+            let inner = unsafe { &mut *self.0.get() };
+            Ok(scope(RTExposedSecretMut(inner, PhantomData)))
+        } else {
+            Err(self.exhausted_error())
+        }
+    }
+
+    /// Returns the error of an exposure of the `RTSecret` once it is exhausted.
+    #[inline(always)]
+    #[track_caller]
+    fn exhausted_error(&self) -> error::ExposeSecretError<MEC> {
+        let ec = self.exposure_count();
+        let mec = MEC::cast_unsigned_to_self_type::<MEC>(__private::SealedToken {});
+        if EP::ZEROIZE_ON_EXHAUSTION {
+            return error::ExposeSecretError::ZeroizedOnExhaustion(
+                error::ZeroizedOnExhaustionError {
+                    mec,
+                    ec,
+                    label: self.label(),
+                    location: Location::caller(),
+                },
+            );
+        }
+        error::ExposeSecretError::ExposeMoreThanMaximallyAllow(
+            error::ExposeMoreThanMaximallyAllowError {
+                mec,
+                ec,
+                label: self.label(),
+                location: Location::caller(),
+            },
+        )
+    }
+
+    /// Zeroizes the secret value if `EP` requires so, the `RTSecret` is exhausted and no exposure is in progress.
+    #[inline(always)]
+    fn zeroize_if_exhausted(&self) {
+        #[cfg(feature = "zeroize")]
+        if EP::ZEROIZE_ON_EXHAUSTION && self.2.get() == EP::ZERO && self.is_exhausted() {
+            // SAFETY: No exposure is in progress, so no reference to the secret value is alive,
+            // and `RTSecret` is `!Sync`, so no other thread can expose it concurrently.
+            unsafe { (*self.0.get()).zeroize() }
+        }
+    }
+}
+
+impl<#[cfg(feature = "zeroize")] T: Zeroize, #[cfg(not(feature = "zeroize"))] T> SecrecySecret<T> {
+    /// `SecrecySecret` can be exposed infinitely many times, hence this saturates at `usize::MAX`.
+    pub const MAX_EXPOSURES: usize = usize::MAX;
+
+    /// Returns `usize::MAX` since `SecrecySecret` can be exposed infinitely many times, see [`SecrecySecret::MAX_EXPOSURES`].
+    #[inline(always)]
+    pub const fn max_exposures(&self) -> usize {
+        Self::MAX_EXPOSURES
+    }
+
+    /// Returns `usize::MAX` since `SecrecySecret` can be exposed infinitely many times.
+    #[inline(always)]
+    pub const fn remaining_exposures(&self) -> usize {
+        Self::MAX_EXPOSURES
+    }
+
+    /// Always returns `false` since `SecrecySecret` can be exposed infinitely many times.
+    #[inline(always)]
+    pub const fn is_exhausted(&self) -> bool {
+        false
+    }
+
+    /// Mutably exposes the secret container **without** any runtime checking, to append to it in place, see [`AppendableSecret`].
+    /// Note: It is impossible to return the 'exposed secret' as the return value of the closure.
+    #[inline(always)]
+    pub fn expose_secret_mut<ReturnType, ClosureType>(&mut self, scope: ClosureType) -> ReturnType
+    where
+        T: AppendableSecret,
+        for<'brand> ClosureType: FnOnce(RTExposedSecretMut<'brand, &'brand mut T>) -> ReturnType,
+    {
+        scope(RTExposedSecretMut(self.0.get_mut(), PhantomData))
+    }
 }
 
 impl<
@@ -264,7 +625,8 @@ impl<
         #[cfg(not(feature = "zeroize"))] T,
         // `IsGreater<U0, Output = True>` so that `RTSecret<T, U0>` cannot call `.expose_secret()`
         MEC: ChooseMinimallyRepresentableUInt + Unsigned + IsGreater<U0, Output = True> + Debug,
-    > traits::RTExposeSecret<'secret, &'secret T> for RTSecret<T, MEC>
+        EP: ExhaustionPolicy,
+    > traits::RTExposeSecret<'secret, &'secret T> for RTSecret<T, MEC, EP>
 {
     type Error = error::ExposeSecretError<MEC>;
 
@@ -340,11 +702,16 @@ impl<
         for<'brand> ClosureType: FnOnce(RTExposedSecret<'brand, &'brand T>) -> ReturnType,
     {
         if self.can_expose() {
-            return scope(RTExposedSecret(&self.0, PhantomData));
+            let _guard = ActiveExposureGuard::new(self);
+            scope(RTExposedSecret(self.inner(), PhantomData))
         } else {
             let ec = self.exposure_count();
             let mec = MEC::cast_unsigned_to_self_type::<MEC>(__private::SealedToken {});
-            panic!("`RTSecret` has already been exposed for {} times, the maximum number it is allowed to be exposed for is {} times.", ec, mec)
+            let label = error::Label(self.label());
+            if EP::ZEROIZE_ON_EXHAUSTION {
+                panic!("`RTSecret`{} has been zeroized after being exposed for {} times, the maximum number it is allowed to be exposed for is {} times.", label, ec, mec)
+            }
+            panic!("`RTSecret`{} has already been exposed for {} times, the maximum number it is allowed to be exposed for is {} times.", label, ec, mec)
         }
     }
 
@@ -408,63 +775,741 @@ impl<
     ///
     /// # Returns
     /// - `Ok`: The value returned by the closure.
-    /// - `Err`: If the exposure count exceeds the maximum allowed, returns an `ExposeSecretError`.
+    /// - `Err`: If the exposure count exceeds the maximum allowed, returns an `ExposeSecretError` carrying the location of the failing call.
     #[inline(always)]
     fn try_expose_secret<ReturnType, ClosureType>(
         &self,
         scope: ClosureType,
     ) -> Result<ReturnType, error::ExposeSecretError<MEC>>
+    where
+        for<'brand> ClosureType: FnOnce(RTExposedSecret<'brand, &'brand T>) -> ReturnType,
+    {
+        self.checked_expose_secret(scope)
+    }
+}
+
+impl<
+        #[cfg(feature = "zeroize")] T: Zeroize,
+        #[cfg(not(feature = "zeroize"))] T,
+        MEC: ChooseMinimallyRepresentableUInt + Unsigned + IsGreater<U0, Output = True> + Debug,
+        EP: ExhaustionPolicy,
+    > RTSecret<T, MEC, EP>
+{
+    /// Mutably exposes the secret container with runtime checking that the exposure count is not more than the maximally allowed exposure count represented by the type parameter `MEC`,
+    /// to append to it in place. It counts as an exposure, like [`RTExposeSecret::expose_secret`](traits::RTExposeSecret::expose_secret).
+    /// The `RTExposedSecretMut` only offers the appending and clearing methods of the container, see [`AppendableSecret`].
+    /// Note: It is impossible to return the 'exposed secret' as the return value of the closure.
+    ///
+    /// Example:
+    /// ```rust
+    /// use sosecrets_rs::{
+    ///     containers::SecretArray,
+    ///     prelude::{typenum::U2, RTSecret},
+    ///     runtime::traits::RTExposeSecret,
+    /// };
+    ///
+    /// let mut secret = RTSecret::<SecretArray<8>, U2>::new(SecretArray::from_slice(b"hunter").unwrap());
+    /// assert!(secret
+    ///     .expose_secret_mut(|mut exposed_secret| exposed_secret.try_push(b'2'))
+    ///     .is_ok());
+    /// assert!(secret.expose_secret(|exposed_secret| exposed_secret.as_slice() == b"hunter2"));
+    /// assert!(secret.is_exhausted());
+    /// ```
+    ///
+    /// # Panics
+    /// If the `RTSecret` has already been exposed for `MEC` times.
+    #[inline(always)]
+    #[track_caller]
+    pub fn expose_secret_mut<ReturnType, ClosureType>(&mut self, scope: ClosureType) -> ReturnType
+    where
+        T: AppendableSecret,
+        for<'brand> ClosureType: FnOnce(RTExposedSecretMut<'brand, &'brand mut T>) -> ReturnType,
+    {
+        match self.checked_expose_secret_mut(scope) {
+            Ok(returned_value) => returned_value,
+            Err(error) => panic!("{}", error),
+        }
+    }
+
+    /// Tries to mutably expose the secret with runtime checking that the exposure count is not more than the maximally allowed exposure count represented by the type parameter `MEC`.
+    ///
+    /// # Returns
+    /// - `Ok`: The value returned by the closure.
+    /// - `Err`: If the `RTSecret` has already been exposed for `MEC` times.
+    #[inline(always)]
+    #[track_caller]
+    pub fn try_expose_secret_mut<ReturnType, ClosureType>(
+        &mut self,
+        scope: ClosureType,
+    ) -> Result<ReturnType, error::ExposeSecretError<MEC>>
+    where
+        T: AppendableSecret,
+        for<'brand> ClosureType: FnOnce(RTExposedSecretMut<'brand, &'brand mut T>) -> ReturnType,
+    {
+        self.checked_expose_secret_mut(scope)
+    }
+}
+
+#[cfg(all(
+    feature = "memfd-secret",
+    target_os = "linux",
+    any(target_arch = "x86_64", target_arch = "aarch64", target_arch = "riscv64")
+))]
+impl<MEC: ChooseMinimallyRepresentableUInt, EP: ExhaustionPolicy> RTSecret<ProtectedBytes, MEC, EP> {
+    /// Returns the kind of memory holding the secret bytes, without exposing them nor counting an exposure.
+    /// It is the same for every exposure, so callers of `expose_secret` never see the difference.
+    #[inline(always)]
+    pub fn protection_mode(&self) -> ProtectionMode {
+        self.inner().mode()
+    }
+}
+
+#[cfg(all(feature = "alloc", feature = "zeroize"))]
+impl<T: ?Sized, MEC, EP> RTSecret<Box<T>, MEC, EP>
+where
+    Box<T>: Zeroize,
+    MEC: ChooseMinimallyRepresentableUInt + Unsigned,
+    EP: ExhaustionPolicy,
+{
+    /// Exposes the unsized secret value owned by the `Box` with runtime checking, shared by [`RTSecret::expose_unsized_secret`], [`RTSecret::try_expose_unsized_secret`]
+    /// and [`NonPanickingRTSecret::try_expose_unsized_secret`](crate::runtime::non_panicking::NonPanickingRTSecret::try_expose_unsized_secret).
+    #[inline(always)]
+    #[track_caller]
+    pub(crate) fn checked_expose_unsized_secret<ReturnType, ClosureType>(
+        &self,
+        scope: ClosureType,
+    ) -> Result<ReturnType, error::ExposeSecretError<MEC>>
     where
         for<'brand> ClosureType: FnOnce(RTExposedSecret<'brand, &'brand T>) -> ReturnType,
     {
         if self.can_expose() {
-            Ok(scope(RTExposedSecret(&self.0, PhantomData)))
+            let _guard = ActiveExposureGuard::new(self);
+            Ok(scope(RTExposedSecret(&**self.inner(), PhantomData)))
         } else {
-            let ec = self.exposure_count();
-            let mec = MEC::cast_unsigned_to_self_type::<MEC>(__private::SealedToken {});
-            Err(error::ExposeSecretError::ExposeMoreThanMaximallyAllow(
-                error::ExposeMoreThanMaximallyAllowError { mec, ec },
-            ))
+            Err(self.exhausted_error())
         }
     }
 }
 
+#[cfg(all(feature = "alloc", feature = "zeroize"))]
+impl<T: ?Sized, MEC, EP> RTSecret<Box<T>, MEC, EP>
+where
+    Box<T>: Zeroize,
+    MEC: ChooseMinimallyRepresentableUInt + Unsigned + IsGreater<U0, Output = True> + Debug,
+    EP: ExhaustionPolicy,
+{
+    /// Exposes the unsized secret value owned by the `Box`, e.g. `[u8]` or `str`, without exposing the `Box` itself,
+    /// with runtime checking that the exposure count is not more than the maximally allowed exposure count represented by the type parameter `MEC`,
+    /// like [`RTExposeSecret::expose_secret`](traits::RTExposeSecret::expose_secret). The whole allocation is zeroized when the `RTSecret` is dropped.
+    /// Note: It is impossible to return the 'exposed secret' as the return value of the closure.
+    ///
+    /// Example:
+    /// ```rust
+    /// use sosecrets_rs::prelude::{typenum::U2, RTSecret};
+    ///
+    /// let secret = RTSecret::<Box<[u8]>, U2>::new(vec![1, 2, 3, 4].into_boxed_slice());
+    /// let first_two = secret.expose_unsized_secret(|exposed_secret| {
+    ///     let exposed_bytes: &[u8] = &exposed_secret;
+    ///     [exposed_bytes[0], exposed_bytes[1]]
+    /// });
+    /// assert_eq!(first_two, [1, 2]);
+    /// assert_eq!(secret.exposure_count(), 1);
+    /// ```
+    ///
+    /// # Panics
+    /// If the `RTSecret` has already been exposed for `MEC` times.
+    #[inline(always)]
+    #[track_caller]
+    pub fn expose_unsized_secret<ReturnType, ClosureType>(&self, scope: ClosureType) -> ReturnType
+    where
+        for<'brand> ClosureType: FnOnce(RTExposedSecret<'brand, &'brand T>) -> ReturnType,
+    {
+        match self.checked_expose_unsized_secret(scope) {
+            Ok(returned_value) => returned_value,
+            Err(error) => panic!("{}", error),
+        }
+    }
+
+    /// Tries to expose the unsized secret value owned by the `Box` with runtime checking, see [`RTSecret::expose_unsized_secret`].
+    ///
+    /// # Returns
+    /// - `Ok`: The value returned by the closure.
+    /// - `Err`: If the `RTSecret` has already been exposed for `MEC` times.
+    #[inline(always)]
+    #[track_caller]
+    pub fn try_expose_unsized_secret<ReturnType, ClosureType>(
+        &self,
+        scope: ClosureType,
+    ) -> Result<ReturnType, error::ExposeSecretError<MEC>>
+    where
+        for<'brand> ClosureType: FnOnce(RTExposedSecret<'brand, &'brand T>) -> ReturnType,
+    {
+        self.checked_expose_unsized_secret(scope)
+    }
+}
+
+#[cfg(all(feature = "alloc", feature = "zeroize"))]
+impl<T: ?Sized> SecrecySecret<Box<T>>
+where
+    Box<T>: Zeroize,
+{
+    /// Exposes the unsized secret value owned by the `Box`, e.g. `[u8]` or `str`, **without** any runtime checking.
+    /// Note: It is impossible to return the 'exposed secret' as the return value of the closure.
+    #[inline(always)]
+    pub fn expose_unsized_secret<ReturnType, ClosureType>(&self, scope: ClosureType) -> ReturnType
+    where
+        for<'brand> ClosureType: FnOnce(RTExposedSecret<'brand, &'brand T>) -> ReturnType,
+    {
+        scope(RTExposedSecret(&**self.inner(), PhantomData))
+    }
+}
+
 impl<
         #[cfg(feature = "zeroize")] T: Zeroize,
         #[cfg(not(feature = "zeroize"))] T,
         MEC: ChooseMinimallyRepresentableUInt,
-    > Drop for RTSecret<T, MEC>
+        EP: ExhaustionPolicy,
+    > Drop for RTSecret<T, MEC, EP>
 {
     /// Zeroizes the secret value when dropped if the `zeroize` feature is enabled.
     fn drop(&mut self) {
         #[cfg(feature = "zeroize")]
-        self.0.zeroize()
+        self.0.get_mut().zeroize()
     }
 }
 
 #[cfg(feature = "cloneable-secret")]
-impl<T, MEC> Clone for RTSecret<T, MEC>
+impl<T, MEC, EP> Clone for RTSecret<T, MEC, EP>
 where
     T: CloneableSecret,
     MEC: ChooseMinimallyRepresentableUInt + Unsigned,
+    EP: ExhaustionPolicy,
 {
     #[inline(always)]
     fn clone(&self) -> Self {
-        Self(self.0.clone(), self.1.clone())
+        Self(
+            UnsafeCell::new(self.inner().clone()),
+            self.1.clone(),
+            Cell::new(EP::ZERO),
+            self.3,
+            PhantomData,
+        )
+    }
+}
+
+#[cfg(feature = "cloneable-secret")]
+impl<T, MEC, EP> RTSecret<T, MEC, EP>
+where
+    T: CloneableSecret,
+    MEC: ChooseMinimallyRepresentableUInt + Unsigned,
+    EP: ExhaustionPolicy,
+{
+    /// Splits the remaining exposure budget of the `RTSecret` into two new `RTSecret`s, with runtime checking that `A` + `B` is not more than the remaining exposure budget.
+    /// The first returned `RTSecret` can be exposed at most `A` times and the second at most `B` times.
+    /// On success, the exposure count of `self` is incremented by `A` + `B`.
+    ///
+    /// Example:
+    /// ```rust
+    /// use sosecrets_rs::{
+    ///     prelude::{typenum::{U1, U2, U5}, RTSecret},
+    ///     runtime::traits::RTExposeSecret,
+    /// };
+    ///
+    /// let secret = RTSecret::<i32, U5>::new(69);
+    /// let _ = secret.expose_secret(|exposed_secret| *exposed_secret);
+    ///
+    /// let (secret_one, secret_two) = secret.split::<U1, U2>().unwrap();
+    /// assert_eq!(secret.exposure_count(), 4);
+    /// assert_eq!(secret_one.expose_secret(|exposed_secret| *exposed_secret), 69);
+    /// assert_eq!(secret_two.expose_secret(|exposed_secret| *exposed_secret), 69);
+    ///
+    /// // Only 1 exposure is left.
+    /// assert!(secret.split::<U1, U1>().is_err());
+    /// ```
+    ///
+    /// # Type Parameters
+    /// - `A`: The maximum exposure count of the first returned `RTSecret`.
+    /// - `B`: The maximum exposure count of the second returned `RTSecret`.
+    ///
+    /// # Returns
+    /// - `Ok`: `(RTSecret<T, A, EP>, RTSecret<T, B, EP>)`, both holding a clone of the secret value.
+    /// - `Err`: If `A` + `B` exceeds the remaining exposure budget, returns an `ExposeSecretError`; the exposure count of `self` is left untouched.
+    #[allow(clippy::type_complexity)]
+    #[track_caller]
+    pub fn split<A, B>(
+        &self,
+    ) -> Result<(RTSecret<T, A, EP>, RTSecret<T, B, EP>), error::ExposeSecretError<MEC>>
+    where
+        A: ChooseMinimallyRepresentableUInt + Unsigned,
+        B: ChooseMinimallyRepresentableUInt + Unsigned,
+    {
+        let ec = self.1.get();
+        let mec = MEC::cast_unsigned_to_self_type::<MEC>(__private::SealedToken {});
+        let requested = A::U64.saturating_add(B::U64);
+        let a = MEC::cast_unsigned_to_self_type::<A>(__private::SealedToken {});
+        let b = MEC::cast_unsigned_to_self_type::<B>(__private::SealedToken {});
+        // `a + b` is only evaluated once `A` + `B` is known to be representable by `MEC`'s unsigned integer type.
+        if requested > MEC::U64 || a + b > mec - ec {
+            return Err(error::ExposeSecretError::InsufficientExposureBudget(
+                error::InsufficientExposureBudgetError {
+                    mec,
+                    ec,
+                    requested,
+                    label: self.label(),
+                    location: Location::caller(),
+                },
+            ));
+        }
+        self.1.set(ec + a + b);
+        let mut secrets = (
+            RTSecret::new(self.inner().clone()),
+            RTSecret::new(self.inner().clone()),
+        );
+        if let Some(label) = self.label() {
+            secrets = (secrets.0.with_label(label), secrets.1.with_label(label));
+        }
+        self.zeroize_if_exhausted();
+        Ok(secrets)
+    }
+}
+
+#[cfg(feature = "debug-secret")]
+impl<T, MEC, EP> core::fmt::Debug for RTSecret<T, MEC, EP>
+where
+    T: DebugSecret,
+    MEC: ChooseMinimallyRepresentableUInt + Unsigned,
+    EP: ExhaustionPolicy,
+{
+    /// Formats the `RTSecret` as "`RTSecret<[REDACTED]>`", where "`[REDACTED]`" is written by [`DebugSecret::debug_secret`],
+    /// followed by the metadata chosen by [`DebugSecret::DEBUG_METADATA`].
+    #[inline(always)]
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        self.fmt_debug_secret(f, T::DEBUG_METADATA)
     }
 }
 
 #[cfg(feature = "debug-secret")]
-impl<T, MEC> core::fmt::Debug for RTSecret<T, MEC>
+impl<T, MEC, EP> RTSecret<T, MEC, EP>
 where
     T: DebugSecret,
     MEC: ChooseMinimallyRepresentableUInt + Unsigned,
+    EP: ExhaustionPolicy,
 {
+    /// Returns a value whose `Debug` output is the one of the `RTSecret` followed by the chosen `metadata`, instead of [`DebugSecret::DEBUG_METADATA`].
+    /// The secret value itself never appears and the exposure count of the `RTSecret` is left untouched.
+    ///
+    /// Example:
+    /// ```rust
+    /// use sosecrets_rs::{prelude::{typenum::U2, RTSecret}, traits::DebugSecretMetadata};
+    ///
+    /// let secret = RTSecret::<[u8; 4], U2>::new([1, 2, 3, 4]);
+    /// assert_eq!(
+    ///     format!("{:?}", secret.debug_with_metadata(DebugSecretMetadata::NONE.with_remaining_exposures())),
+    ///     "RTSecret<[REDACTED]> { remaining_exposures: 2 }"
+    /// );
+    /// assert_eq!(secret.exposure_count(), 0);
+    /// ```
+    pub fn debug_with_metadata(
+        &self,
+        metadata: DebugSecretMetadata,
+    ) -> impl core::fmt::Debug + '_ {
+        crate::traits::DebugWith(move |f: &mut core::fmt::Formatter<'_>| {
+            self.fmt_debug_secret(f, metadata)
+        })
+    }
+
     #[inline(always)]
+    fn fmt_debug_secret(
+        &self,
+        f: &mut core::fmt::Formatter<'_>,
+        metadata: DebugSecretMetadata,
+    ) -> core::fmt::Result {
+        crate::traits::fmt_debug_secret(
+            f,
+            "RTSecret",
+            self.inner(),
+            metadata,
+            self.remaining_exposures(),
+        )
+    }
+}
+
+#[cfg(feature = "display-secret")]
+impl<T, MEC, EP> core::fmt::Display for RTSecret<T, MEC, EP>
+where
+    T: DisplaySecret,
+    MEC: ChooseMinimallyRepresentableUInt + Unsigned,
+    EP: ExhaustionPolicy,
+{
+    /// Formats the `RTSecret` in the masked form given by [`DisplaySecret`], e.g. "`sk_live_****abcd`".
+    /// If `T` shows any edge character, displaying the `RTSecret` counts as an exposure;
+    /// once the `RTSecret` is exhausted, only [`DisplaySecret::MASK`] is shown and the exposure count is left untouched.
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        f.write_str("RTSecret<")?;
-        T::debug_secret(f)?;
-        f.write_str(">")
+        if !crate::traits::shows_edges::<T>() {
+            return f.write_str(T::MASK);
+        }
+        self.checked_expose_secret(|exposed_secret| {
+            crate::traits::fmt_display_secret(f, &*exposed_secret)
+        })
+        .unwrap_or_else(|_| f.write_str(T::MASK))
+    }
+}
+
+#[cfg(feature = "envelope")]
+impl<MEC, EP> RTSecret<Vec<u8>, MEC, EP>
+where
+    MEC: ChooseMinimallyRepresentableUInt + Unsigned + IsGreater<U0, Output = True> + Debug + 'static,
+    <MEC as ChooseMinimallyRepresentableUInt>::Output: Send + Sync,
+    EP: ExhaustionPolicy,
+{
+    /// Seals the secret into an envelope labelled `label` and written to `writer`, see [`envelope`], which counts as an exposure of the `RTSecret`.
+    ///
+    /// # Errors
+    /// Returns [`EnvelopeError::Exposure`] if the `RTSecret` has already been exposed for `MEC` times, in which case nothing is written to `writer`.
+    pub fn seal_to_writer(
+        &self,
+        key: &EnvelopeKey<'_>,
+        label: &str,
+        writer: impl Write,
+    ) -> Result<(), EnvelopeError> {
+        self.checked_expose_secret(|exposed_secret| {
+            envelope::seal_to_writer(&exposed_secret, key, label, writer)
+        })
+        .map_err(|err| EnvelopeError::Exposure(Box::new(err)))?
+    }
+}
+
+#[cfg(feature = "envelope")]
+impl<MEC: ChooseMinimallyRepresentableUInt, EP: ExhaustionPolicy> RTSecret<Vec<u8>, MEC, EP> {
+    /// Reads an envelope from `reader` and decrypts it into a new `RTSecret`, which has never been exposed, see [`envelope`].
+    ///
+    /// # Errors
+    /// If the envelope cannot be read or parsed, or cannot be authenticated with `key`.
+    pub fn open_from_reader(key: &EnvelopeKey<'_>, reader: impl Read) -> Result<Self, EnvelopeError> {
+        envelope::open_from_reader(key, reader).map(Self::new)
+    }
+}
+
+#[cfg(feature = "envelope")]
+impl SecrecySecret<Vec<u8>> {
+    /// Seals the secret into an envelope labelled `label` and written to `writer`, see [`envelope`].
+    pub fn seal_to_writer(
+        &self,
+        key: &EnvelopeKey<'_>,
+        label: &str,
+        writer: impl Write,
+    ) -> Result<(), EnvelopeError> {
+        envelope::seal_to_writer(self.inner(), key, label, writer)
+    }
+}
+
+#[cfg(feature = "rand-core")]
+impl<const N: usize, MEC: ChooseMinimallyRepresentableUInt, EP: ExhaustionPolicy>
+    RTSecret<[u8; N], MEC, EP>
+{
+    /// Creates a new `RTSecret` holding `N` random bytes from the cryptographically secure `rng`,
+    /// which writes them in place into the `RTSecret`, so that no other copy of them is made on the stack.
+    ///
+    /// Example:
+    /// ```rust
+    /// #[cfg(feature = "getrandom")]
+    /// {
+    ///     use sosecrets_rs::prelude::{rand_core::OsRng, typenum::U1, RTSecret, SecrecySecret};
+    ///
+    ///     let session_token = RTSecret::<[u8; 32], U1>::generate_with(&mut OsRng);
+    ///     let nonce = SecrecySecret::<[u8; 12]>::generate_with(&mut OsRng);
+    /// }
+    /// ```
+    ///
+    /// # Panics
+    /// If `rng` fails, like [`RngCore::fill_bytes`](rand_core::RngCore::fill_bytes).
+    pub fn generate_with<R: CryptoRngCore + ?Sized>(rng: &mut R) -> Self {
+        let mut secret = Self::new([0; N]);
+        rng.fill_bytes(secret.0.get_mut());
+        secret
+    }
+
+    /// Tries to create a new `RTSecret` holding `N` random bytes from the cryptographically secure `rng`, see [`RTSecret::generate_with`].
+    ///
+    /// # Errors
+    /// If `rng` fails; the bytes it has already written are zeroized with the "zeroize" feature.
+    pub fn try_generate_with<R: CryptoRngCore + ?Sized>(rng: &mut R) -> Result<Self, rand_core::Error> {
+        let mut secret = Self::new([0; N]);
+        rng.try_fill_bytes(secret.0.get_mut())?;
+        Ok(secret)
+    }
+
+    /// Creates a new `RTSecret` holding `N` random bytes from the random number generator of the operating system, see [`RTSecret::generate_with`].
+    ///
+    /// # Panics
+    /// If the random number generator of the operating system fails.
+    #[cfg(feature = "getrandom")]
+    pub fn generate() -> Self {
+        Self::generate_with(&mut rand_core::OsRng)
+    }
+}
+
+#[cfg(feature = "fingerprint")]
+impl<T, MEC, EP> RTSecret<T, MEC, EP>
+where
+    T: FingerprintSecret,
+    MEC: ChooseMinimallyRepresentableUInt + Unsigned + IsGreater<U0, Output = True> + Debug,
+    EP: ExhaustionPolicy,
+{
+    /// Computes the [`Fingerprint`] of the secret with `key`, which counts as an exposure of the `RTSecret`.
+    ///
+    /// # Panics
+    /// Like [`RTExposeSecret::expose_secret`](traits::RTExposeSecret::expose_secret), if the `RTSecret` has already been exposed for `MEC` times.
+    #[track_caller]
+    pub fn fingerprint(&self, key: &FingerprintKey<'_>) -> Fingerprint {
+        match self.try_fingerprint(key) {
+            Ok(fingerprint) => fingerprint,
+            Err(error) => panic!("{}", error),
+        }
+    }
+
+    /// Tries to compute the [`Fingerprint`] of the secret with `key`, which counts as an exposure of the `RTSecret`.
+    ///
+    /// # Returns
+    /// - `Ok`: The `Fingerprint`.
+    /// - `Err`: If the `RTSecret` has already been exposed for `MEC` times.
+    #[track_caller]
+    pub fn try_fingerprint(
+        &self,
+        key: &FingerprintKey<'_>,
+    ) -> Result<Fingerprint, error::ExposeSecretError<MEC>> {
+        self.checked_expose_secret(|exposed_secret| {
+            key.fingerprint(exposed_secret.fingerprint_bytes())
+        })
+    }
+}
+
+#[cfg(feature = "fingerprint")]
+impl<T: FingerprintSecret> SecrecySecret<T> {
+    /// Computes the [`Fingerprint`] of the secret with `key`.
+    ///
+    /// Example:
+    /// ```rust
+    /// use sosecrets_rs::{fingerprint::FingerprintKey, prelude::SecrecySecret};
+    ///
+    /// let key = FingerprintKey::new(b"fingerprint key", b"salt");
+    /// let secret = SecrecySecret::new(*b"abcd");
+    /// assert_eq!(secret.fingerprint(&key), secret.fingerprint(&key));
+    /// ```
+    pub fn fingerprint(&self, key: &FingerprintKey<'_>) -> Fingerprint {
+        key.fingerprint(self.inner().fingerprint_bytes())
+    }
+}
+
+#[cfg(feature = "hkdf")]
+impl<
+        #[cfg(feature = "zeroize")] T: Zeroize + AsRef<[u8]>,
+        #[cfg(not(feature = "zeroize"))] T: AsRef<[u8]>,
+        MEC: ChooseMinimallyRepresentableUInt + Unsigned + IsGreater<U0, Output = True> + Debug,
+        EP: ExhaustionPolicy,
+    > RTSecret<T, MEC, EP>
+{
+    /// Derives a new `Secret` of `N` bytes from the secret as the master key with HKDF-SHA256 and `params`,
+    /// which counts as an exposure of the `RTSecret`, see the [`hkdf`](crate::hkdf) module.
+    ///
+    /// # Panics
+    /// Like [`RTExposeSecret::expose_secret`](traits::RTExposeSecret::expose_secret), if the `RTSecret` has already been exposed for `MEC` times.
+    #[track_caller]
+    pub fn derive_key<const N: usize, MEC2: Unsigned>(&self, params: &HkdfParams<'_>) -> Secret<[u8; N], MEC2>
+    where
+        U0: IsLessOrEqual<MEC2, Output = True>,
+    {
+        match self.try_derive_key(params) {
+            Ok(key) => key,
+            Err(error) => panic!("{}", error),
+        }
+    }
+
+    /// Tries to derive a new `Secret` of `N` bytes from the secret as the master key with HKDF-SHA256 and `params`,
+    /// which counts as an exposure of the `RTSecret`.
+    ///
+    /// # Returns
+    /// - `Ok`: The derived `Secret`.
+    /// - `Err`: If the `RTSecret` has already been exposed for `MEC` times.
+    #[track_caller]
+    pub fn try_derive_key<const N: usize, MEC2: Unsigned>(
+        &self,
+        params: &HkdfParams<'_>,
+    ) -> Result<Secret<[u8; N], MEC2>, error::ExposeSecretError<MEC>>
+    where
+        U0: IsLessOrEqual<MEC2, Output = True>,
+    {
+        self.checked_expose_secret(|exposed_secret| Secret::derive_from((*exposed_secret).as_ref(), params))
+    }
+}
+
+#[cfg(feature = "hkdf")]
+impl<
+        #[cfg(feature = "zeroize")] T: Zeroize + AsRef<[u8]>,
+        #[cfg(not(feature = "zeroize"))] T: AsRef<[u8]>,
+    > SecrecySecret<T>
+{
+    /// Derives a new `Secret` of `N` bytes from the secret as the master key with HKDF-SHA256 and `params`, see the [`hkdf`](crate::hkdf) module.
+    ///
+    /// Example:
+    /// ```rust
+    /// use sosecrets_rs::{hkdf::HkdfParams, prelude::{typenum::U1, SecrecySecret, Secret}};
+    ///
+    /// let master_key = SecrecySecret::new([7; 32]);
+    /// let tenant_key: Secret<[u8; 32], U1> = master_key.derive_key(&HkdfParams::new(b"salt", b"tenant-a"));
+    /// ```
+    pub fn derive_key<const N: usize, MEC2: Unsigned>(&self, params: &HkdfParams<'_>) -> Secret<[u8; N], MEC2>
+    where
+        U0: IsLessOrEqual<MEC2, Output = True>,
+    {
+        Secret::derive_from(self.inner().as_ref(), params)
+    }
+}
+
+#[cfg(feature = "password-hash")]
+impl<
+        #[cfg(feature = "zeroize")] T: Zeroize + AsRef<[u8]>,
+        #[cfg(not(feature = "zeroize"))] T: AsRef<[u8]>,
+        MEC: ChooseMinimallyRepresentableUInt + Unsigned + IsGreater<U0, Output = True> + Debug,
+        EP: ExhaustionPolicy,
+    > RTSecret<T, MEC, EP>
+{
+    /// Hashes the secret as a password with Argon2id, `params` and a random salt from the cryptographically secure `rng` into a PHC string,
+    /// which counts as an exposure of the `RTSecret`, see the [`password_hash`] module.
+    ///
+    /// # Panics
+    /// Like [`RTExposeSecret::expose_secret`](traits::RTExposeSecret::expose_secret), if the `RTSecret` has already been exposed for `MEC` times,
+    /// see [`RTSecret::try_hash_password_with`].
+    #[track_caller]
+    pub fn hash_password_with<R: CryptoRngCore + ?Sized>(
+        &self,
+        params: &Argon2Params,
+        rng: &mut R,
+    ) -> Result<String, PasswordHashError> {
+        match self.try_hash_password_with(params, rng) {
+            Ok(hash) => hash,
+            Err(error) => panic!("{}", error),
+        }
+    }
+
+    /// Tries to hash the secret as a password with Argon2id, `params` and a random salt from the cryptographically secure `rng` into a PHC string,
+    /// which counts as an exposure of the `RTSecret`.
+    ///
+    /// # Returns
+    /// - `Ok`: The result of the hashing, see [`RTSecret::hash_password_with`].
+    /// - `Err`: If the `RTSecret` has already been exposed for `MEC` times.
+    #[track_caller]
+    pub fn try_hash_password_with<R: CryptoRngCore + ?Sized>(
+        &self,
+        params: &Argon2Params,
+        rng: &mut R,
+    ) -> Result<Result<String, PasswordHashError>, error::ExposeSecretError<MEC>> {
+        self.checked_expose_secret(|exposed_secret| params.hash((*exposed_secret).as_ref(), rng))
+    }
+
+    /// Hashes the secret as a password with Argon2id, `params` and a random salt from the random number generator of the operating system,
+    /// see [`RTSecret::hash_password_with`].
+    #[cfg(feature = "getrandom")]
+    #[track_caller]
+    pub fn hash_password(&self, params: &Argon2Params) -> Result<String, PasswordHashError> {
+        self.hash_password_with(params, &mut rand_core::OsRng)
+    }
+
+    /// Tries to hash the secret as a password with Argon2id, `params` and a random salt from the random number generator of the operating system,
+    /// see [`RTSecret::try_hash_password_with`].
+    #[cfg(feature = "getrandom")]
+    #[track_caller]
+    pub fn try_hash_password(
+        &self,
+        params: &Argon2Params,
+    ) -> Result<Result<String, PasswordHashError>, error::ExposeSecretError<MEC>> {
+        self.try_hash_password_with(params, &mut rand_core::OsRng)
+    }
+
+    /// Verifies the secret as a password against the PHC string `stored_hash` in constant time, which counts as an exposure of the `RTSecret`.
+    ///
+    /// Returns whether the password matches, or an error if `stored_hash` is not an Argon2id PHC string.
+    ///
+    /// # Panics
+    /// Like [`RTExposeSecret::expose_secret`](traits::RTExposeSecret::expose_secret), if the `RTSecret` has already been exposed for `MEC` times,
+    /// see [`RTSecret::try_verify_password`].
+    #[track_caller]
+    pub fn verify_password(&self, stored_hash: &str) -> Result<bool, PasswordHashError> {
+        match self.try_verify_password(stored_hash) {
+            Ok(matches) => matches,
+            Err(error) => panic!("{}", error),
+        }
+    }
+
+    /// Tries to verify the secret as a password against the PHC string `stored_hash` in constant time, which counts as an exposure of the `RTSecret`.
+    ///
+    /// # Returns
+    /// - `Ok`: The result of the verification, see [`RTSecret::verify_password`].
+    /// - `Err`: If the `RTSecret` has already been exposed for `MEC` times.
+    #[track_caller]
+    pub fn try_verify_password(
+        &self,
+        stored_hash: &str,
+    ) -> Result<Result<bool, PasswordHashError>, error::ExposeSecretError<MEC>> {
+        self.checked_expose_secret(|exposed_secret| password_hash::verify((*exposed_secret).as_ref(), stored_hash))
+    }
+}
+
+#[cfg(feature = "password-hash")]
+impl<
+        #[cfg(feature = "zeroize")] T: Zeroize + AsRef<[u8]>,
+        #[cfg(not(feature = "zeroize"))] T: AsRef<[u8]>,
+    > SecrecySecret<T>
+{
+    /// Hashes the secret as a password with Argon2id, `params` and a random salt from the cryptographically secure `rng` into a PHC string,
+    /// see the [`password_hash`] module.
+    pub fn hash_password_with<R: CryptoRngCore + ?Sized>(
+        &self,
+        params: &Argon2Params,
+        rng: &mut R,
+    ) -> Result<String, PasswordHashError> {
+        params.hash(self.inner().as_ref(), rng)
+    }
+
+    /// Hashes the secret as a password with Argon2id, `params` and a random salt from the random number generator of the operating system,
+    /// see [`SecrecySecret::hash_password_with`].
+    #[cfg(feature = "getrandom")]
+    pub fn hash_password(&self, params: &Argon2Params) -> Result<String, PasswordHashError> {
+        self.hash_password_with(params, &mut rand_core::OsRng)
+    }
+
+    /// Verifies the secret as a password against the PHC string `stored_hash` in constant time, see [`RTSecret::verify_password`].
+    pub fn verify_password(&self, stored_hash: &str) -> Result<bool, PasswordHashError> {
+        password_hash::verify(self.inner().as_ref(), stored_hash)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<
+        #[cfg(feature = "zeroize")] T: Zeroize,
+        #[cfg(not(feature = "zeroize"))] T,
+        MEC: ChooseMinimallyRepresentableUInt,
+        EP: ExhaustionPolicy,
+    > serde::Serialize for RTSecret<T, MEC, EP>
+{
+    /// Serializes the `RTSecret` as the string [`REDACTED`](crate::traits::REDACTED), the secret value is never serialized.
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(crate::traits::REDACTED)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<
+        'de,
+        #[cfg(feature = "zeroize")] T: Zeroize + serde::Deserialize<'de>,
+        #[cfg(not(feature = "zeroize"))] T: serde::Deserialize<'de>,
+        MEC: ChooseMinimallyRepresentableUInt,
+        EP: ExhaustionPolicy,
+    > serde::Deserialize<'de> for RTSecret<T, MEC, EP>
+{
+    /// Deserializes the secret value into a new `RTSecret`, which has never been exposed.
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        T::deserialize(deserializer).map(Self::new)
     }
 }
 
@@ -483,14 +1528,14 @@ mod tests {
         *secret_one.1.get_mut() = u8::MAX - 6;
 
         for _ in 0..=5 {
-            let _ = secret_one.expose_secret(|exposed_secret| {
+            secret_one.expose_secret(|exposed_secret| {
                 assert_eq!(*exposed_secret, 69);
             });
         }
 
         assert_eq!(secret_one.exposure_count(), u8::MAX);
 
-        let _ = secret_one.expose_secret(|exposed_secret| {
+        secret_one.expose_secret(|exposed_secret| {
             assert_eq!(*exposed_secret, 69);
         });
     }
@@ -1,3 +1,7 @@
+use core::ops::{Add, Sub};
+
+use crate::traits::__private;
+
 /// A trait for exposing secrets with runtime checking.
 pub trait RTExposeSecret<'secret, T> {
     /// The type representing the `Error` variant as part of the `Result` returned type in `try_expose_secret`.
@@ -15,6 +19,7 @@ pub trait RTExposeSecret<'secret, T> {
     ///
     /// # Returns
     /// The value returned by the closure.
+    #[track_caller]
     fn expose_secret<ReturnType, ClosureType>(&self, scope: ClosureType) -> ReturnType
     where
         for<'brand> ClosureType: FnOnce(Self::Exposed<'brand>) -> ReturnType;
@@ -27,6 +32,7 @@ pub trait RTExposeSecret<'secret, T> {
     /// # Returns
     /// - `Ok`: The value returned by the closure.
     /// - `Err`: If there is an error during exposure, it returns an error of type `Self::Error`.
+    #[track_caller]
     fn try_expose_secret<ReturnType, ClosureType>(
         &self,
         scope: ClosureType,
@@ -34,3 +40,25 @@ pub trait RTExposeSecret<'secret, T> {
     where
         for<'brand> ClosureType: FnOnce(Self::Exposed<'brand>) -> ReturnType;
 }
+
+/// A trait for the policies deciding what happens to the secret value of an `RTSecret`, or the ciphertext of a `SealedSecret`, once it has been exposed for the maximally allowed number of times.
+///
+/// This is a 'Sealed' trait, it is implemented by [`RetainOnExhaustion`](crate::runtime::secret::RetainOnExhaustion)
+/// and, if the `zeroize` feature is enabled, [`ZeroizeOnExhaustion`](crate::runtime::secret::ZeroizeOnExhaustion).
+pub trait ExhaustionPolicy: __private::SealedTrait {
+    /// Whether the secret value is zeroized right after the closure of the last allowed exposure returns.
+    const ZEROIZE_ON_EXHAUSTION: bool;
+
+    /// The type counting the exposures which are in progress, i.e. whose closures have not returned yet.
+    /// It is zero-sized if the policy does not need to know about it.
+    type ActiveExposures: Copy
+        + PartialEq
+        + Add<Self::ActiveExposures, Output = Self::ActiveExposures>
+        + Sub<Self::ActiveExposures, Output = Self::ActiveExposures>;
+
+    /// The additive identity of the type `Self::ActiveExposures`.
+    const ZERO: Self::ActiveExposures;
+
+    /// The multiplicative identity of the type `Self::ActiveExposures`.
+    const ONE: Self::ActiveExposures;
+}
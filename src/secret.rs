@@ -14,18 +14,57 @@ use core::{
     ops::{Add, Deref, Drop},
 };
 
-use crate::traits::ExposeSecret;
+#[cfg(feature = "cloneable-secret")]
+use core::ops::Sub;
+
+use crate::traits::{AppendableSecret, ExposeSecret};
 pub use typenum;
+/// The `rand_core` crate, whose RNG traits are accepted by the `generate_with` constructors.
+#[cfg(feature = "rand-core")]
+pub use rand_core;
 use typenum::{IsLessOrEqual, Sum, True, Unsigned, U0, U1};
 
+#[cfg(feature = "cloneable-secret")]
+use typenum::Diff;
+
 #[cfg(feature = "zeroize")]
 use zeroize::Zeroize;
 
+#[cfg(all(feature = "alloc", feature = "zeroize"))]
+use alloc::boxed::Box;
+
 #[cfg(feature = "cloneable-secret")]
 use crate::traits::CloneableSecret;
 
 #[cfg(feature = "debug-secret")]
-use crate::traits::DebugSecret;
+use crate::traits::{DebugSecret, DebugSecretMetadata};
+
+#[cfg(feature = "display-secret")]
+use crate::traits::DisplaySecret;
+
+#[cfg(feature = "envelope")]
+use crate::envelope::{self, EnvelopeError, EnvelopeKey};
+#[cfg(feature = "envelope")]
+use alloc::vec::Vec;
+#[cfg(feature = "envelope")]
+use std::io::{Read, Write};
+
+#[cfg(feature = "fingerprint")]
+use crate::{
+    fingerprint::{Fingerprint, FingerprintKey},
+    traits::FingerprintSecret,
+};
+
+#[cfg(feature = "hkdf")]
+use crate::hkdf::HkdfParams;
+
+#[cfg(feature = "password-hash")]
+use crate::password_hash::{self, Argon2Params, PasswordHashError};
+#[cfg(feature = "password-hash")]
+use alloc::string::String;
+
+#[cfg(feature = "rand-core")]
+use rand_core::CryptoRngCore;
 
 type AddU1<A> = <A as core::ops::Add<U1>>::Output;
 
@@ -39,7 +78,7 @@ type AddU1<A> = <A as core::ops::Add<U1>>::Output;
 /// - `T`: The underlying type of the secret.
 /// - `MEC`: Maximum Exposure Count, a type-level unsigned integer, with `typenum::Unsigned` bound, indicating the maximum allowed exposures for the secret.
 /// - `EC`: Exposure Count, a type-level unsigned integer, with `typenum::Unsigned` bound, representing the current exposure count of the secret.
-/// It is limited by the Maximum Exposure Count, if `EC` is greater than `MEC`, the program cannot be compiled.
+///   It is limited by the Maximum Exposure Count, if `EC` is greater than `MEC`, the program cannot be compiled.
 ///
 /// ## Features
 /// - `zeroize` (optional): If enabled, the secret will be automatically zeroized (cleared) after reaching its maximum exposure count.
@@ -56,6 +95,10 @@ pub struct Secret<
 /// Type representing an exposed secret value. It holds an annotated (`'brand`) [invariant](https://doc.rust-lang.org/nomicon/subtyping.html#variance) lifetime.
 pub struct ExposedSecret<'brand, T>(T, PhantomData<fn(&'brand ()) -> &'brand ()>);
 
+/// Type representing a mutably exposed secret container, see [`Secret::expose_secret_mut`].
+/// It dereferences to a shared reference to the container, and only offers the appending and clearing methods of it mutably, see [`AppendableSecret`]. It holds an annotated (`'brand`) [invariant](https://doc.rust-lang.org/nomicon/subtyping.html#variance) lifetime.
+pub struct ExposedSecretMut<'brand, T>(pub(crate) T, PhantomData<fn(&'brand ()) -> &'brand ()>);
+
 impl<#[cfg(feature = "zeroize")] T: Zeroize, #[cfg(not(feature = "zeroize"))] T, MEC: Unsigned>
     Secret<T, MEC, U0>
 where
@@ -107,6 +150,59 @@ where
     }
 }
 
+impl<
+        #[cfg(feature = "zeroize")] T: Zeroize,
+        #[cfg(not(feature = "zeroize"))] T,
+        MEC: Unsigned,
+        EC: Add<U1> + Unsigned + IsLessOrEqual<MEC, Output = True>,
+    > Secret<T, MEC, EC>
+{
+    /// The maximum number of times the `Secret` is allowed to be exposed, i.e. the runtime value of `MEC`.
+    pub const MAX_EXPOSURES: usize = MEC::USIZE;
+
+    /// The number of times the `Secret` has been exposed, i.e. the runtime value of `EC`.
+    pub const EXPOSURE_COUNT: usize = EC::USIZE;
+
+    /// The number of times the `Secret` can still be exposed, i.e. the runtime value of `MEC` - `EC`.
+    pub const REMAINING_EXPOSURES: usize = MEC::USIZE - EC::USIZE;
+
+    /// Returns the maximum number of times the `Secret` is allowed to be exposed, see [`Secret::MAX_EXPOSURES`].
+    #[inline(always)]
+    pub const fn max_exposures(&self) -> usize {
+        Self::MAX_EXPOSURES
+    }
+
+    /// Returns the number of times the `Secret` has been exposed, see [`Secret::EXPOSURE_COUNT`].
+    #[inline(always)]
+    pub const fn exposure_count(&self) -> usize {
+        Self::EXPOSURE_COUNT
+    }
+
+    /// Returns the number of times the `Secret` can still be exposed, see [`Secret::REMAINING_EXPOSURES`].
+    ///
+    /// # Examples
+    /// ```rust
+    /// use sosecrets_rs::{prelude::*, traits::ExposeSecret};
+    /// use typenum::U3;
+    ///
+    /// let secret = Secret::<_, U3>::new(69);
+    /// let (secret, _) = secret.expose_secret(|exposed_secret| *exposed_secret);
+    /// assert_eq!(secret.exposure_count(), 1);
+    /// assert_eq!(secret.remaining_exposures(), 2);
+    /// assert!(!secret.is_exhausted());
+    /// ```
+    #[inline(always)]
+    pub const fn remaining_exposures(&self) -> usize {
+        Self::REMAINING_EXPOSURES
+    }
+
+    /// Returns `true` if the `Secret` cannot be exposed anymore, i.e. `EC` is equal to `MEC`.
+    #[inline(always)]
+    pub const fn is_exhausted(&self) -> bool {
+        Self::REMAINING_EXPOSURES == 0
+    }
+}
+
 impl<
         'max,
         #[cfg(feature = "zeroize")] T: Zeroize,
@@ -196,7 +292,7 @@ impl<
     ///
     #[inline(always)]
     fn expose_secret<ReturnType, ClosureType>(
-        mut self,
+        self,
         scope: ClosureType,
     ) -> (Secret<T, MEC, AddU1<EC>>, ReturnType)
     where
@@ -204,17 +300,113 @@ impl<
         for<'brand> ClosureType: FnOnce(ExposedSecret<'brand, &'brand T>) -> ReturnType,
     {
         let returned_value = scope(ExposedSecret(&self.0, PhantomData));
+        (self.into_next(), returned_value)
+    }
+}
+
+impl<
+        #[cfg(feature = "zeroize")] T: Zeroize,
+        #[cfg(not(feature = "zeroize"))] T,
+        MEC: Unsigned,
+        EC: Add<U1> + Unsigned + IsLessOrEqual<MEC, Output = True>,
+    > Secret<T, MEC, EC>
+{
+    /// Mutably exposes the secret container, to append to it in place, and returns the `Secret` with an incremented count (i.e. `EC`),
+    /// along with the result of the provided closure, like [`ExposeSecret::expose_secret`].
+    /// The `ExposedSecretMut` only offers the appending and clearing methods of the container, see [`AppendableSecret`].
+    /// It is impossible to return the `ExposedSecretMut` out from the closure `scope`.
+    ///
+    /// Example:
+    /// ```rust
+    /// use sosecrets_rs::{
+    ///     containers::SecretArray,
+    ///     prelude::{typenum::U2, Secret},
+    ///     traits::ExposeSecret,
+    /// };
+    ///
+    /// let secret = Secret::<SecretArray<8>, U2>::new(SecretArray::from_slice(b"hunter").unwrap());
+    /// let (secret, pushed) = secret.expose_secret_mut(|mut exposed_secret| exposed_secret.try_push(b'2'));
+    /// assert!(pushed.is_ok());
+    /// let (_, is_hunter2) = secret.expose_secret(|exposed_secret| exposed_secret.as_slice() == b"hunter2");
+    /// assert!(is_hunter2);
+    /// ```
+    #[inline(always)]
+    pub fn expose_secret_mut<ReturnType, ClosureType>(
+        mut self,
+        scope: ClosureType,
+    ) -> (Secret<T, MEC, AddU1<EC>>, ReturnType)
+    where
+        AddU1<EC>: Add<U1> + Unsigned + IsLessOrEqual<MEC, Output = True>,
+        T: AppendableSecret,
+        for<'brand> ClosureType: FnOnce(ExposedSecretMut<'brand, &'brand mut T>) -> ReturnType,
+    {
+        let returned_value = scope(ExposedSecretMut(&mut self.0, PhantomData));
+        (self.into_next(), returned_value)
+    }
+
+    /// Moves the secret value into a `Secret` with an incremented exposure count, after it has been exposed.
+    #[inline(always)]
+    fn into_next(mut self) -> Secret<T, MEC, AddU1<EC>>
+    where
+        AddU1<EC>: Add<U1> + Unsigned + IsLessOrEqual<MEC, Output = True>,
+    {
         // SAFETY: Since compile error prevents constructing a `Secret` with `EC` > `MEC`,
         // and it is not possible to call `expose_secret(...)`
         // when `Secret` is maximally exposed to access **private** `self.0` field,
         // therefore, this is safe.
         let inner = ManuallyDrop::new(unsafe { ManuallyDrop::take(&mut self.0) });
         forget(self);
-        (Secret(inner, PhantomData), returned_value)
+        Secret(inner, PhantomData)
+    }
+}
+
+#[cfg(all(feature = "alloc", feature = "zeroize"))]
+impl<T: ?Sized, MEC, EC> Secret<Box<T>, MEC, EC>
+where
+    Box<T>: Zeroize,
+    MEC: Unsigned,
+    EC: Add<U1> + Unsigned + IsLessOrEqual<MEC, Output = True>,
+{
+    /// Exposes the unsized secret value owned by the `Box`, e.g. `[u8]` or `str`, without exposing the `Box` itself,
+    /// and returns the `Secret` with an incremented count (i.e. `EC`), along with the result of the provided closure, like [`ExposeSecret::expose_secret`].
+    /// The whole allocation is zeroized when the `Secret` is dropped.
+    /// It is impossible to return the `ExposedSecret` out from the closure `scope`.
+    ///
+    /// Example:
+    /// ```rust
+    /// use sosecrets_rs::prelude::{typenum::U2, Secret};
+    ///
+    /// let secret = Secret::<Box<str>, U2>::new("hunter2".into());
+    /// let (_, is_hunter2) = secret.expose_unsized_secret(|exposed_secret| {
+    ///     let exposed_str: &str = &exposed_secret;
+    ///     exposed_str == "hunter2"
+    /// });
+    /// assert!(is_hunter2);
+    /// ```
+    #[inline(always)]
+    pub fn expose_unsized_secret<ReturnType, ClosureType>(
+        self,
+        scope: ClosureType,
+    ) -> (Secret<Box<T>, MEC, AddU1<EC>>, ReturnType)
+    where
+        AddU1<EC>: Add<U1> + Unsigned + IsLessOrEqual<MEC, Output = True>,
+        for<'brand> ClosureType: FnOnce(ExposedSecret<'brand, &'brand T>) -> ReturnType,
+    {
+        let returned_value = scope(ExposedSecret(&**self.0, PhantomData));
+        (self.into_next(), returned_value)
+    }
+}
+
+impl<T: ?Sized> Deref for ExposedSecret<'_, &'_ T> {
+    type Target = T;
+
+    #[inline(always)]
+    fn deref(&self) -> &T {
+        self.0
     }
 }
 
-impl<T> Deref for ExposedSecret<'_, &'_ T> {
+impl<T: ?Sized> Deref for ExposedSecretMut<'_, &'_ mut T> {
     type Target = T;
 
     #[inline(always)]
@@ -254,6 +446,76 @@ where
     }
 }
 
+#[cfg(feature = "cloneable-secret")]
+impl<T, MEC, EC> Secret<T, MEC, EC>
+where
+    T: CloneableSecret,
+    MEC: Unsigned + Sub<EC>,
+    EC: Unsigned + Add<U1> + IsLessOrEqual<MEC, Output = True>,
+    Diff<MEC, EC>: Unsigned,
+{
+    /// Splits the remaining exposure budget of the `Secret` into two new `Secret`s, consuming the `Secret`.
+    /// The first returned `Secret` can be exposed at most `A` times and the second at most `B` times.
+    /// At compile time, if `A` + `B` 'is greater than' `MEC` - `EC`, calling this method will be a compile error.
+    ///
+    /// Example:
+    /// ```rust
+    /// use sosecrets_rs::{
+    ///     prelude::{typenum::{U1, U2, U5}, Secret},
+    ///     traits::ExposeSecret,
+    /// };
+    ///
+    /// let secret = Secret::<_, U5>::new(69);
+    /// let (secret, _) = secret.expose_secret(|exposed_secret| *exposed_secret);
+    ///
+    /// // 4 exposures are left, 3 of them are handed out.
+    /// let (secret_one, secret_two) = secret.split::<U1, U2>();
+    ///
+    /// let (_, returned_value) = secret_one.expose_secret(|exposed_secret| *exposed_secret);
+    /// assert_eq!(69, returned_value);
+    ///
+    /// let (secret_two, _) = secret_two.expose_secret(|exposed_secret| *exposed_secret);
+    /// let (_, returned_value) = secret_two.expose_secret(|exposed_secret| *exposed_secret);
+    /// assert_eq!(69, returned_value);
+    /// ```
+    ///
+    /// Example (this will **not** compile):
+    /// ```rust,compile_fail
+    /// use sosecrets_rs::{
+    ///     prelude::{typenum::{U2, U3, U5}, Secret},
+    ///     traits::ExposeSecret,
+    /// };
+    ///
+    /// let secret = Secret::<_, U5>::new(69);
+    /// let (secret, _) = secret.expose_secret(|exposed_secret| *exposed_secret);
+    ///
+    /// // Only 4 exposures are left.
+    /// let (secret_one, secret_two) = secret.split::<U2, U3>();
+    /// ```
+    ///
+    /// # Type Parameters
+    /// - `A`: The maximum exposure count of the first returned `Secret`.
+    /// - `B`: The maximum exposure count of the second returned `Secret`.
+    ///
+    /// # Returns
+    /// `(Secret<T, A>, Secret<T, B>)`, both holding the same secret value and with a fresh exposure count.
+    #[inline(always)]
+    pub fn split<A, B>(mut self) -> (Secret<T, A>, Secret<T, B>)
+    where
+        A: Unsigned + Add<B>,
+        B: Unsigned,
+        Sum<A, B>: IsLessOrEqual<Diff<MEC, EC>, Output = True>,
+        U0: IsLessOrEqual<A, Output = True> + IsLessOrEqual<B, Output = True>,
+    {
+        let first = Secret(self.0.clone(), PhantomData);
+        // SAFETY: `self` is consumed and forgotten right after its inner value is taken out,
+        // so the inner value is never dropped twice.
+        let inner = ManuallyDrop::new(unsafe { ManuallyDrop::take(&mut self.0) });
+        forget(self);
+        (first, Secret(inner, PhantomData))
+    }
+}
+
 #[cfg(feature = "debug-secret")]
 impl<T, MEC, EC> core::fmt::Debug for Secret<T, MEC, EC>
 where
@@ -261,10 +523,328 @@ where
     MEC: Unsigned,
     EC: Unsigned + Add<U1> + IsLessOrEqual<MEC, Output = True>,
 {
+    /// Formats the `Secret` as "`Secret<[REDACTED]>`", where "`[REDACTED]`" is written by [`DebugSecret::debug_secret`],
+    /// followed by the metadata chosen by [`DebugSecret::DEBUG_METADATA`].
+    #[inline(always)]
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        self.fmt_debug_secret(f, T::DEBUG_METADATA)
+    }
+}
+
+#[cfg(feature = "debug-secret")]
+impl<T, MEC, EC> Secret<T, MEC, EC>
+where
+    T: DebugSecret,
+    MEC: Unsigned,
+    EC: Unsigned + Add<U1> + IsLessOrEqual<MEC, Output = True>,
+{
+    /// Returns a value whose `Debug` output is the one of the `Secret` followed by the chosen `metadata`, instead of [`DebugSecret::DEBUG_METADATA`].
+    /// The secret value itself never appears and the `Secret` is not exposed.
+    ///
+    /// Example:
+    /// ```rust
+    /// use sosecrets_rs::{prelude::{typenum::U2, Secret}, traits::DebugSecretMetadata};
+    ///
+    /// let secret = Secret::<[u8; 4], U2>::new([1, 2, 3, 4]);
+    /// assert_eq!(
+    ///     format!("{:?}", secret.debug_with_metadata(DebugSecretMetadata::NONE.with_byte_len())),
+    ///     "Secret<[REDACTED]> { byte_len: 4 }"
+    /// );
+    /// ```
+    pub fn debug_with_metadata(
+        &self,
+        metadata: DebugSecretMetadata,
+    ) -> impl core::fmt::Debug + '_ {
+        crate::traits::DebugWith(move |f: &mut core::fmt::Formatter<'_>| {
+            self.fmt_debug_secret(f, metadata)
+        })
+    }
+
+    #[inline(always)]
+    fn fmt_debug_secret(
+        &self,
+        f: &mut core::fmt::Formatter<'_>,
+        metadata: DebugSecretMetadata,
+    ) -> core::fmt::Result {
+        crate::traits::fmt_debug_secret(
+            f,
+            "Secret",
+            self.0.deref(),
+            metadata,
+            Self::REMAINING_EXPOSURES,
+        )
+    }
+}
+
+#[cfg(feature = "display-secret")]
+impl<T, MEC, EC> core::fmt::Display for Secret<T, MEC, EC>
+where
+    T: DisplaySecret,
+    MEC: Unsigned,
+    EC: Unsigned + Add<U1> + IsLessOrEqual<MEC, Output = True>,
+{
+    /// Formats the `Secret` in the masked form given by [`DisplaySecret`], e.g. "`sk_live_****abcd`".
+    /// As the exposure count of a `Secret` is part of its type, displaying it cannot count as an exposure;
+    /// instead, only [`DisplaySecret::MASK`] is shown once the `Secret` is exhausted.
     #[inline(always)]
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        f.write_str("Secret<")?;
-        T::debug_secret(f)?;
-        f.write_str(">")
+        if Self::REMAINING_EXPOSURES == 0 {
+            return f.write_str(T::MASK);
+        }
+        crate::traits::fmt_display_secret(f, self.0.deref())
+    }
+}
+
+#[cfg(feature = "envelope")]
+impl<MEC, EC> Secret<Vec<u8>, MEC, EC>
+where
+    MEC: Unsigned,
+    EC: Add<U1> + Unsigned + IsLessOrEqual<MEC, Output = True>,
+{
+    /// Seals the secret into an envelope labelled `label` and written to `writer`, see [`envelope`], which consumes one exposure of the `Secret`.
+    ///
+    /// Returns the `Secret` with an incremented exposure count, along with the result of sealing.
+    ///
+    /// Example:
+    /// ```rust
+    /// use sosecrets_rs::{
+    ///     envelope::EnvelopeKey,
+    ///     prelude::{typenum::U2, Secret},
+    ///     traits::ExposeSecret,
+    /// };
+    ///
+    /// let key = EnvelopeKey::raw(&[7; 32]);
+    /// let secret = Secret::<Vec<u8>, U2>::new(b"hunter2".to_vec());
+    /// let mut file = Vec::new();
+    /// let (_, result) = secret.seal_to_writer(&key, "db-password", &mut file);
+    /// result.unwrap();
+    ///
+    /// let opened = Secret::<Vec<u8>, U2>::open_from_reader(&key, file.as_slice()).unwrap();
+    /// let (_, length) = opened.expose_secret(|exposed_secret| exposed_secret.len());
+    /// assert_eq!(length, 7);
+    /// ```
+    #[allow(clippy::type_complexity)]
+    pub fn seal_to_writer(
+        self,
+        key: &EnvelopeKey<'_>,
+        label: &str,
+        writer: impl Write,
+    ) -> (Secret<Vec<u8>, MEC, Sum<EC, U1>>, Result<(), EnvelopeError>)
+    where
+        Sum<EC, U1>: Unsigned + IsLessOrEqual<MEC, Output = True> + Add<U1>,
+    {
+        let result = envelope::seal_to_writer(&self.0, key, label, writer);
+        (self.into_next(), result)
+    }
+}
+
+#[cfg(feature = "envelope")]
+impl<MEC: Unsigned> Secret<Vec<u8>, MEC, U0>
+where
+    U0: IsLessOrEqual<MEC, Output = True>,
+{
+    /// Reads an envelope from `reader` and decrypts it into a new `Secret`, see [`envelope`].
+    ///
+    /// # Errors
+    /// If the envelope cannot be read or parsed, or cannot be authenticated with `key`.
+    pub fn open_from_reader(key: &EnvelopeKey<'_>, reader: impl Read) -> Result<Self, EnvelopeError> {
+        envelope::open_from_reader(key, reader).map(Self::new)
+    }
+}
+
+#[cfg(feature = "rand-core")]
+impl<const N: usize, MEC: Unsigned> Secret<[u8; N], MEC, U0>
+where
+    U0: IsLessOrEqual<MEC, Output = True>,
+{
+    /// Creates a new `Secret` holding `N` random bytes from the cryptographically secure `rng`,
+    /// which writes them in place into the `Secret`, so that no other copy of them is made on the stack.
+    ///
+    /// Example:
+    /// ```rust
+    /// #[cfg(feature = "getrandom")]
+    /// {
+    ///     use sosecrets_rs::prelude::{rand_core::OsRng, typenum::U1, Secret};
+    ///
+    ///     let api_key = Secret::<[u8; 32], U1>::generate_with(&mut OsRng);
+    /// }
+    /// ```
+    ///
+    /// # Panics
+    /// If `rng` fails, like [`RngCore::fill_bytes`](rand_core::RngCore::fill_bytes).
+    pub fn generate_with<R: CryptoRngCore + ?Sized>(rng: &mut R) -> Self {
+        let mut secret = Self::new([0; N]);
+        rng.fill_bytes(&mut *secret.0);
+        secret
+    }
+
+    /// Tries to create a new `Secret` holding `N` random bytes from the cryptographically secure `rng`, see [`Secret::generate_with`].
+    ///
+    /// # Errors
+    /// If `rng` fails; the bytes it has already written are zeroized with the "zeroize" feature.
+    pub fn try_generate_with<R: CryptoRngCore + ?Sized>(rng: &mut R) -> Result<Self, rand_core::Error> {
+        let mut secret = Self::new([0; N]);
+        rng.try_fill_bytes(&mut *secret.0)?;
+        Ok(secret)
+    }
+
+    /// Creates a new `Secret` holding `N` random bytes from the random number generator of the operating system, see [`Secret::generate_with`].
+    ///
+    /// # Panics
+    /// If the random number generator of the operating system fails.
+    #[cfg(feature = "getrandom")]
+    pub fn generate() -> Self {
+        Self::generate_with(&mut rand_core::OsRng)
+    }
+}
+
+#[cfg(feature = "fingerprint")]
+impl<T, MEC, EC> Secret<T, MEC, EC>
+where
+    T: FingerprintSecret,
+    MEC: Unsigned,
+    EC: Add<U1> + Unsigned + IsLessOrEqual<MEC, Output = True>,
+{
+    /// Computes the [`Fingerprint`] of the secret with `key`, which consumes one exposure of the `Secret`.
+    ///
+    /// Returns the `Secret` with an incremented exposure count, along with the `Fingerprint`.
+    ///
+    /// Example:
+    /// ```rust
+    /// use sosecrets_rs::{fingerprint::FingerprintKey, prelude::{typenum::U2, Secret}};
+    ///
+    /// let key = FingerprintKey::new(b"fingerprint key", b"salt");
+    /// let secret = Secret::<[u8; 4], U2>::new(*b"abcd");
+    /// let (secret, fingerprint) = secret.fingerprint(&key);
+    /// let (_, other_fingerprint) = secret.fingerprint(&key);
+    /// assert_eq!(fingerprint, other_fingerprint);
+    /// ```
+    pub fn fingerprint(self, key: &FingerprintKey<'_>) -> (Secret<T, MEC, Sum<EC, U1>>, Fingerprint)
+    where
+        Sum<EC, U1>: Unsigned + IsLessOrEqual<MEC, Output = True> + Add<U1>,
+    {
+        let fingerprint = key.fingerprint(self.0.fingerprint_bytes());
+        (self.into_next(), fingerprint)
+    }
+}
+
+#[cfg(feature = "hkdf")]
+impl<const N: usize, MEC: Unsigned> Secret<[u8; N], MEC, U0>
+where
+    U0: IsLessOrEqual<MEC, Output = True>,
+{
+    /// Creates a new `Secret` holding `N` bytes derived from the master key `ikm` with `params`, which are written in place into the `Secret`.
+    pub(crate) fn derive_from(ikm: &[u8], params: &HkdfParams<'_>) -> Self {
+        let mut key = Self::new([0; N]);
+        params.derive(ikm, &mut key.0);
+        key
+    }
+}
+
+#[cfg(feature = "hkdf")]
+impl<
+        #[cfg(feature = "zeroize")] T: Zeroize + AsRef<[u8]>,
+        #[cfg(not(feature = "zeroize"))] T: AsRef<[u8]>,
+        MEC: Unsigned,
+        EC: Add<U1> + Unsigned + IsLessOrEqual<MEC, Output = True>,
+    > Secret<T, MEC, EC>
+{
+    /// Derives a new `Secret` of `N` bytes from the secret as the master key with HKDF-SHA256 and `params`, which consumes one exposure of the `Secret`.
+    ///
+    /// Returns the `Secret` with an incremented exposure count, along with the derived `Secret`, see the [`hkdf`](crate::hkdf) module.
+    ///
+    /// Example:
+    /// ```rust
+    /// use sosecrets_rs::{hkdf::HkdfParams, prelude::{typenum::{U1, U2}, Secret}};
+    ///
+    /// let master_key = Secret::<[u8; 32], U2>::new([7; 32]);
+    /// let (master_key, encryption_key) = master_key.derive_key::<32, U1>(&HkdfParams::new(b"salt", b"encryption"));
+    /// let (_, signing_key) = master_key.derive_key::<64, U1>(&HkdfParams::new(b"salt", b"signing"));
+    /// ```
+    ///
+    /// The length of the derived key is checked at compile time, it cannot exceed [`MAX_KEY_LEN`](crate::hkdf::MAX_KEY_LEN) bytes.
+    #[allow(clippy::type_complexity)]
+    pub fn derive_key<const N: usize, MEC2: Unsigned>(
+        self,
+        params: &HkdfParams<'_>,
+    ) -> (Secret<T, MEC, Sum<EC, U1>>, Secret<[u8; N], MEC2>)
+    where
+        Sum<EC, U1>: Unsigned + IsLessOrEqual<MEC, Output = True> + Add<U1>,
+        U0: IsLessOrEqual<MEC2, Output = True>,
+    {
+        let key = Secret::derive_from((*self.0).as_ref(), params);
+        (self.into_next(), key)
+    }
+}
+
+#[cfg(feature = "password-hash")]
+impl<
+        #[cfg(feature = "zeroize")] T: Zeroize + AsRef<[u8]>,
+        #[cfg(not(feature = "zeroize"))] T: AsRef<[u8]>,
+        MEC: Unsigned,
+        EC: Add<U1> + Unsigned + IsLessOrEqual<MEC, Output = True>,
+    > Secret<T, MEC, EC>
+{
+    /// Hashes the secret as a password with Argon2id, `params` and a random salt from the cryptographically secure `rng` into a PHC string,
+    /// which consumes one exposure of the `Secret`, see the [`password_hash`](crate::password_hash) module.
+    ///
+    /// Returns the `Secret` with an incremented exposure count, along with the PHC string.
+    ///
+    /// Example:
+    /// ```rust
+    /// #[cfg(feature = "getrandom")]
+    /// {
+    ///     use sosecrets_rs::{
+    ///         password_hash::Argon2Params,
+    ///         prelude::{rand_core::OsRng, typenum::U2, Secret},
+    ///     };
+    ///
+    ///     let password = Secret::<String, U2>::new("hunter2".to_owned());
+    ///     let (password, stored_hash) = password.hash_password_with(&Argon2Params::DEFAULT, &mut OsRng);
+    ///     let (_, matches) = password.verify_password(&stored_hash.unwrap());
+    ///     assert!(matches.unwrap());
+    /// }
+    /// ```
+    #[allow(clippy::type_complexity)]
+    pub fn hash_password_with<R: CryptoRngCore + ?Sized>(
+        self,
+        params: &Argon2Params,
+        rng: &mut R,
+    ) -> (Secret<T, MEC, Sum<EC, U1>>, Result<String, PasswordHashError>)
+    where
+        Sum<EC, U1>: Unsigned + IsLessOrEqual<MEC, Output = True> + Add<U1>,
+    {
+        let hash = params.hash((*self.0).as_ref(), rng);
+        (self.into_next(), hash)
+    }
+
+    /// Hashes the secret as a password with Argon2id, `params` and a random salt from the random number generator of the operating system,
+    /// see [`Secret::hash_password_with`].
+    #[cfg(feature = "getrandom")]
+    #[allow(clippy::type_complexity)]
+    pub fn hash_password(
+        self,
+        params: &Argon2Params,
+    ) -> (Secret<T, MEC, Sum<EC, U1>>, Result<String, PasswordHashError>)
+    where
+        Sum<EC, U1>: Unsigned + IsLessOrEqual<MEC, Output = True> + Add<U1>,
+    {
+        self.hash_password_with(params, &mut rand_core::OsRng)
+    }
+
+    /// Verifies the secret as a password against the PHC string `stored_hash` in constant time, which consumes one exposure of the `Secret`.
+    ///
+    /// Returns the `Secret` with an incremented exposure count, along with whether the password matches,
+    /// or an error if `stored_hash` is not an Argon2id PHC string.
+    #[allow(clippy::type_complexity)]
+    pub fn verify_password(
+        self,
+        stored_hash: &str,
+    ) -> (Secret<T, MEC, Sum<EC, U1>>, Result<bool, PasswordHashError>)
+    where
+        Sum<EC, U1>: Unsigned + IsLessOrEqual<MEC, Output = True> + Add<U1>,
+    {
+        let matches = password_hash::verify((*self.0).as_ref(), stored_hash);
+        (self.into_next(), matches)
     }
 }
@@ -6,7 +6,7 @@ use core::{
     cmp::PartialOrd,
     fmt::{Debug, Display},
     hash::Hash,
-    ops::{Add, AddAssign},
+    ops::{Add, AddAssign, Sub},
 };
 use typenum::{IsLessOrEqual, Sum, True, Unsigned, U0, U1};
 
@@ -50,11 +50,47 @@ pub trait ExposeSecret<'max, T, MEC: Unsigned, EC: Unsigned>: Sized {
         Sum<EC, U1>: Unsigned + Add<U1> + IsLessOrEqual<MEC, Output = True>;
 }
 
+/// The text written in place of a secret value, e.g. by the default implementation of `DebugSecret::debug_secret`.
+pub const REDACTED: &str = "[REDACTED]";
+
+/// A secret container which can be appended to in place, through the `expose_secret_mut` methods of
+/// [`Secret`](crate::prelude::Secret), [`RTSecret`](crate::prelude::RTSecret) and [`SecrecySecret`](crate::prelude::SecrecySecret).
+///
+/// The mutably exposed secret only forwards the appending and clearing methods of the container, and never hands out a mutable reference to the whole value,
+/// so that the secret value cannot be moved out of the secret, e.g. with `core::mem::take`.
+/// This trait is sealed and implemented by [`SecretArray<N>`](crate::containers::SecretArray), [`SecretString`](crate::containers::SecretString),
+/// [`SecretBytes`](crate::containers::SecretBytes) and `ProtectedBytes`, whose fixed-length bytes can only be filled in place.
+pub trait AppendableSecret: __private::SealedTrait {}
+
 #[cfg(feature = "cloneable-secret")]
 pub use self::cloneable_secret::CloneableSecret;
 
 #[cfg(feature = "debug-secret")]
-pub use self::debug_secret::DebugSecret;
+pub use self::debug_secret::{DebugSecret, DebugSecretMetadata};
+
+#[cfg(feature = "debug-secret")]
+pub(crate) use self::debug_secret::{fmt_debug_secret, DebugWith};
+
+#[cfg(feature = "display-secret")]
+pub use self::display_secret::DisplaySecret;
+
+#[cfg(feature = "display-secret")]
+pub(crate) use self::display_secret::{fmt_display_secret, shows_edges};
+
+#[cfg(feature = "fingerprint")]
+pub use self::fingerprint_secret::FingerprintSecret;
+
+#[cfg(feature = "sealed")]
+pub use self::sealable_secret::SealableSecret;
+
+/// Derives [`CloneableSecret`](trait@CloneableSecret) for a struct or an enum whose fields all implement it.
+#[cfg(all(feature = "derive", feature = "cloneable-secret"))]
+pub use sosecrets_rs_derive::CloneableSecret;
+
+/// Derives [`DebugSecret`](trait@DebugSecret) and `Debug` for a struct or an enum whose fields all implement `DebugSecret`,
+/// each field is redacted unless it is annotated with `#[secret(show)]`.
+#[cfg(all(feature = "derive", feature = "debug-secret"))]
+pub use sosecrets_rs_derive::DebugSecret;
 
 #[cfg(feature = "cloneable-secret")]
 mod cloneable_secret {
@@ -101,14 +137,93 @@ mod cloneable_secret {
     {
     }
 
+    impl<
+            #[cfg(feature = "zeroize")] T: Clone + Zeroize,
+            #[cfg(not(feature = "zeroize"))] T: Clone,
+        > CloneableSecret for Option<T>
+    {
+    }
+
+    #[cfg(feature = "alloc")]
+    use alloc::boxed::Box;
+
+    #[cfg(feature = "alloc")]
+    impl CloneableSecret for Box<str> {}
+
+    #[cfg(feature = "alloc")]
+    impl<
+            #[cfg(feature = "zeroize")] T: Clone + Zeroize,
+            #[cfg(not(feature = "zeroize"))] T: Clone,
+        > CloneableSecret for Box<[T]>
+    {
+    }
+
+    #[cfg(feature = "zeroize")]
+    impl<T: Clone + Zeroize> CloneableSecret for zeroize::Zeroizing<T> {}
+
+    // `zeroize` does not implement `Zeroize` for `Box<T>`, `Cow`, `BTreeMap` and `HashMap`,
+    // so they are only cloneable secrets without the "zeroize" feature.
+    #[cfg(all(any(feature = "alloc", feature = "std"), not(feature = "zeroize")))]
+    use alloc::{borrow::Cow, collections::BTreeMap};
+
+    #[cfg(all(feature = "std", not(feature = "zeroize")))]
+    use std::collections::HashMap;
+
+    #[cfg(all(any(feature = "alloc", feature = "std"), not(feature = "zeroize")))]
+    impl<T: Clone> CloneableSecret for alloc::boxed::Box<T> {}
+
+    #[cfg(all(any(feature = "alloc", feature = "std"), not(feature = "zeroize")))]
+    impl CloneableSecret for Cow<'static, str> {}
+
+    #[cfg(all(any(feature = "alloc", feature = "std"), not(feature = "zeroize")))]
+    impl<K: Clone, V: Clone> CloneableSecret for BTreeMap<K, V> {}
+
+    #[cfg(all(feature = "std", not(feature = "zeroize")))]
+    impl<K: Clone, V: Clone, S: Clone> CloneableSecret for HashMap<K, V, S> {}
+
+    crate::macros::impl_cloneable_secret_for_numbers!(
+        i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize, f32, f64, bool, char
+    );
+
     crate::macros::impl_cloneable_secret_for_numbers!(
-        i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize, f32, f64
+        core::num::NonZeroI8,
+        core::num::NonZeroI16,
+        core::num::NonZeroI32,
+        core::num::NonZeroI64,
+        core::num::NonZeroI128,
+        core::num::NonZeroIsize,
+        core::num::NonZeroU8,
+        core::num::NonZeroU16,
+        core::num::NonZeroU32,
+        core::num::NonZeroU64,
+        core::num::NonZeroU128,
+        core::num::NonZeroUsize
+    );
+
+    crate::macros::impl_cloneable_secret_for_tuples!(
+        (A),
+        (A, B),
+        (A, B, C),
+        (A, B, C, D),
+        (A, B, C, D, E),
+        (A, B, C, D, E, F),
+        (A, B, C, D, E, F, G),
+        (A, B, C, D, E, F, G, H),
+        (A, B, C, D, E, F, G, H, I),
+        (A, B, C, D, E, F, G, H, I, J)
+    );
+
+    // `zeroize` implements `Zeroize` for tuples of up to 10 elements.
+    #[cfg(not(feature = "zeroize"))]
+    crate::macros::impl_cloneable_secret_for_tuples!(
+        (A, B, C, D, E, F, G, H, I, J, K),
+        (A, B, C, D, E, F, G, H, I, J, K, L)
     );
 }
 
 #[cfg(feature = "debug-secret")]
 mod debug_secret {
-    use core::fmt::Debug;
+    use core::fmt::{Debug, Display};
 
     #[cfg(feature = "zeroize")]
     use zeroize::Zeroize;
@@ -121,7 +236,17 @@ mod debug_secret {
     pub trait DebugSecret: Debug + Zeroize {
         /// Formats the secret as "`[REDACTED]`".
         fn debug_secret(f: &mut core::fmt::Formatter<'_>) -> Result<(), core::fmt::Error> {
-            f.write_str("[REDACTED]")
+            f.write_str(crate::traits::REDACTED)
+        }
+
+        /// The metadata appended to the `Debug` output of `Secret` and `RTSecret` holding a secret of this type, none by default.
+        /// It can also be chosen for a single formatting with `Secret::debug_with_metadata` or `RTSecret::debug_with_metadata`.
+        const DEBUG_METADATA: DebugSecretMetadata = DebugSecretMetadata::NONE;
+
+        /// Returns the length in bytes of the secret value, which is shown with [`DebugSecretMetadata::with_byte_len`].
+        /// Returns `None` by default, i.e. the length is not known.
+        fn debug_secret_byte_len(&self) -> Option<usize> {
+            None
         }
     }
 
@@ -133,7 +258,145 @@ mod debug_secret {
     pub trait DebugSecret: Debug {
         /// Formats the secret as "`[REDACTED]`".
         fn debug_secret(f: &mut core::fmt::Formatter<'_>) -> Result<(), core::fmt::Error> {
-            f.write_str("[REDACTED]")
+            f.write_str(crate::traits::REDACTED)
+        }
+
+        /// The metadata appended to the `Debug` output of `Secret` and `RTSecret` holding a secret of this type, none by default.
+        /// It can also be chosen for a single formatting with `Secret::debug_with_metadata` or `RTSecret::debug_with_metadata`.
+        const DEBUG_METADATA: DebugSecretMetadata = DebugSecretMetadata::NONE;
+
+        /// Returns the length in bytes of the secret value, which is shown with [`DebugSecretMetadata::with_byte_len`].
+        /// Returns `None` by default, i.e. the length is not known.
+        fn debug_secret_byte_len(&self) -> Option<usize> {
+            None
+        }
+    }
+
+    /// The metadata about a secret that is appended to the redacted `Debug` output of `Secret` and `RTSecret`, the secret value itself never appears.
+    ///
+    /// Example:
+    /// ```rust
+    /// use sosecrets_rs::{prelude::{typenum::U2, Secret}, traits::DebugSecretMetadata};
+    ///
+    /// let secret = Secret::<[u8; 4], U2>::new([1, 2, 3, 4]);
+    /// assert_eq!(format!("{:?}", secret), "Secret<[REDACTED]>");
+    /// assert_eq!(
+    ///     format!("{:?}", secret.debug_with_metadata(DebugSecretMetadata::ALL)),
+    ///     "Secret<[REDACTED]> { type_name: \"[u8; 4]\", byte_len: 4, remaining_exposures: 2 }"
+    /// );
+    /// ```
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct DebugSecretMetadata {
+        type_name: bool,
+        byte_len: bool,
+        remaining_exposures: bool,
+        #[cfg(feature = "fingerprint")]
+        fingerprint: Option<crate::fingerprint::Fingerprint>,
+    }
+
+    impl DebugSecretMetadata {
+        /// No metadata, the `Debug` output is "`Secret<[REDACTED]>`".
+        pub const NONE: Self = Self {
+            type_name: false,
+            byte_len: false,
+            remaining_exposures: false,
+            #[cfg(feature = "fingerprint")]
+            fingerprint: None,
+        };
+
+        /// All the metadata, i.e. the type name, the byte length and the remaining exposure budget.
+        pub const ALL: Self = Self {
+            type_name: true,
+            byte_len: true,
+            remaining_exposures: true,
+            #[cfg(feature = "fingerprint")]
+            fingerprint: None,
+        };
+
+        /// Shows the type name of the secret value, as returned by [`core::any::type_name`].
+        pub const fn with_type_name(self) -> Self {
+            Self {
+                type_name: true,
+                ..self
+            }
+        }
+
+        /// Shows the length in bytes of the secret value, as returned by [`DebugSecret::debug_secret_byte_len`], if it is known.
+        pub const fn with_byte_len(self) -> Self {
+            Self {
+                byte_len: true,
+                ..self
+            }
+        }
+
+        /// Shows the number of times the secret can still be exposed.
+        pub const fn with_remaining_exposures(self) -> Self {
+            Self {
+                remaining_exposures: true,
+                ..self
+            }
+        }
+
+        /// Shows a [`Fingerprint`](crate::fingerprint::Fingerprint) of the secret, computed beforehand with its `fingerprint()` method.
+        #[cfg(feature = "fingerprint")]
+        pub const fn with_fingerprint(self, fingerprint: crate::fingerprint::Fingerprint) -> Self {
+            Self {
+                fingerprint: Some(fingerprint),
+                ..self
+            }
+        }
+    }
+
+    /// Formats `secret` as "`{name}<[REDACTED]>`", followed by the chosen `metadata`, given the `remaining_exposures` of the secret.
+    pub(crate) fn fmt_debug_secret<T: DebugSecret>(
+        f: &mut core::fmt::Formatter<'_>,
+        name: &str,
+        secret: &T,
+        metadata: DebugSecretMetadata,
+        remaining_exposures: usize,
+    ) -> core::fmt::Result {
+        f.write_str(name)?;
+        f.write_str("<")?;
+        T::debug_secret(f)?;
+        f.write_str(">")?;
+
+        let mut separator = " { ";
+        let mut field = |f: &mut core::fmt::Formatter<'_>, name: &str, value: &dyn Display| {
+            let result = write!(f, "{}{}: {}", separator, name, value);
+            separator = ", ";
+            result
+        };
+        if metadata.type_name {
+            field(
+                f,
+                "type_name",
+                &format_args!("{:?}", core::any::type_name::<T>()),
+            )?;
+        }
+        if metadata.byte_len {
+            if let Some(byte_len) = secret.debug_secret_byte_len() {
+                field(f, "byte_len", &byte_len)?;
+            }
+        }
+        #[cfg(feature = "fingerprint")]
+        if let Some(fingerprint) = &metadata.fingerprint {
+            field(f, "fingerprint", fingerprint)?;
+        }
+        if metadata.remaining_exposures {
+            field(f, "remaining_exposures", &remaining_exposures)?;
+        }
+        if separator == ", " {
+            f.write_str(" }")?;
+        }
+        Ok(())
+    }
+
+    /// Implements `Debug` with a closure.
+    pub(crate) struct DebugWith<F>(pub(crate) F);
+
+    impl<F: Fn(&mut core::fmt::Formatter<'_>) -> core::fmt::Result> Debug for DebugWith<F> {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            (self.0)(f)
         }
     }
 
@@ -143,13 +406,20 @@ mod debug_secret {
             const N: usize,
         > DebugSecret for [T; N]
     {
+        fn debug_secret_byte_len(&self) -> Option<usize> {
+            Some(core::mem::size_of_val(self))
+        }
     }
 
     #[cfg(feature = "alloc")]
     use alloc::{string::String, vec::Vec};
 
     #[cfg(feature = "alloc")]
-    impl DebugSecret for String {}
+    impl DebugSecret for String {
+        fn debug_secret_byte_len(&self) -> Option<usize> {
+            Some(self.len())
+        }
+    }
 
     #[cfg(feature = "alloc")]
     impl<
@@ -157,11 +427,319 @@ mod debug_secret {
             #[cfg(not(feature = "zeroize"))] T: Debug,
         > DebugSecret for Vec<T>
     {
+        fn debug_secret_byte_len(&self) -> Option<usize> {
+            Some(core::mem::size_of_val(self.as_slice()))
+        }
+    }
+
+    impl<
+            #[cfg(feature = "zeroize")] T: Debug + Zeroize,
+            #[cfg(not(feature = "zeroize"))] T: Debug,
+        > DebugSecret for Option<T>
+    {
+    }
+
+    #[cfg(feature = "alloc")]
+    use alloc::boxed::Box;
+
+    #[cfg(feature = "alloc")]
+    impl DebugSecret for Box<str> {
+        fn debug_secret_byte_len(&self) -> Option<usize> {
+            Some(self.len())
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    impl<
+            #[cfg(feature = "zeroize")] T: Debug + Zeroize,
+            #[cfg(not(feature = "zeroize"))] T: Debug,
+        > DebugSecret for Box<[T]>
+    {
+        fn debug_secret_byte_len(&self) -> Option<usize> {
+            Some(core::mem::size_of_val(&**self))
+        }
+    }
+
+    #[cfg(feature = "zeroize")]
+    impl<T: Debug + Zeroize> DebugSecret for zeroize::Zeroizing<T> {}
+
+    // `zeroize` does not implement `Zeroize` for `Box<T>`, `Cow`, `BTreeMap` and `HashMap`,
+    // so they are only debuggable secrets without the "zeroize" feature.
+    #[cfg(all(any(feature = "alloc", feature = "std"), not(feature = "zeroize")))]
+    use alloc::{borrow::Cow, collections::BTreeMap};
+
+    #[cfg(all(feature = "std", not(feature = "zeroize")))]
+    use std::collections::HashMap;
+
+    #[cfg(all(any(feature = "alloc", feature = "std"), not(feature = "zeroize")))]
+    impl<T: Debug> DebugSecret for alloc::boxed::Box<T> {}
+
+    #[cfg(all(any(feature = "alloc", feature = "std"), not(feature = "zeroize")))]
+    impl DebugSecret for Cow<'static, str> {
+        fn debug_secret_byte_len(&self) -> Option<usize> {
+            Some(self.len())
+        }
     }
 
+    #[cfg(all(any(feature = "alloc", feature = "std"), not(feature = "zeroize")))]
+    impl<K: Debug, V: Debug> DebugSecret for BTreeMap<K, V> {}
+
+    #[cfg(all(feature = "std", not(feature = "zeroize")))]
+    impl<K: Debug, V: Debug, S> DebugSecret for HashMap<K, V, S> {}
+
     crate::macros::impl_debug_secret_for_numbers!(
-        i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize, f32, f64
+        i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize, f32, f64, bool, char
+    );
+
+    crate::macros::impl_debug_secret_for_numbers!(
+        core::num::NonZeroI8,
+        core::num::NonZeroI16,
+        core::num::NonZeroI32,
+        core::num::NonZeroI64,
+        core::num::NonZeroI128,
+        core::num::NonZeroIsize,
+        core::num::NonZeroU8,
+        core::num::NonZeroU16,
+        core::num::NonZeroU32,
+        core::num::NonZeroU64,
+        core::num::NonZeroU128,
+        core::num::NonZeroUsize
+    );
+
+    crate::macros::impl_debug_secret_for_tuples!(
+        (A),
+        (A, B),
+        (A, B, C),
+        (A, B, C, D),
+        (A, B, C, D, E),
+        (A, B, C, D, E, F),
+        (A, B, C, D, E, F, G),
+        (A, B, C, D, E, F, G, H),
+        (A, B, C, D, E, F, G, H, I),
+        (A, B, C, D, E, F, G, H, I, J)
     );
+
+    // `zeroize` implements `Zeroize` for tuples of up to 10 elements.
+    #[cfg(not(feature = "zeroize"))]
+    crate::macros::impl_debug_secret_for_tuples!(
+        (A, B, C, D, E, F, G, H, I, J, K),
+        (A, B, C, D, E, F, G, H, I, J, K, L)
+    );
+}
+
+#[cfg(feature = "display-secret")]
+mod display_secret {
+    //! Traits and implementations related to displayable secrets.
+
+    #[cfg(feature = "zeroize")]
+    use zeroize::Zeroize;
+
+    /// A trait for secrets that can be displayed in a masked form, e.g. "`sk_live_****abcd`".
+    ///
+    /// `Secret` and `RTSecret` implement `Display` if their secret value implements this trait.
+    /// Only [`SHOWN_PREFIX_CHARS`](DisplaySecret::SHOWN_PREFIX_CHARS) leading and [`SHOWN_SUFFIX_CHARS`](DisplaySecret::SHOWN_SUFFIX_CHARS) trailing characters
+    /// of [`display_secret_str`](DisplaySecret::display_secret_str) are shown, around [`MASK`](DisplaySecret::MASK);
+    /// if the secret is not longer than the edges together, only the mask is shown.
+    ///
+    /// Example:
+    /// ```rust
+    /// use sosecrets_rs::{prelude::{typenum::U2, Secret}, traits::DisplaySecret};
+    /// #[cfg(feature = "zeroize")]
+    /// use zeroize::Zeroize;
+    ///
+    /// struct ApiKey([u8; 20]);
+    ///
+    /// #[cfg(feature = "zeroize")]
+    /// impl Zeroize for ApiKey {
+    ///     fn zeroize(&mut self) {
+    ///         self.0.zeroize()
+    ///     }
+    /// }
+    ///
+    /// impl DisplaySecret for ApiKey {
+    ///     const SHOWN_PREFIX_CHARS: usize = 8;
+    ///     const SHOWN_SUFFIX_CHARS: usize = 4;
+    ///
+    ///     fn display_secret_str(&self) -> &str {
+    ///         core::str::from_utf8(&self.0).unwrap_or_default()
+    ///     }
+    /// }
+    ///
+    /// let secret = Secret::<_, U2>::new(ApiKey(*b"sk_live_12345678abcd"));
+    /// assert_eq!(format!("{}", secret), "sk_live_****abcd");
+    /// ```
+    #[cfg(feature = "zeroize")]
+    pub trait DisplaySecret: Zeroize {
+        /// The number of leading characters shown, none by default.
+        const SHOWN_PREFIX_CHARS: usize = 0;
+
+        /// The number of trailing characters shown, none by default.
+        const SHOWN_SUFFIX_CHARS: usize = 0;
+
+        /// The text written in place of the hidden characters.
+        const MASK: &'static str = "****";
+
+        /// Returns the secret as text, of which only the edges are shown.
+        fn display_secret_str(&self) -> &str;
+    }
+
+    /// A trait for secrets that can be displayed in a masked form, e.g. "`sk_live_****abcd`".
+    ///
+    /// `Secret` and `RTSecret` implement `Display` if their secret value implements this trait.
+    /// Only [`SHOWN_PREFIX_CHARS`](DisplaySecret::SHOWN_PREFIX_CHARS) leading and [`SHOWN_SUFFIX_CHARS`](DisplaySecret::SHOWN_SUFFIX_CHARS) trailing characters
+    /// of [`display_secret_str`](DisplaySecret::display_secret_str) are shown, around [`MASK`](DisplaySecret::MASK);
+    /// if the secret is not longer than the edges together, only the mask is shown.
+    #[cfg(not(feature = "zeroize"))]
+    pub trait DisplaySecret {
+        /// The number of leading characters shown, none by default.
+        const SHOWN_PREFIX_CHARS: usize = 0;
+
+        /// The number of trailing characters shown, none by default.
+        const SHOWN_SUFFIX_CHARS: usize = 0;
+
+        /// The text written in place of the hidden characters.
+        const MASK: &'static str = "****";
+
+        /// Returns the secret as text, of which only the edges are shown.
+        fn display_secret_str(&self) -> &str;
+    }
+
+    /// Returns whether any character of a secret of type `T` may be shown, i.e. whether displaying it reads the secret.
+    pub(crate) const fn shows_edges<T: DisplaySecret>() -> bool {
+        T::SHOWN_PREFIX_CHARS != 0 || T::SHOWN_SUFFIX_CHARS != 0
+    }
+
+    /// Formats `secret` as its shown edges around [`DisplaySecret::MASK`].
+    pub(crate) fn fmt_display_secret<T: DisplaySecret>(
+        f: &mut core::fmt::Formatter<'_>,
+        secret: &T,
+    ) -> core::fmt::Result {
+        let text = secret.display_secret_str();
+        let char_count = text.chars().count();
+        let shown = T::SHOWN_PREFIX_CHARS.saturating_add(T::SHOWN_SUFFIX_CHARS);
+        if shown == 0 || char_count <= shown {
+            return f.write_str(T::MASK);
+        }
+        let byte_index = |char_index: usize| {
+            text.char_indices()
+                .nth(char_index)
+                .map_or(text.len(), |(byte_index, _)| byte_index)
+        };
+        f.write_str(&text[..byte_index(T::SHOWN_PREFIX_CHARS)])?;
+        f.write_str(T::MASK)?;
+        f.write_str(&text[byte_index(char_count - T::SHOWN_SUFFIX_CHARS)..])
+    }
+
+    #[cfg(feature = "alloc")]
+    use alloc::string::String;
+
+    #[cfg(feature = "alloc")]
+    impl DisplaySecret for String {
+        fn display_secret_str(&self) -> &str {
+            self
+        }
+    }
+}
+
+#[cfg(feature = "fingerprint")]
+mod fingerprint_secret {
+    //! Traits and implementations related to fingerprintable secrets.
+
+    #[cfg(feature = "zeroize")]
+    use zeroize::Zeroize;
+
+    /// A trait for byte-like secrets whose [`Fingerprint`](crate::fingerprint::Fingerprint) can be computed.
+    ///
+    /// `Secret`, `RTSecret` and `SecrecySecret` have a `fingerprint()` method if their secret value implements this trait.
+    #[cfg(feature = "zeroize")]
+    pub trait FingerprintSecret: Zeroize {
+        /// Returns the bytes of the secret that are fingerprinted.
+        fn fingerprint_bytes(&self) -> &[u8];
+    }
+
+    /// A trait for byte-like secrets whose [`Fingerprint`](crate::fingerprint::Fingerprint) can be computed.
+    ///
+    /// `Secret`, `RTSecret` and `SecrecySecret` have a `fingerprint()` method if their secret value implements this trait.
+    #[cfg(not(feature = "zeroize"))]
+    pub trait FingerprintSecret {
+        /// Returns the bytes of the secret that are fingerprinted.
+        fn fingerprint_bytes(&self) -> &[u8];
+    }
+
+    impl<const N: usize> FingerprintSecret for [u8; N] {
+        fn fingerprint_bytes(&self) -> &[u8] {
+            self
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    use alloc::{string::String, vec::Vec};
+
+    #[cfg(feature = "alloc")]
+    impl FingerprintSecret for String {
+        fn fingerprint_bytes(&self) -> &[u8] {
+            self.as_bytes()
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    impl FingerprintSecret for Vec<u8> {
+        fn fingerprint_bytes(&self) -> &[u8] {
+            self
+        }
+    }
+}
+
+#[cfg(feature = "sealed")]
+mod sealable_secret {
+    //! Traits and implementations related to secrets which can be sealed, i.e. kept encrypted in memory.
+
+    use alloc::{string::String, vec::Vec};
+    use zeroize::Zeroize;
+
+    /// A trait for byte-like secrets which can be kept encrypted in a [`SealedSecret`](crate::runtime::sealed::SealedSecret).
+    ///
+    /// The bytes returned by [`SealableSecret::sealable_bytes`] are encrypted, and a new value is rebuilt from them
+    /// with [`SealableSecret::from_unsealed_bytes`] for every exposure.
+    pub trait SealableSecret: Zeroize + Sized {
+        /// Returns the bytes of the secret that are sealed.
+        fn sealable_bytes(&self) -> &[u8];
+
+        /// Rebuilds the secret from `bytes`, which are always the bytes returned by `sealable_bytes` for a value of the same type.
+        fn from_unsealed_bytes(bytes: &[u8]) -> Self;
+    }
+
+    impl<const N: usize> SealableSecret for [u8; N] {
+        fn sealable_bytes(&self) -> &[u8] {
+            self
+        }
+
+        fn from_unsealed_bytes(bytes: &[u8]) -> Self {
+            let mut array = [0; N];
+            array.copy_from_slice(bytes);
+            array
+        }
+    }
+
+    impl SealableSecret for String {
+        fn sealable_bytes(&self) -> &[u8] {
+            self.as_bytes()
+        }
+
+        fn from_unsealed_bytes(bytes: &[u8]) -> Self {
+            String::from(core::str::from_utf8(bytes).expect("the sealed bytes of a `String` are valid UTF-8"))
+        }
+    }
+
+    impl SealableSecret for Vec<u8> {
+        fn sealable_bytes(&self) -> &[u8] {
+            self
+        }
+
+        fn from_unsealed_bytes(bytes: &[u8]) -> Self {
+            bytes.to_vec()
+        }
+    }
 }
 
 impl_sealed_trait_for_uint!(u8, u16, u32, u64, u128);
@@ -172,6 +750,7 @@ pub trait ChooseMinimallyRepresentableUInt: __private::SealedTrait {
     /// e.g. If `Self` is `typenum::consts::U69`, then `Self::Output` is `u8`.
     type Output: AddAssign
         + Add<Self::Output, Output = Self::Output>
+        + Sub<Self::Output, Output = Self::Output>
         + PartialOrd
         + Debug
         + Display
@@ -188,10 +767,24 @@ pub trait ChooseMinimallyRepresentableUInt: __private::SealedTrait {
     const ZERO: Self::Output;
     /// The multiplicative identity of the type `Self::Output`, e.g. `1_usize`, `1_u32`.
     const ONE: Self::Output;
+    /// The type storing the optional label of an `RTSecret`, zero-sized if `Self` is `U0` since such an `RTSecret` never fails to be exposed.
+    type Label: Copy;
+    /// The value of `Self::Label` without a label.
+    const NO_LABEL: Self::Label;
 
     /// A convenient method to convert the unsigned integer represented at the type level by `Self` to a value of type `Self::Output`.
     /// e.g. converting from `typenum::consts::U69` to `69_u8`.
     fn cast_unsigned_to_self_type<T: Unsigned>(_: __private::SealedToken) -> Self::Output;
+
+    /// A convenient method to convert a value of type `Self::Output` to `usize`.
+    /// e.g. converting from `69_u8` to `69_usize`.
+    fn cast_self_type_to_usize(value: Self::Output, _: __private::SealedToken) -> usize;
+
+    /// Converts `label` to a value of type `Self::Label`, which discards it if `Self::Label` is zero-sized.
+    fn to_label(label: &'static str, _: __private::SealedToken) -> Self::Label;
+
+    /// Converts a value of type `Self::Label` back to the label it stores, if any.
+    fn from_label(label: Self::Label, _: __private::SealedToken) -> Option<&'static str>;
 }
 
 /// A trait for types that can be converted to their atomic representation.
@@ -328,10 +921,24 @@ impl ChooseMinimallyRepresentableUInt for U0 {
 
     const ZERO: Self::Output = NumericalZeroSizedType {};
     const ONE: Self::Output = NumericalZeroSizedType {};
+    type Label = NumericalZeroSizedType;
+    const NO_LABEL: Self::Label = NumericalZeroSizedType {};
 
     fn cast_unsigned_to_self_type<T: Unsigned>(_: __private::SealedToken) -> Self::Output {
         NumericalZeroSizedType {}
     }
+
+    fn cast_self_type_to_usize(_: Self::Output, _: __private::SealedToken) -> usize {
+        0
+    }
+
+    fn to_label(_: &'static str, _: __private::SealedToken) -> Self::Label {
+        NumericalZeroSizedType {}
+    }
+
+    fn from_label(_: Self::Label, _: __private::SealedToken) -> Option<&'static str> {
+        None
+    }
 }
 
 #[cfg(target_has_atomic = "8")]
@@ -8,10 +8,24 @@ impl ChooseMinimallyRepresentableUInt for NumericalZeroSizedType {
 
     const ZERO: Self::Output = NumericalZeroSizedType {};
     const ONE: Self::Output = NumericalZeroSizedType {};
+    type Label = NumericalZeroSizedType;
+    const NO_LABEL: Self::Label = NumericalZeroSizedType {};
 
     fn cast_unsigned_to_self_type<T: typenum::Unsigned>(_: __private::SealedToken) -> Self::Output {
         NumericalZeroSizedType {}
     }
+
+    fn cast_self_type_to_usize(_: Self::Output, _: __private::SealedToken) -> usize {
+        0
+    }
+
+    fn to_label(_: &'static str, _: __private::SealedToken) -> Self::Label {
+        NumericalZeroSizedType {}
+    }
+
+    fn from_label(_: Self::Label, _: __private::SealedToken) -> Option<&'static str> {
+        None
+    }
 }
 
 #[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Default)]
@@ -29,6 +43,14 @@ impl core::ops::Add<Self> for NumericalZeroSizedType {
     }
 }
 
+impl core::ops::Sub<Self> for NumericalZeroSizedType {
+    type Output = Self;
+
+    fn sub(self, _other: Self) -> Self::Output {
+        NumericalZeroSizedType {}
+    }
+}
+
 impl core::fmt::Display for NumericalZeroSizedType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         write!(f, "NumericalZeroSizedType")
@@ -8,13 +8,13 @@ impl<T> UseSecret<T> {
     }
 }
 
-#[cfg(feature = "debug-secret")]
+#[cfg(any(feature = "debug-secret", feature = "display-secret"))]
 pub struct Comparator<'a> {
     valid: bool,
     to_compare: &'a str,
 }
 
-#[cfg(feature = "debug-secret")]
+#[cfg(any(feature = "debug-secret", feature = "display-secret"))]
 impl<'a> Comparator<'a> {
     pub const fn new(s: &'a str) -> Self {
         Self {
@@ -23,16 +23,15 @@ impl<'a> Comparator<'a> {
         }
     }
 
-    pub const fn is_valid(self) -> bool {
+    pub const fn is_valid(&self) -> bool {
         self.valid && self.to_compare.is_empty()
     }
 }
 
-#[cfg(feature = "debug-secret")]
+#[cfg(any(feature = "debug-secret", feature = "display-secret"))]
 impl<'a> core::fmt::Write for Comparator<'a> {
     fn write_str(&mut self, s: &str) -> Result<(), core::fmt::Error> {
         if s.eq(self.to_compare) {
-            self.valid = self.valid && true;
             self.to_compare = "";
             return Ok(());
         }
@@ -45,3 +44,35 @@ impl<'a> core::fmt::Write for Comparator<'a> {
         Ok(())
     }
 }
+
+/// A random number generator which always fails.
+#[cfg(feature = "rand-core")]
+#[allow(dead_code)]
+pub struct FailingRng;
+
+#[cfg(feature = "rand-core")]
+impl sosecrets_rs::prelude::rand_core::RngCore for FailingRng {
+    fn next_u32(&mut self) -> u32 {
+        unimplemented!()
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        unimplemented!()
+    }
+
+    fn fill_bytes(&mut self, _: &mut [u8]) {
+        unimplemented!()
+    }
+
+    fn try_fill_bytes(
+        &mut self,
+        _: &mut [u8],
+    ) -> Result<(), sosecrets_rs::prelude::rand_core::Error> {
+        use sosecrets_rs::prelude::rand_core::Error;
+
+        Err(core::num::NonZeroU32::new(Error::CUSTOM_START).unwrap().into())
+    }
+}
+
+#[cfg(feature = "rand-core")]
+impl sosecrets_rs::prelude::rand_core::CryptoRng for FailingRng {}
@@ -1,8 +1,10 @@
+#![allow(clippy::let_and_return)]
+
 use sosecrets_rs::{prelude::*, traits::ExposeSecret};
 use typenum::consts::{U2, U5};
 mod common;
 use common::UseSecret;
-#[cfg(feature = "debug-secret")]
+#[cfg(any(feature = "debug-secret", feature = "display-secret"))]
 use core::fmt::Write;
 
 #[test]
@@ -519,3 +521,743 @@ fn test_static_ref_and_ref_can_leak_secret() {
 
     assert_eq!(exposed_secret._inner, 69);
 }
+
+#[cfg(feature = "cloneable-secret")]
+#[test]
+fn test_split_exposure_budget() {
+    use typenum::{U1, U3};
+
+    let new_secret: Secret<_, U5> = Secret::new(69);
+    let (new_secret, _) = new_secret.expose_secret(|exposed_secret| *exposed_secret);
+
+    let (secret_one, secret_two) = new_secret.split::<U1, U3>();
+
+    let (_, returned_value) = secret_one.expose_secret(|exposed_secret| *exposed_secret);
+    assert_eq!(69, returned_value);
+
+    let (secret_two, returned_value) = secret_two.expose_secret(|exposed_secret| *exposed_secret);
+    assert_eq!(69, returned_value);
+    let (secret_two, returned_value) = secret_two.expose_secret(|exposed_secret| *exposed_secret);
+    assert_eq!(69, returned_value);
+    let (_, returned_value) = secret_two.expose_secret(|exposed_secret| *exposed_secret);
+    assert_eq!(69, returned_value);
+}
+
+#[test]
+fn test_exposure_budget_introspection() {
+    let new_secret: Secret<_, U2> = Secret::new(69);
+    assert_eq!(Secret::<i32, U2>::MAX_EXPOSURES, 2);
+    assert_eq!(new_secret.max_exposures(), 2);
+    assert_eq!(new_secret.exposure_count(), 0);
+    assert_eq!(new_secret.remaining_exposures(), 2);
+    assert!(!new_secret.is_exhausted());
+
+    let (new_secret, _) = new_secret.expose_secret(|exposed_secret| *exposed_secret);
+    assert_eq!(new_secret.exposure_count(), 1);
+    assert_eq!(new_secret.remaining_exposures(), 1);
+    assert!(!new_secret.is_exhausted());
+
+    let (new_secret, _) = new_secret.expose_secret(|exposed_secret| *exposed_secret);
+    assert_eq!(new_secret.exposure_count(), 2);
+    assert_eq!(new_secret.remaining_exposures(), 0);
+    assert!(new_secret.is_exhausted());
+}
+
+#[test]
+#[cfg(feature = "debug-secret")]
+fn test_debug_secret_alternate_hides_exposure_budget() {
+    use sosecrets_rs::traits::{DebugSecret, DebugSecretMetadata};
+    #[cfg(feature = "zeroize")]
+    use zeroize::Zeroize;
+
+    #[derive(Debug, Clone)]
+    struct A {
+        _inner: i32,
+    }
+
+    #[cfg(feature = "zeroize")]
+    impl Zeroize for A {
+        fn zeroize(&mut self) {
+            self._inner.zeroize()
+        }
+    }
+
+    impl DebugSecret for A {}
+
+    let new_secret: Secret<A, U5> = Secret::new(A { _inner: 69 });
+    let (new_secret, _) = new_secret.expose_secret(|_| {});
+
+    let mut cmp = common::Comparator::new("Secret<[REDACTED]>");
+    let _ = write!(&mut cmp, "{:#?}", new_secret);
+    assert!(cmp.is_valid());
+
+    let mut cmp = common::Comparator::new("Secret<[REDACTED]> { remaining_exposures: 4 }");
+    let _ = write!(
+        &mut cmp,
+        "{:#?}",
+        new_secret.debug_with_metadata(DebugSecretMetadata::NONE.with_remaining_exposures())
+    );
+    assert!(cmp.is_valid());
+}
+
+#[test]
+#[cfg(feature = "debug-secret")]
+fn test_debug_secret_metadata() {
+    use sosecrets_rs::traits::{DebugSecret, DebugSecretMetadata};
+    #[cfg(feature = "zeroize")]
+    use zeroize::Zeroize;
+
+    #[derive(Debug, Clone)]
+    struct ApiKey([u8; 4]);
+
+    #[cfg(feature = "zeroize")]
+    impl Zeroize for ApiKey {
+        fn zeroize(&mut self) {
+            self.0.zeroize()
+        }
+    }
+
+    impl DebugSecret for ApiKey {
+        const DEBUG_METADATA: DebugSecretMetadata =
+            DebugSecretMetadata::NONE.with_byte_len().with_remaining_exposures();
+
+        fn debug_secret_byte_len(&self) -> Option<usize> {
+            Some(self.0.len())
+        }
+    }
+
+    let new_secret: Secret<ApiKey, U5> = Secret::new(ApiKey([1, 2, 3, 4]));
+    let mut cmp =
+        common::Comparator::new("Secret<[REDACTED]> { byte_len: 4, remaining_exposures: 5 }");
+    let _ = write!(&mut cmp, "{:?}", new_secret);
+    assert!(cmp.is_valid());
+
+    let (new_secret, _) = new_secret.expose_secret(|_| {});
+    let mut cmp =
+        common::Comparator::new("Secret<[REDACTED]> { byte_len: 4, remaining_exposures: 4 }");
+    let _ = write!(&mut cmp, "{:#?}", new_secret);
+    assert!(cmp.is_valid());
+
+    let mut cmp = common::Comparator::new("Secret<[REDACTED]>");
+    let _ = write!(
+        &mut cmp,
+        "{:?}",
+        new_secret.debug_with_metadata(DebugSecretMetadata::NONE)
+    );
+    assert!(cmp.is_valid());
+
+    let new_secret: Secret<[u16; 3], U2> = Secret::new([69, 420, 1337]);
+    let mut cmp = common::Comparator::new("Secret<[REDACTED]>");
+    let _ = write!(&mut cmp, "{:?}", new_secret);
+    assert!(cmp.is_valid());
+
+    let mut cmp = common::Comparator::new(
+        "Secret<[REDACTED]> { type_name: \"[u16; 3]\", byte_len: 6, remaining_exposures: 2 }",
+    );
+    let _ = write!(
+        &mut cmp,
+        "{:?}",
+        new_secret.debug_with_metadata(DebugSecretMetadata::ALL)
+    );
+    assert!(cmp.is_valid());
+}
+
+#[test]
+#[cfg(all(feature = "debug-secret", feature = "alloc"))]
+fn test_debug_secret_metadata_byte_len_of_string_and_vec() {
+    use sosecrets_rs::traits::DebugSecretMetadata;
+
+    let metadata = DebugSecretMetadata::NONE.with_byte_len();
+
+    let string_secret: Secret<String, U2> = Secret::new("hunter2".to_owned());
+    let vec_secret: Secret<Vec<u32>, U2> = Secret::new(vec![1, 2, 3]);
+
+    let mut cmp = common::Comparator::new("Secret<[REDACTED]> { byte_len: 7 }");
+    let _ = write!(&mut cmp, "{:?}", string_secret.debug_with_metadata(metadata));
+    assert!(cmp.is_valid());
+
+    let mut cmp = common::Comparator::new("Secret<[REDACTED]> { byte_len: 12 }");
+    let _ = write!(&mut cmp, "{:?}", vec_secret.debug_with_metadata(metadata));
+    assert!(cmp.is_valid());
+
+    let output = format!("{:#?}", string_secret.debug_with_metadata(DebugSecretMetadata::ALL));
+    assert!(!output.contains("hunter2"));
+}
+
+#[test]
+#[cfg(feature = "display-secret")]
+fn test_display_secret() {
+    use sosecrets_rs::traits::DisplaySecret;
+    #[cfg(feature = "zeroize")]
+    use zeroize::Zeroize;
+
+    struct ApiKey(&'static str);
+
+    #[cfg(feature = "zeroize")]
+    impl Zeroize for ApiKey {
+        fn zeroize(&mut self) {
+            self.0 = "";
+        }
+    }
+
+    impl DisplaySecret for ApiKey {
+        const SHOWN_PREFIX_CHARS: usize = 8;
+        const SHOWN_SUFFIX_CHARS: usize = 4;
+
+        fn display_secret_str(&self) -> &str {
+            self.0
+        }
+    }
+
+    struct Pin(&'static str);
+
+    #[cfg(feature = "zeroize")]
+    impl Zeroize for Pin {
+        fn zeroize(&mut self) {
+            self.0 = "";
+        }
+    }
+
+    impl DisplaySecret for Pin {
+        const MASK: &'static str = "[PIN]";
+
+        fn display_secret_str(&self) -> &str {
+            self.0
+        }
+    }
+
+    let new_secret: Secret<ApiKey, U2> = Secret::new(ApiKey("sk_live_51HxYzabcd"));
+    let mut cmp = common::Comparator::new("sk_live_****abcd");
+    let _ = write!(&mut cmp, "{}", new_secret);
+    assert!(cmp.is_valid());
+
+    // The edges are counted in characters, not in bytes.
+    let new_secret: Secret<ApiKey, U2> = Secret::new(ApiKey("clé_testé_secrète_ünïcödé"));
+    let mut cmp = common::Comparator::new("clé_test****cödé");
+    let _ = write!(&mut cmp, "{}", new_secret.expose_secret(|_| {}).0);
+    assert!(cmp.is_valid());
+
+    // A secret not longer than its edges is fully masked.
+    let new_secret: Secret<ApiKey, U2> = Secret::new(ApiKey("sk_live_abcd"));
+    let mut cmp = common::Comparator::new("****");
+    let _ = write!(&mut cmp, "{}", new_secret);
+    assert!(cmp.is_valid());
+
+    // An exhausted `Secret` is fully masked.
+    let new_secret: Secret<ApiKey, U2> = Secret::new(ApiKey("sk_live_51HxYzabcd"));
+    let (new_secret, _) = new_secret.expose_secret(|_| {});
+    let (new_secret, _) = new_secret.expose_secret(|_| {});
+    let mut cmp = common::Comparator::new("****");
+    let _ = write!(&mut cmp, "{}", new_secret);
+    assert!(cmp.is_valid());
+
+    let new_secret: Secret<Pin, U2> = Secret::new(Pin("1234"));
+    let mut cmp = common::Comparator::new("PIN: [PIN]");
+    let _ = write!(&mut cmp, "PIN: {}", new_secret);
+    assert!(cmp.is_valid());
+}
+
+#[test]
+#[cfg(all(feature = "display-secret", feature = "alloc"))]
+fn test_display_secret_string() {
+    let new_secret: Secret<String, U2> = Secret::new("hunter2".to_owned());
+    assert_eq!(format!("password: {}", new_secret), "password: ****");
+}
+
+#[test]
+#[cfg(feature = "fingerprint")]
+fn test_fingerprint() {
+    use sosecrets_rs::fingerprint::FingerprintKey;
+
+    let key = FingerprintKey::new(b"fingerprint key", b"production");
+
+    let new_secret: Secret<[u8; 12], U2> = Secret::new(*b"sk_live_abcd");
+    assert_eq!(new_secret.exposure_count(), 0);
+    let (new_secret, fingerprint) = new_secret.fingerprint(&key);
+    assert_eq!(new_secret.exposure_count(), 1);
+    assert_eq!(fingerprint.to_string(), "74952a968620c9aa");
+    assert_eq!(
+        format!("{:?}", fingerprint),
+        "Fingerprint(74952a968620c9aa)"
+    );
+
+    let (new_secret, same_fingerprint) = new_secret.fingerprint(&key);
+    assert_eq!(new_secret.exposure_count(), 2);
+    assert_eq!(fingerprint, same_fingerprint);
+
+    // The fingerprint depends on the salt and on the key.
+    let (_, fingerprint) = Secret::<_, U2>::new(*b"sk_live_abcd")
+        .fingerprint(&FingerprintKey::new(b"fingerprint key", b"staging"));
+    assert_eq!(fingerprint.to_string(), "542bc020eaa60b76");
+    let (_, fingerprint) = Secret::<_, U2>::new(*b"sk_live_abcd")
+        .fingerprint(&FingerprintKey::new(b"other key", b"production"));
+    assert_eq!(fingerprint.to_string(), "e6578b8b815f3c9d");
+
+    let (_, other_fingerprint) = Secret::<_, U2>::new(*b"sk_live_abce").fingerprint(&key);
+    assert_ne!(other_fingerprint, same_fingerprint);
+
+    assert!(!format!("{:?}", key).contains("fingerprint key"));
+}
+
+#[test]
+#[cfg(all(feature = "fingerprint", feature = "alloc"))]
+fn test_fingerprint_string_and_vec() {
+    use sosecrets_rs::fingerprint::FingerprintKey;
+
+    let key = FingerprintKey::new(b"fingerprint key", b"production");
+    let (_, string_fingerprint) =
+        Secret::<String, U2>::new("sk_live_abcd".to_owned()).fingerprint(&key);
+    let (_, vec_fingerprint) =
+        Secret::<Vec<u8>, U2>::new(b"sk_live_abcd".to_vec()).fingerprint(&key);
+    assert_eq!(string_fingerprint, vec_fingerprint);
+    assert_eq!(string_fingerprint.to_string(), "74952a968620c9aa");
+}
+
+#[test]
+#[cfg(feature = "hkdf")]
+fn test_derive_key() {
+    use sosecrets_rs::hkdf::HkdfParams;
+    use typenum::consts::U1;
+
+    // The test case 1 of RFC 5869.
+    let salt: [u8; 13] = core::array::from_fn(|i| i as u8);
+    let info: [u8; 10] = core::array::from_fn(|i| 0xf0 + i as u8);
+    let params = HkdfParams::new(&salt, &info);
+    let expected_okm = [
+        0x3c, 0xb2, 0x5f, 0x25, 0xfa, 0xac, 0xd5, 0x7a, 0x90, 0x43, 0x4f, 0x64, 0xd0, 0x36, 0x2f,
+        0x2a, 0x2d, 0x2d, 0x0a, 0x90, 0xcf, 0x1a, 0x5a, 0x4c, 0x5d, 0xb0, 0x2d, 0x56, 0xec, 0xc4,
+        0xc5, 0xbf, 0x34, 0x00, 0x72, 0x08, 0xd5, 0xb8, 0x87, 0x18, 0x58, 0x65,
+    ];
+
+    let master_key = Secret::<[u8; 22], U2>::new([0x0b; 22]);
+    let (master_key, okm) = master_key.derive_key::<42, U1>(&params);
+    assert_eq!(master_key.exposure_count(), 1);
+    assert_eq!(okm.exposure_count(), 0);
+    let (_, okm) = okm.expose_secret(|exposed_secret| *exposed_secret);
+    assert_eq!(okm, expected_okm);
+
+    let (master_key, short_okm): (_, Secret<[u8; 16], U1>) = master_key.derive_key(&params);
+    assert_eq!(master_key.exposure_count(), 2);
+    let (_, short_okm) = short_okm.expose_secret(|exposed_secret| *exposed_secret);
+    assert_eq!(short_okm, expected_okm[..16]);
+
+    // Another info derives an independent key.
+    let (_, other_okm) = Secret::<[u8; 22], U1>::new([0x0b; 22])
+        .derive_key::<42, U1>(&HkdfParams::new(&salt, b"other"));
+    let (_, other_okm) = other_okm.expose_secret(|exposed_secret| *exposed_secret);
+    assert_ne!(other_okm, expected_okm);
+
+    #[cfg(feature = "alloc")]
+    {
+        let (_, okm) = Secret::<Vec<u8>, U1>::new(vec![0x0b; 22]).derive_key::<42, U1>(&params);
+        let (_, okm) = okm.expose_secret(|exposed_secret| *exposed_secret);
+        assert_eq!(okm, expected_okm);
+    }
+}
+
+#[test]
+#[cfg(feature = "password-hash")]
+fn test_hash_password() {
+    use rand_chacha::{rand_core::SeedableRng, ChaCha20Rng};
+    use sosecrets_rs::password_hash::{Argon2Params, PasswordHashError};
+    use typenum::consts::U3;
+
+    // The Argon2id hash of "password" with the salt "somesalt" by the reference implementation.
+    let reference_hash =
+        "$argon2id$v=19$m=256,t=2,p=1$c29tZXNhbHQ$nf65EOgLrQMR/uIPnA4rEsF5h7TKyQwu9U1bMCHGi/4";
+    let (password, matches) =
+        Secret::<String, U3>::new("password".to_owned()).verify_password(reference_hash);
+    assert!(matches.unwrap());
+    assert_eq!(password.exposure_count(), 1);
+    let (_, matches) =
+        Secret::<String, U2>::new("Password".to_owned()).verify_password(reference_hash);
+    assert!(!matches.unwrap());
+
+    // The salt is the first 16 bytes of the ChaCha20 keystream under the all-zero key and nonce.
+    let mut rng = ChaCha20Rng::from_seed([0; 32]);
+    let params = Argon2Params::new(64, 1, 1);
+    let (password, stored_hash) = password.hash_password_with(&params, &mut rng);
+    let stored_hash = stored_hash.unwrap();
+    assert!(stored_hash.starts_with("$argon2id$v=19$m=64,t=1,p=1$drjgraDxPZBAXWrlU4a9KA$"));
+    assert_eq!(password.exposure_count(), 2);
+    let (password, matches) = password.verify_password(&stored_hash);
+    assert!(matches.unwrap());
+    assert_eq!(password.exposure_count(), 3);
+
+    // The same password hashes differently with another salt.
+    let (_, other_hash) =
+        Secret::<String, U2>::new("password".to_owned()).hash_password_with(&params, &mut rng);
+    assert_ne!(other_hash.unwrap(), stored_hash);
+
+    let (_, result) = Secret::<String, U2>::new("password".to_owned())
+        .hash_password_with(&Argon2Params::new(8, 0, 1), &mut rng);
+    assert!(matches!(result, Err(PasswordHashError::InvalidParams)));
+    let (_, result) = Secret::<String, U2>::new("password".to_owned()).verify_password(
+        "$argon2i$v=19$m=256,t=2,p=1$c29tZXNhbHQ$nf65EOgLrQMR/uIPnA4rEsF5h7TKyQwu9U1bMCHGi/4",
+    );
+    assert!(matches!(result, Err(PasswordHashError::UnsupportedHash)));
+    for invalid_hash in ["", "password", "$argon2id$v=19$m=256,t=2,p=1$c29tZXNhbHQ"] {
+        let (_, result) =
+            Secret::<String, U2>::new("password".to_owned()).verify_password(invalid_hash);
+        assert!(
+            matches!(result, Err(PasswordHashError::InvalidHash)),
+            "{}",
+            invalid_hash
+        );
+    }
+
+    assert_eq!(Argon2Params::default(), Argon2Params::new(19 * 1024, 2, 1));
+}
+
+#[test]
+#[cfg(all(feature = "fingerprint", feature = "debug-secret"))]
+fn test_fingerprint_in_debug_secret_output() {
+    use sosecrets_rs::{fingerprint::FingerprintKey, traits::DebugSecretMetadata};
+
+    let key = FingerprintKey::new(b"fingerprint key", b"production");
+    let (new_secret, fingerprint) = Secret::<[u8; 12], U5>::new(*b"sk_live_abcd").fingerprint(&key);
+
+    let mut cmp = common::Comparator::new(
+        "Secret<[REDACTED]> { byte_len: 12, fingerprint: 74952a968620c9aa, remaining_exposures: 4 }",
+    );
+    let _ = write!(
+        &mut cmp,
+        "{:?}",
+        new_secret.debug_with_metadata(
+            DebugSecretMetadata::NONE
+                .with_byte_len()
+                .with_fingerprint(fingerprint)
+                .with_remaining_exposures()
+        )
+    );
+    assert!(cmp.is_valid());
+}
+
+#[test]
+fn test_secret_array_in_place_appending() {
+    use sosecrets_rs::containers::{CapacityError, SecretArray};
+
+    let new_secret: Secret<SecretArray<8>, U5> = Secret::new(SecretArray::new());
+    let (new_secret, returned_value) =
+        new_secret.expose_secret_mut(|mut exposed_secret| exposed_secret.try_push_str("hunter"));
+    assert_eq!(returned_value, Ok(()));
+    assert_eq!(new_secret.exposure_count(), 1);
+
+    let (new_secret, returned_value) = new_secret
+        .expose_secret_mut(|mut exposed_secret| exposed_secret.try_extend_from_slice(b"2!!!"));
+    assert_eq!(
+        returned_value,
+        Err(CapacityError {
+            capacity: 8,
+            required: 10
+        })
+    );
+
+    let (new_secret, returned_value) = new_secret.expose_secret_mut(|mut exposed_secret| {
+        exposed_secret.try_push(b'2').unwrap();
+        exposed_secret.try_push(b'!')
+    });
+    assert_eq!(returned_value, Ok(()));
+
+    let (new_secret, (len, remaining_capacity)) = new_secret.expose_secret(|exposed_secret| {
+        (exposed_secret.len(), exposed_secret.remaining_capacity())
+    });
+    assert_eq!((len, remaining_capacity), (8, 0));
+
+    let (_, is_hunter2) =
+        new_secret.expose_secret(|exposed_secret| exposed_secret.as_str() == Some("hunter2!"));
+    assert!(is_hunter2);
+}
+
+#[test]
+fn test_secret_array() {
+    use sosecrets_rs::containers::{CapacityError, SecretArray};
+
+    let mut array = SecretArray::<4>::from_slice(b"abc").unwrap();
+    assert_eq!(array.as_slice(), b"abc");
+    assert_eq!(array.capacity(), SecretArray::<4>::CAPACITY);
+    assert_eq!(
+        array.try_push_str("de"),
+        Err(CapacityError {
+            capacity: 4,
+            required: 5
+        })
+    );
+    assert_eq!(array.as_slice(), b"abc");
+
+    array.as_mut_slice()[0] = b'x';
+    array.truncate(2);
+    assert_eq!(array.as_slice(), b"xb");
+    array.truncate(3);
+    assert_eq!(array.len(), 2);
+    array.clear();
+    assert!(array.is_empty());
+    assert_eq!(array.as_str(), Some(""));
+
+    assert!(SecretArray::<4>::from_slice(b"abcde").is_err());
+    assert!(SecretArray::<4>::from_slice(&[0xff])
+        .unwrap()
+        .as_str()
+        .is_none());
+    assert_eq!(
+        format!("{:?}", SecretArray::<4>::from_slice(b"abc").unwrap()),
+        "SecretArray([REDACTED])"
+    );
+}
+
+#[test]
+#[cfg(all(feature = "cloneable-secret", feature = "debug-secret"))]
+fn test_secret_array_cloneable_and_debug_secret() {
+    use sosecrets_rs::{containers::SecretArray, traits::DebugSecretMetadata};
+
+    let new_secret: Secret<_, U2> = Secret::new(SecretArray::<64>::from_slice(b"hunter2").unwrap());
+    let (_, len) = new_secret
+        .clone()
+        .expose_secret(|exposed_secret| exposed_secret.len());
+    assert_eq!(len, 7);
+
+    let mut cmp = common::Comparator::new("Secret<[REDACTED]> { byte_len: 7 }");
+    let _ = write!(
+        &mut cmp,
+        "{:?}",
+        new_secret.debug_with_metadata(DebugSecretMetadata::NONE.with_byte_len())
+    );
+    assert!(cmp.is_valid());
+}
+
+#[test]
+#[cfg(feature = "cloneable-secret")]
+fn test_cloneable_secret_for_standard_types() {
+    use core::num::NonZeroU32;
+
+    let flags: Secret<(bool, char, Option<u8>), U2> = Secret::new((true, 'x', Some(69)));
+    let (_, flags) = flags
+        .clone()
+        .expose_secret(|exposed_secret| *exposed_secret);
+    assert_eq!(flags, (true, 'x', Some(69)));
+
+    let pin = Secret::<NonZeroU32, U2>::new(NonZeroU32::new(1234).unwrap());
+    let (_, pin) = pin.clone().expose_secret(|exposed_secret| *exposed_secret);
+    assert_eq!(pin.get(), 1234);
+
+    let wide = Secret::<_, U2>::new((1u8, 2u16, 3u32, 4u64, 5u128, 6i8, 7i16, 8i32, 9i64, 10i128));
+    let (_, last) = wide
+        .clone()
+        .expose_secret(|exposed_secret| exposed_secret.9);
+    assert_eq!(last, 10);
+}
+
+#[test]
+#[cfg(all(feature = "cloneable-secret", feature = "alloc"))]
+fn test_cloneable_secret_for_composite_credentials() {
+    let credentials: Secret<(String, String), U2> =
+        Secret::new(("admin".to_owned(), "hunter2".to_owned()));
+    let (_, username) = credentials
+        .clone()
+        .expose_secret(|exposed_secret| exposed_secret.0.clone());
+    assert_eq!(username, "admin");
+
+    let boxed = Secret::<Box<str>, U2>::new("hunter2".into());
+    let (_, len) = boxed
+        .clone()
+        .expose_secret(|exposed_secret| exposed_secret.len());
+    assert_eq!(len, 7);
+
+    let boxed = Secret::<Box<[u8]>, U2>::new(vec![1, 2, 3].into_boxed_slice());
+    let (_, len) = boxed
+        .clone()
+        .expose_secret(|exposed_secret| exposed_secret.len());
+    assert_eq!(len, 3);
+}
+
+#[test]
+#[cfg(all(
+    feature = "cloneable-secret",
+    feature = "debug-secret",
+    any(feature = "alloc", feature = "std"),
+    not(feature = "zeroize")
+))]
+fn test_cloneable_and_debug_secret_for_std_types_without_zeroize() {
+    use std::{borrow::Cow, collections::BTreeMap};
+
+    let token = Secret::<Cow<'static, str>, U2>::new(Cow::Borrowed("hunter2"));
+    assert_eq!(format!("{:?}", token.clone()), "Secret<[REDACTED]>");
+
+    #[cfg(feature = "std")]
+    {
+        let mut by_host = std::collections::HashMap::new();
+        by_host.insert("db", "hunter2");
+        let by_host = Secret::<_, U2>::new(by_host);
+        let (_, len) = by_host
+            .clone()
+            .expose_secret(|exposed_secret| exposed_secret.len());
+        assert_eq!(len, 1);
+    }
+
+    let by_user = Secret::<_, U2>::new(BTreeMap::from([("admin", "hunter2")]));
+    assert_eq!(format!("{:?}", by_user.clone()), "Secret<[REDACTED]>");
+
+    let boxed = Secret::<Box<u64>, U2>::new(Box::new(69));
+    assert_eq!(format!("{:?}", boxed.clone()), "Secret<[REDACTED]>");
+
+    let widest = Secret::<_, U2>::new((1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 'l'));
+    assert_eq!(format!("{:?}", widest.clone()), "Secret<[REDACTED]>");
+}
+
+#[test]
+#[cfg(all(feature = "debug-secret", feature = "alloc"))]
+fn test_debug_secret_for_standard_types() {
+    use sosecrets_rs::traits::DebugSecretMetadata;
+
+    let credentials: Secret<(String, String), U2> =
+        Secret::new(("admin".to_owned(), "hunter2".to_owned()));
+    assert_eq!(format!("{:?}", credentials), "Secret<[REDACTED]>");
+
+    let maybe_token = Secret::<Option<Box<str>>, U2>::new(Some("hunter2".into()));
+    assert_eq!(format!("{:?}", maybe_token), "Secret<[REDACTED]>");
+
+    let boxed = Secret::<Box<str>, U2>::new("hunter2".into());
+    assert_eq!(
+        format!(
+            "{:?}",
+            boxed.debug_with_metadata(DebugSecretMetadata::NONE.with_byte_len())
+        ),
+        "Secret<[REDACTED]> { byte_len: 7 }"
+    );
+}
+
+#[test]
+#[cfg(feature = "zeroize")]
+fn test_zeroize_on_drop_secret_is_zeroized_once() {
+    use core::sync::atomic::{AtomicUsize, Ordering};
+    use sosecrets_rs::containers::ZeroizedOnDrop;
+    use zeroize::{Zeroize, ZeroizeOnDrop, Zeroizing};
+
+    static DROPS: AtomicUsize = AtomicUsize::new(0);
+
+    struct SelfZeroizing([u8; 4]);
+
+    impl Drop for SelfZeroizing {
+        fn drop(&mut self) {
+            self.0 = [0; 4];
+            DROPS.fetch_add(1, Ordering::SeqCst);
+        }
+    }
+
+    impl ZeroizeOnDrop for SelfZeroizing {}
+
+    let secret = Secret::<_, U2>::new(ZeroizedOnDrop::new(SelfZeroizing([1, 2, 3, 4])));
+    let (secret, first) =
+        secret.expose_secret(|exposed_secret| exposed_secret.get().map(|value| value.0[0]));
+    assert_eq!(first, Some(1));
+    assert_eq!(DROPS.load(Ordering::SeqCst), 0);
+    drop(secret);
+    assert_eq!(DROPS.load(Ordering::SeqCst), 1);
+
+    let secret = Secret::<Zeroizing<[u8; 4]>, U2>::new(Zeroizing::new([1, 2, 3, 4]));
+    let (_, sum) = secret.expose_secret(|exposed_secret| exposed_secret.iter().sum::<u8>());
+    assert_eq!(sum, 10);
+
+    let mut wrapper = ZeroizedOnDrop::from(Zeroizing::new([1u8, 2, 3, 4]));
+    assert_eq!(wrapper.get().map(|value| value[3]), Some(4));
+    wrapper.zeroize();
+    assert!(wrapper.is_zeroized());
+    assert!(wrapper.get().is_none());
+    assert_eq!(format!("{:?}", wrapper), "ZeroizedOnDrop([REDACTED])");
+}
+
+#[test]
+#[cfg(all(
+    feature = "zeroize",
+    feature = "cloneable-secret",
+    feature = "debug-secret"
+))]
+fn test_zeroizing_is_cloneable_and_debug_secret() {
+    use sosecrets_rs::containers::ZeroizedOnDrop;
+    use zeroize::Zeroizing;
+
+    let secret = Secret::<_, U2>::new(Zeroizing::new([1u8, 2, 3, 4]));
+    let (_, first) = secret
+        .clone()
+        .expose_secret(|exposed_secret| exposed_secret[0]);
+    assert_eq!(first, 1);
+    assert_eq!(format!("{:?}", secret), "Secret<[REDACTED]>");
+
+    let secret = Secret::<_, U2>::new(ZeroizedOnDrop::new(Zeroizing::new([1u8, 2, 3, 4])));
+    let (_, last) = secret
+        .clone()
+        .expose_secret(|exposed_secret| exposed_secret.get().map(|value| value[3]));
+    assert_eq!(last, Some(4));
+    assert_eq!(format!("{:?}", secret), "Secret<[REDACTED]>");
+}
+
+#[test]
+#[cfg(all(feature = "alloc", feature = "zeroize"))]
+fn test_expose_unsized_secret() {
+    let secret = Secret::<Box<[u8]>, U2>::new(vec![1, 2, 3, 4].into_boxed_slice());
+    let (secret, first) = secret.expose_unsized_secret(|exposed_secret| {
+        let exposed_bytes: &[u8] = &exposed_secret;
+        exposed_bytes[0]
+    });
+    assert_eq!(first, 1);
+    assert_eq!(secret.exposure_count(), 1);
+    let (secret, len) = secret.expose_secret(|exposed_secret| exposed_secret.len());
+    assert_eq!(len, 4);
+    assert!(secret.is_exhausted());
+
+    let secret = Secret::<Box<str>, U2>::new("hunter2".into());
+    let (_, upper) = secret.expose_unsized_secret(|exposed_secret| exposed_secret.to_uppercase());
+    assert_eq!(upper, "HUNTER2");
+}
+
+#[test]
+#[cfg(feature = "envelope")]
+fn test_envelope() {
+    use sosecrets_rs::envelope::{EnvelopeError, EnvelopeKey};
+
+    let raw_key: [u8; 32] = core::array::from_fn(|index| index as u8);
+    let key = EnvelopeKey::raw(&raw_key);
+
+    let golden: &[u8] = include_bytes!("golden/envelope_v1_raw_key.bin");
+    let opened = Secret::<Vec<u8>, U2>::open_from_reader(&key, golden).unwrap();
+    assert_eq!(opened.exposure_count(), 0);
+
+    let mut file = Vec::new();
+    let (opened, result) = opened.seal_to_writer(&key, "db-password", &mut file);
+    result.unwrap();
+    assert_eq!(opened.exposure_count(), 1);
+    // The nonce is random, so the envelope differs from the golden file, but holds the same secret.
+    assert_ne!(file, golden);
+    let reopened = Secret::<Vec<u8>, U2>::open_from_reader(&key, file.as_slice()).unwrap();
+    let (_, is_same) = reopened.expose_secret(|exposed_secret| &*exposed_secret == b"hunter2");
+    assert!(is_same);
+
+    let wrong_key = [0; 32];
+    assert!(matches!(
+        Secret::<Vec<u8>, U2>::open_from_reader(&EnvelopeKey::raw(&wrong_key), golden),
+        Err(EnvelopeError::Authentication)
+    ));
+}
+
+#[test]
+#[cfg(feature = "rand-core")]
+fn test_generate() {
+    use rand_chacha::{rand_core::SeedableRng, ChaCha20Rng};
+
+    // The first bytes of the ChaCha20 keystream under the all-zero key and nonce (RFC 8439, A.1).
+    let mut rng = ChaCha20Rng::from_seed([0; 32]);
+    let secret = Secret::<[u8; 8], U2>::generate_with(&mut rng);
+    assert_eq!(secret.exposure_count(), 0);
+    let (secret, bytes) = secret.expose_secret(|exposed_secret| *exposed_secret);
+    assert_eq!(bytes, [0x76, 0xb8, 0xe0, 0xad, 0xa0, 0xf1, 0x3d, 0x90]);
+    assert_eq!(secret.exposure_count(), 1);
+
+    // The same seed generates the same secret, and the next bytes differ.
+    let mut rng = ChaCha20Rng::from_seed([0; 32]);
+    let first = Secret::<[u8; 8], U2>::try_generate_with(&mut rng).unwrap();
+    let second = Secret::<[u8; 8], U2>::try_generate_with(&mut rng).unwrap();
+    let (_, first) = first.expose_secret(|exposed_secret| *exposed_secret);
+    let (_, second) = second.expose_secret(|exposed_secret| *exposed_secret);
+    assert_eq!(first, bytes);
+    assert_ne!(first, second);
+}
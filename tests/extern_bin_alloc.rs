@@ -0,0 +1,150 @@
+#![cfg(all(feature = "alloc", feature = "zeroize"))]
+
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    sync::atomic::{AtomicBool, Ordering},
+};
+
+use sosecrets_rs::{prelude::*, runtime::traits::RTExposeSecret, traits::ExposeSecret};
+use typenum::consts::{U2, U3};
+
+/// A marker that no other test allocates, looked for in every freed buffer.
+const MARKER: &str = "sosecrets-rs marker 5a8f0c";
+
+static FREED_MARKER: AtomicBool = AtomicBool::new(false);
+
+/// Records whether a buffer containing `MARKER` is freed.
+struct MarkerDetectingAllocator;
+
+unsafe impl GlobalAlloc for MarkerDetectingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        let freed = std::slice::from_raw_parts(ptr, layout.size());
+        if freed
+            .windows(MARKER.len())
+            .any(|window| window == MARKER.as_bytes())
+        {
+            FREED_MARKER.store(true, Ordering::SeqCst);
+        }
+        System.dealloc(ptr, layout)
+    }
+}
+
+#[global_allocator]
+static ALLOCATOR: MarkerDetectingAllocator = MarkerDetectingAllocator;
+
+#[test]
+fn test_growth_does_not_leave_copies_in_freed_memory() {
+    // `Vec<u8>` leaves the secret in the buffers it frees when it grows.
+    FREED_MARKER.store(false, Ordering::SeqCst);
+    let mut bytes = Vec::with_capacity(MARKER.len());
+    bytes.extend_from_slice(MARKER.as_bytes());
+    bytes.extend_from_slice(&[0; 64]);
+    assert!(FREED_MARKER.load(Ordering::SeqCst));
+    drop(bytes);
+
+    FREED_MARKER.store(false, Ordering::SeqCst);
+    let mut bytes = SecretBytes::with_capacity(MARKER.len());
+    bytes.extend_from_slice(MARKER.as_bytes());
+    let capacity = bytes.capacity();
+    bytes.extend_from_slice(&[0; 64]);
+    for byte in 0..=255 {
+        bytes.push(byte);
+    }
+    assert!(bytes.capacity() > capacity);
+    assert_eq!(&bytes.as_slice()[..MARKER.len()], MARKER.as_bytes());
+    assert_eq!(bytes.len(), MARKER.len() + 64 + 256);
+    let secret = RTSecret::<_, U2>::new(bytes);
+    assert!(secret
+        .expose_secret(|exposed_secret| exposed_secret.as_slice().starts_with(MARKER.as_bytes())));
+    drop(secret);
+    assert!(!FREED_MARKER.load(Ordering::SeqCst));
+
+    let mut string = SecretString::new();
+    string.push_str(MARKER);
+    for _ in 0..64 {
+        string.push_str("more text");
+    }
+    string.push('!');
+    assert!(string.as_str().starts_with(MARKER));
+    let secret = SecrecySecret::new(string.clone());
+    string.clear();
+    assert!(string.is_empty());
+    assert!(secret.expose_secret(|exposed_secret| exposed_secret.as_str().contains(MARKER)));
+    drop(secret);
+    drop(string);
+
+    let string = SecretString::from(MARKER);
+    let (_, len) =
+        Secret::<_, U3>::new(string).expose_secret(|exposed_secret| exposed_secret.len());
+    assert_eq!(len, MARKER.len());
+    assert!(!FREED_MARKER.load(Ordering::SeqCst));
+
+    // The whole allocation of an unsized secret owned by a `Box` is zeroized.
+    let secret = Secret::<Box<[u8]>, U2>::new(MARKER.as_bytes().into());
+    let (secret, len) = secret.expose_unsized_secret(|exposed_secret| exposed_secret.len());
+    assert_eq!(len, MARKER.len());
+    drop(secret);
+    let secret = RTSecret::<Box<str>, U2>::new(MARKER.into());
+    assert!(secret.expose_unsized_secret(|exposed_secret| exposed_secret.starts_with(MARKER)));
+    drop(secret);
+    assert!(!FREED_MARKER.load(Ordering::SeqCst));
+}
+
+#[test]
+fn test_secret_string_and_bytes() {
+    let string = SecretString::from("hunter2".to_owned());
+    assert_eq!(string.len(), 7);
+    assert_eq!(string.as_bytes(), b"hunter2");
+    assert_eq!(format!("{:?}", string), "SecretString([REDACTED])");
+
+    let bytes = SecretBytes::from(&b"hunter2"[..]);
+    assert_eq!(bytes.as_slice(), b"hunter2");
+    assert_eq!(format!("{:?}", bytes), "SecretBytes([REDACTED])");
+
+    let empty = SecretString::new();
+    assert!(empty.is_empty());
+    assert_eq!(empty.capacity(), 0);
+    assert!(SecretBytes::default().is_empty());
+}
+
+#[test]
+#[cfg(feature = "debug-secret")]
+fn test_secret_string_debug_secret() {
+    use sosecrets_rs::traits::DebugSecretMetadata;
+
+    let secret = RTSecret::<_, U2>::new(SecretString::from("hunter2"));
+    assert_eq!(format!("{:?}", secret), "RTSecret<[REDACTED]>");
+    assert_eq!(
+        format!(
+            "{:?}",
+            secret.debug_with_metadata(DebugSecretMetadata::NONE.with_byte_len())
+        ),
+        "RTSecret<[REDACTED]> { byte_len: 7 }"
+    );
+}
+
+#[test]
+#[cfg(feature = "cloneable-secret")]
+fn test_secret_bytes_cloneable_secret() {
+    let secret = Secret::<_, U2>::new(SecretBytes::from(vec![1, 2, 3]));
+    let (_, len) = secret
+        .clone()
+        .expose_secret(|exposed_secret| exposed_secret.len());
+    assert_eq!(len, 3);
+}
+
+#[test]
+#[cfg(feature = "serde")]
+fn test_secret_string_serde() {
+    let secret: RTSecret<SecretString, U2> = serde_json::from_str("\"hunter2\"").unwrap();
+    assert!(secret.expose_secret(|exposed_secret| exposed_secret.as_str() == "hunter2"));
+    let secret: RTSecret<SecretBytes, U2> = serde_json::from_str("[1, 2, 3]").unwrap();
+    assert_eq!(
+        secret.expose_secret(|exposed_secret| exposed_secret.as_slice().to_vec()),
+        vec![1, 2, 3]
+    );
+}
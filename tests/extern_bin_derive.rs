@@ -0,0 +1,203 @@
+#![cfg(feature = "derive")]
+
+use sosecrets_rs::prelude::*;
+#[allow(dead_code)]
+mod common;
+#[cfg(feature = "debug-secret")]
+use core::fmt::Write;
+
+#[cfg(feature = "cloneable-secret")]
+#[test]
+fn test_derive_cloneable_secret() {
+    use sosecrets_rs::traits::{CloneableSecret, ExposeSecret};
+    use typenum::{U1, U2, U3};
+    #[cfg(feature = "zeroize")]
+    use zeroize::Zeroize;
+
+    #[derive(Clone, CloneableSecret, PartialEq, Debug)]
+    #[cfg_attr(feature = "zeroize", derive(Zeroize))]
+    struct Credentials {
+        user_id: u64,
+        api_key: [u8; 4],
+    }
+
+    #[derive(Clone, CloneableSecret)]
+    #[cfg_attr(feature = "zeroize", derive(Zeroize))]
+    struct Wrapper<T>(T);
+
+    #[derive(Clone, CloneableSecret)]
+    #[cfg_attr(feature = "zeroize", derive(Zeroize))]
+    enum Key {
+        Short(u32),
+        Long { high: u64, low: u64 },
+    }
+
+    fn assert_cloneable_secret<T: CloneableSecret>() {}
+    assert_cloneable_secret::<Credentials>();
+    assert_cloneable_secret::<Wrapper<Credentials>>();
+    assert_cloneable_secret::<Key>();
+
+    let credentials = Credentials {
+        user_id: 69,
+        api_key: [1, 2, 3, 4],
+    };
+    let secret = Secret::<_, U3>::new(Wrapper(credentials));
+    let (secret_one, secret_two) = secret.split::<U1, U2>();
+    let (_, returned_value) = secret_one.expose_secret(|exposed_secret| exposed_secret.0.user_id);
+    assert_eq!(returned_value, 69);
+    let (_, returned_value) = secret_two.expose_secret(|exposed_secret| exposed_secret.0.api_key);
+    assert_eq!(returned_value, [1, 2, 3, 4]);
+
+    let key_value = |key: &Key| match *key {
+        Key::Short(key) => u64::from(key),
+        Key::Long { high, low } => high + low,
+    };
+    let secret = Secret::<_, U1>::new(Key::Long { high: 1, low: 2 });
+    let (_, returned_value) = secret
+        .clone()
+        .expose_secret(|exposed_secret| key_value(&exposed_secret));
+    assert_eq!(returned_value, 3);
+    let secret = Secret::<_, U1>::new(Key::Short(69));
+    let (_, returned_value) = secret.expose_secret(|exposed_secret| key_value(&exposed_secret));
+    assert_eq!(returned_value, 69);
+}
+
+#[cfg(feature = "debug-secret")]
+#[test]
+fn test_derive_debug_secret() {
+    use sosecrets_rs::traits::DebugSecret;
+    #[cfg(feature = "zeroize")]
+    use zeroize::Zeroize;
+
+    #[derive(DebugSecret)]
+    #[cfg_attr(feature = "zeroize", derive(Zeroize))]
+    struct Credentials {
+        #[secret(show)]
+        user_id: u64,
+        #[secret(redact)]
+        api_key: [u8; 4],
+        pin: u16,
+    }
+
+    #[derive(DebugSecret)]
+    #[cfg_attr(feature = "zeroize", derive(Zeroize))]
+    struct Pair<T>(#[secret(show)] T, T);
+
+    #[derive(DebugSecret)]
+    #[cfg_attr(feature = "zeroize", derive(Zeroize))]
+    enum Key {
+        Short(#[secret(show)] u32),
+        Long { high: u64, low: u64 },
+    }
+
+    fn assert_debug_secret<T: DebugSecret>() {}
+    assert_debug_secret::<Credentials>();
+    assert_debug_secret::<Pair<u8>>();
+    assert_debug_secret::<Key>();
+
+    let credentials = Credentials {
+        user_id: 69,
+        api_key: [1, 2, 3, 4],
+        pin: 1234,
+    };
+    let mut comparator = common::Comparator::new(
+        "Credentials { user_id: 69, api_key: [REDACTED], pin: [REDACTED] }",
+    );
+    write!(&mut comparator, "{:?}", credentials).unwrap();
+    assert!(comparator.is_valid());
+
+    let mut comparator = common::Comparator::new("Pair(1, [REDACTED])");
+    write!(&mut comparator, "{:?}", Pair(1_u8, 2_u8)).unwrap();
+    assert!(comparator.is_valid());
+
+    let mut comparator = common::Comparator::new("Short(69)");
+    write!(&mut comparator, "{:?}", Key::Short(69)).unwrap();
+    assert!(comparator.is_valid());
+
+    let mut comparator = common::Comparator::new("Long { high: [REDACTED], low: [REDACTED] }");
+    write!(&mut comparator, "{:?}", Key::Long { high: 1, low: 2 }).unwrap();
+    assert!(comparator.is_valid());
+
+    let secret = Secret::<_, typenum::U1>::new(credentials);
+    let mut comparator = common::Comparator::new("Secret<[REDACTED]>");
+    write!(&mut comparator, "{:?}", secret).unwrap();
+    assert!(comparator.is_valid());
+}
+
+#[test]
+fn test_secret_struct() {
+    use sosecrets_rs::runtime::traits::RTExposeSecret;
+    use typenum::{U1, U3};
+
+    #[secret_struct]
+    struct Db {
+        host: &'static str,
+        #[secret(max = 3)]
+        password: [u8; 4],
+        #[secret]
+        token: u64,
+    }
+
+    #[secret_struct]
+    struct ApiKey(&'static str, #[secret(max = typenum::U1)] u32);
+
+    let db = Db {
+        host: "localhost",
+        password: RTSecret::new([1, 2, 3, 4]),
+        token: SecrecySecret::new(69),
+    };
+    let _: &RTSecret<[u8; 4], U3> = &db.password;
+    let _: &SecrecySecret<u64> = &db.token;
+    assert_eq!(
+        format!("{:?}", db),
+        "Db { host: \"localhost\", password: [REDACTED], token: [REDACTED] }"
+    );
+    assert_eq!(
+        db.password
+            .expose_secret(|exposed_secret| exposed_secret[0]),
+        1
+    );
+    assert_eq!(db.password.remaining_exposures(), 2);
+    assert_eq!(db.token.expose_secret(|exposed_secret| *exposed_secret), 69);
+
+    let api_key = ApiKey("production", RTSecret::new(69));
+    let _: &RTSecret<u32, U1> = &api_key.1;
+    assert_eq!(
+        format!("{:?}", api_key),
+        "ApiKey(\"production\", [REDACTED])"
+    );
+    assert_eq!(api_key.0, "production");
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn test_secret_struct_serde() {
+    use sosecrets_rs::runtime::traits::RTExposeSecret;
+
+    #[secret_struct]
+    #[derive(serde::Deserialize, serde::Serialize)]
+    struct Db {
+        host: String,
+        port: u16,
+        #[secret(max = 2)]
+        password: [u8; 4],
+    }
+
+    let db: Db =
+        serde_json::from_str(r#"{ "host": "localhost", "port": 5432, "password": [1, 2, 3, 4] }"#)
+            .unwrap();
+    assert_eq!(db.host, "localhost");
+    assert_eq!(db.port, 5432);
+    assert_eq!(db.password.exposure_count(), 0);
+    assert_eq!(
+        db.password.expose_secret(|exposed_secret| *exposed_secret),
+        [1, 2, 3, 4]
+    );
+
+    assert_eq!(
+        serde_json::to_string(&db).unwrap(),
+        r#"{"host":"localhost","port":5432,"password":"[REDACTED]"}"#
+    );
+    // Serializing does not expose the secret.
+    assert_eq!(db.password.exposure_count(), 1);
+}
@@ -1,3 +1,5 @@
+#![allow(clippy::let_and_return, clippy::let_unit_value)]
+
 use sosecrets_rs::{
     prelude::typenum::{U0, U1, U2},
     runtime::{traits::RTExposeSecret, RTExposedSecret, RTSecret, SecrecySecret},
@@ -728,3 +730,1282 @@ fn test_exposed_fully_but_dropped() {
 
     assert_eq!(NUM_DROPS.load(Ordering::Relaxed), 1usize);
 }
+
+#[cfg(feature = "cloneable-secret")]
+#[test]
+fn test_split_exposure_budget() {
+    use sosecrets_rs::{
+        prelude::typenum::{U3, U5},
+        runtime::error::ExposeSecretError,
+    };
+
+    let secret = RTSecret::<i32, U5>::new(69);
+    let _ = secret.expose_secret(|exposed_secret| *exposed_secret);
+
+    let (secret_one, secret_two) = secret.split::<U1, U2>().unwrap();
+    assert_eq!(secret.exposure_count(), 4_u8);
+    assert_eq!(secret_one.exposure_count(), 0_u8);
+    assert_eq!(secret_two.exposure_count(), 0_u8);
+
+    assert_eq!(secret_one.expose_secret(|exposed_secret| *exposed_secret), 69);
+    assert!(secret_one.try_expose_secret(|_| {}).is_err());
+
+    assert_eq!(secret_two.expose_secret(|exposed_secret| *exposed_secret), 69);
+    assert_eq!(secret_two.expose_secret(|exposed_secret| *exposed_secret), 69);
+    assert!(secret_two.try_expose_secret(|_| {}).is_err());
+
+    let result = secret.split::<U1, U1>();
+    assert!(matches!(
+        result,
+        Err(ExposeSecretError::InsufficientExposureBudget(ref err))
+            if err.mec == 5 && err.ec == 4 && err.requested == 2
+    ));
+    assert_eq!(secret.exposure_count(), 4_u8);
+
+    let result = secret.split::<U3, U5>();
+    assert!(result.is_err());
+    assert_eq!(secret.exposure_count(), 4_u8);
+
+    let (secret_three, _) = secret.split::<U1, U0>().unwrap();
+    assert_eq!(secret.exposure_count(), 5_u8);
+    assert_eq!(secret_three.expose_secret(|exposed_secret| *exposed_secret), 69);
+}
+
+#[test]
+fn test_exposure_budget_introspection() {
+    let secret = RTSecret::<i32, U2>::new(69);
+    assert_eq!(RTSecret::<i32, U2>::MAX_EXPOSURES, 2);
+    assert_eq!(secret.max_exposures(), 2);
+    assert_eq!(secret.remaining_exposures(), 2);
+    assert!(!secret.is_exhausted());
+
+    let _ = secret.expose_secret(|exposed_secret| *exposed_secret);
+    assert_eq!(secret.exposure_count(), 1_u8);
+    assert_eq!(secret.remaining_exposures(), 1);
+    assert!(!secret.is_exhausted());
+
+    let _ = secret.expose_secret(|exposed_secret| *exposed_secret);
+    assert_eq!(secret.remaining_exposures(), 0);
+    assert!(secret.is_exhausted());
+
+    let secrecy_secret = SecrecySecret::<i32>::new(69);
+    let _ = secrecy_secret.expose_secret(|exposed_secret| *exposed_secret);
+    assert_eq!(SecrecySecret::<i32>::MAX_EXPOSURES, usize::MAX);
+    assert_eq!(secrecy_secret.remaining_exposures(), usize::MAX);
+    assert!(!secrecy_secret.is_exhausted());
+}
+
+#[test]
+#[cfg(feature = "debug-secret")]
+fn test_debug_secret_alternate_hides_exposure_budget() {
+    use core::fmt::Write;
+    use sosecrets_rs::{
+        prelude::typenum::U5,
+        traits::{DebugSecret, DebugSecretMetadata},
+    };
+    #[cfg(feature = "zeroize")]
+    use zeroize::Zeroize;
+
+    #[derive(Debug, Clone)]
+    struct A {
+        _inner: i32,
+    }
+
+    #[cfg(feature = "zeroize")]
+    impl Zeroize for A {
+        fn zeroize(&mut self) {
+            self._inner.zeroize()
+        }
+    }
+
+    impl DebugSecret for A {}
+
+    let new_secret: RTSecret<A, U5> = RTSecret::new(A { _inner: 69 });
+    let _ = new_secret.expose_secret(|_| {});
+
+    let mut cmp = common::Comparator::new("RTSecret<[REDACTED]>");
+    let _ = write!(&mut cmp, "{:#?}", new_secret);
+    assert!(cmp.is_valid());
+
+    let mut cmp = common::Comparator::new("RTSecret<[REDACTED]> { remaining_exposures: 4 }");
+    let _ = write!(
+        &mut cmp,
+        "{:#?}",
+        new_secret.debug_with_metadata(DebugSecretMetadata::NONE.with_remaining_exposures())
+    );
+    assert!(cmp.is_valid());
+
+    let mut cmp = common::Comparator::new("RTSecret<[REDACTED]>");
+    let _ = write!(&mut cmp, "{:?}", new_secret);
+    assert!(cmp.is_valid());
+}
+
+#[cfg(feature = "zeroize")]
+#[test]
+fn test_zeroize_on_exhaustion() {
+    use core::sync::atomic::{AtomicUsize, Ordering};
+    use sosecrets_rs::{
+        prelude::{typenum::U3, ZeroizeOnExhaustion},
+        runtime::error::ExposeSecretError,
+    };
+    use zeroize::Zeroize;
+
+    static NUM_ZEROIZES: AtomicUsize = AtomicUsize::new(0);
+
+    struct DetectZeroize(i32);
+
+    impl Zeroize for DetectZeroize {
+        fn zeroize(&mut self) {
+            self.0.zeroize();
+            NUM_ZEROIZES.fetch_add(1, Ordering::Relaxed);
+        }
+    }
+
+    {
+        let secret = RTSecret::<DetectZeroize, U2, ZeroizeOnExhaustion>::new(DetectZeroize(69));
+
+        assert_eq!(secret.expose_secret(|exposed_secret| exposed_secret.0), 69);
+        assert_eq!(NUM_ZEROIZES.load(Ordering::Relaxed), 0usize);
+
+        assert_eq!(secret.expose_secret(|exposed_secret| exposed_secret.0), 69);
+        assert_eq!(NUM_ZEROIZES.load(Ordering::Relaxed), 1usize);
+        assert!(secret.is_exhausted());
+
+        let result = secret.try_expose_secret(|exposed_secret| exposed_secret.0);
+        assert!(matches!(
+            result,
+            Err(ExposeSecretError::ZeroizedOnExhaustion(ref err)) if err.mec == 2 && err.ec == 2
+        ));
+    }
+    // `Drop` still zeroizes the already zeroized value.
+    assert_eq!(NUM_ZEROIZES.load(Ordering::Relaxed), 2usize);
+
+    {
+        let secret = RTSecret::<DetectZeroize, U3, ZeroizeOnExhaustion>::new(DetectZeroize(69));
+        let _ = secret.expose_secret(|_| {});
+
+        // The last allowed exposure happens within another exposure, the secret value must outlive the outer closure.
+        let returned_value = secret.expose_secret(|outer_exposed_secret| {
+            let inner_value = secret.expose_secret(|exposed_secret| exposed_secret.0);
+            assert_eq!(NUM_ZEROIZES.load(Ordering::Relaxed), 2usize);
+            inner_value + outer_exposed_secret.0
+        });
+        assert_eq!(returned_value, 138);
+        assert_eq!(NUM_ZEROIZES.load(Ordering::Relaxed), 3usize);
+    }
+    assert_eq!(NUM_ZEROIZES.load(Ordering::Relaxed), 4usize);
+}
+
+#[cfg(feature = "zeroize")]
+#[test]
+fn test_zeroize_on_exhaustion_when_closure_panics() {
+    use core::cell::Cell;
+    use sosecrets_rs::prelude::ZeroizingRTSecret;
+    use std::panic::{catch_unwind, AssertUnwindSafe};
+    use zeroize::Zeroize;
+
+    thread_local! {
+        static ZEROIZED: Cell<bool> = const { Cell::new(false) };
+    }
+
+    struct DetectZeroize;
+
+    impl Zeroize for DetectZeroize {
+        fn zeroize(&mut self) {
+            ZEROIZED.with(|zeroized| zeroized.set(true));
+        }
+    }
+
+    let secret = ZeroizingRTSecret::<DetectZeroize, U1>::new(DetectZeroize);
+
+    let result = catch_unwind(AssertUnwindSafe(|| {
+        secret.expose_secret(|_| panic!("panicked while the secret is exposed"));
+    }));
+    assert!(result.is_err());
+    assert!(ZEROIZED.with(|zeroized| zeroized.get()));
+}
+
+#[cfg(feature = "zeroize")]
+#[test]
+#[should_panic = "`RTSecret` has been zeroized after being exposed for 1 times, the maximum number it is allowed to be exposed for is 1 times."]
+fn test_zeroize_on_exhaustion_should_panic() {
+    use sosecrets_rs::prelude::ZeroizingRTSecret;
+
+    let secret = ZeroizingRTSecret::<[u8; 4], U1>::new([1, 2, 3, 4]);
+
+    let _ = secret.expose_secret(|exposed_secret| exposed_secret[0]);
+    let _ = secret.expose_secret(|exposed_secret| exposed_secret[0]);
+}
+
+#[cfg(all(feature = "zeroize", feature = "cloneable-secret"))]
+#[test]
+fn test_zeroize_on_exhaustion_after_split() {
+    use sosecrets_rs::prelude::ZeroizingRTSecret;
+
+    let secret = ZeroizingRTSecret::<[u8; 4], U2>::new([1, 2, 3, 4]);
+
+    let (secret_one, secret_two) = secret.split::<U1, U1>().unwrap();
+    assert!(secret.is_exhausted());
+    assert!(secret.try_expose_secret(|exposed_secret| exposed_secret[0]).is_err());
+
+    assert_eq!(secret_one.expose_secret(|exposed_secret| exposed_secret[0]), 1);
+    assert!(secret_one.try_expose_secret(|exposed_secret| exposed_secret[0]).is_err());
+    assert_eq!(secret_two.expose_secret(|exposed_secret| exposed_secret[3]), 4);
+}
+
+#[test]
+fn test_expose_secret_error_carries_label_and_location() {
+    let secret = RTSecret::<isize, U1>::new(69);
+    let _ = secret.expose_secret(|exposed_secret| *exposed_secret);
+
+    let line = line!() + 1;
+    let err = secret.try_expose_secret(|exposed_secret| *exposed_secret);
+    let err = err.unwrap_err();
+    assert_eq!(err.label(), None);
+    assert_eq!(err.location().file(), file!());
+    assert_eq!(err.location().line(), line);
+    assert_eq!(
+        err.to_string(),
+        format!("`Secret` is exposed more than what it is maximally allowed to; it is exposed for 1 times and it is only allowed to be exposed for 1 times at {}", err.location())
+    );
+
+    let err = err.with_label("db_password");
+    assert_eq!(err.label(), Some("db_password"));
+    assert_eq!(
+        err.to_string(),
+        format!("`Secret` is exposed more than what it is maximally allowed to; it is exposed for 1 times and it is only allowed to be exposed for 1 times (label: `db_password`) at {}", err.location())
+    );
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_expose_secret_error_is_std_error() {
+    fn expose_twice(secret: &RTSecret<isize, U1>) -> Result<isize, Box<dyn std::error::Error>> {
+        let first = secret.try_expose_secret(|exposed_secret| *exposed_secret)?;
+        let second = secret
+            .try_expose_secret(|exposed_secret| *exposed_secret)
+            .map_err(|err| err.with_label("db_password"))?;
+        Ok(first + second)
+    }
+
+    let secret = RTSecret::<isize, U1>::new(69);
+    let err = expose_twice(&secret).unwrap_err();
+    assert!(err.to_string().contains("(label: `db_password`)"));
+    // The message is not repeated by the error chain.
+    assert!(err.source().is_none());
+}
+
+#[test]
+fn test_labelled_rt_secret() {
+    let secret = RTSecret::<isize, U1>::new(69).with_label("db_password");
+    assert_eq!(secret.label(), Some("db_password"));
+    let _ = secret.expose_secret(|exposed_secret| *exposed_secret);
+
+    let err = secret
+        .try_expose_secret(|exposed_secret| *exposed_secret)
+        .unwrap_err();
+    assert_eq!(err.label(), Some("db_password"));
+    assert!(err.to_string().contains("(label: `db_password`)"));
+    assert_eq!(err.with_label("other").label(), Some("other"));
+
+    let secrecy_secret = SecrecySecret::new(69).with_label("db_password");
+    assert_eq!(secrecy_secret.label(), None);
+
+    #[cfg(feature = "cloneable-secret")]
+    {
+        let secret = RTSecret::<isize, U2>::new(69).with_label("db_password");
+        let (secret_one, secret_two) = secret.split::<U1, U1>().unwrap();
+        assert_eq!(secret_one.label(), Some("db_password"));
+        assert_eq!(secret_two.label(), Some("db_password"));
+    }
+}
+
+#[test]
+#[should_panic = "`RTSecret` (label: `db_password`) has already been exposed for 1 times, the maximum number it is allowed to be exposed for is 1 times."]
+fn test_labelled_rt_secret_panics_with_label() {
+    let secret = RTSecret::<isize, U1>::new(69).with_label("db_password");
+    let _ = secret.expose_secret(|exposed_secret| *exposed_secret);
+    let _ = secret.expose_secret(|exposed_secret| *exposed_secret);
+}
+
+#[test]
+fn test_non_panicking_rt_secret() {
+    use sosecrets_rs::runtime::NonPanickingRTSecret;
+
+    let secret = NonPanickingRTSecret::<isize, U2>::new(69);
+    assert_eq!(secret.max_exposures(), 2);
+
+    assert_eq!(
+        secret
+            .try_expose_secret(|exposed_secret| *exposed_secret)
+            .unwrap(),
+        69
+    );
+    assert_eq!(
+        secret
+            .try_expose_secret(|exposed_secret| *exposed_secret)
+            .unwrap(),
+        69
+    );
+    assert!(secret.is_exhausted());
+
+    let line = line!() + 1;
+    let err = secret.try_expose_secret(|exposed_secret| *exposed_secret);
+    let err = err.unwrap_err();
+    assert_eq!(err.location().file(), file!());
+    assert_eq!(err.location().line(), line);
+    assert_eq!(secret.exposure_count(), 2);
+
+    let secret: NonPanickingRTSecret<isize, U1> = RTSecret::<isize, U1>::new(69).into();
+    assert_eq!(
+        secret
+            .try_expose_secret(|exposed_secret| *exposed_secret)
+            .unwrap(),
+        69
+    );
+    assert!(secret.try_expose_secret(|exposed_secret| *exposed_secret).is_err());
+}
+
+#[test]
+#[cfg(feature = "debug-secret")]
+fn test_debug_secret_metadata() {
+    use core::fmt::Write;
+    use sosecrets_rs::{
+        prelude::typenum::U3, runtime::NonPanickingRTSecret, traits::DebugSecretMetadata,
+    };
+
+    let new_secret: RTSecret<[u8; 4], U3> = RTSecret::new([1, 2, 3, 4]);
+    let _ = new_secret.expose_secret(|_| {});
+
+    let mut cmp = common::Comparator::new("RTSecret<[REDACTED]>");
+    let _ = write!(&mut cmp, "{:?}", new_secret);
+    assert!(cmp.is_valid());
+
+    let mut cmp = common::Comparator::new(
+        "RTSecret<[REDACTED]> { type_name: \"[u8; 4]\", byte_len: 4, remaining_exposures: 2 }",
+    );
+    let _ = write!(
+        &mut cmp,
+        "{:?}",
+        new_secret.debug_with_metadata(DebugSecretMetadata::ALL)
+    );
+    assert!(cmp.is_valid());
+
+    let mut cmp = common::Comparator::new("RTSecret<[REDACTED]> { byte_len: 4 }");
+    let _ = write!(
+        &mut cmp,
+        "{:#?}",
+        new_secret.debug_with_metadata(DebugSecretMetadata::NONE.with_byte_len())
+    );
+    assert!(cmp.is_valid());
+
+    // Formatting does not expose the secret.
+    assert_eq!(new_secret.exposure_count(), 1);
+
+    let non_panicking_secret = NonPanickingRTSecret::from(new_secret);
+    let mut cmp = common::Comparator::new("RTSecret<[REDACTED]> { remaining_exposures: 2 }");
+    let _ = write!(
+        &mut cmp,
+        "{:?}",
+        non_panicking_secret
+            .debug_with_metadata(DebugSecretMetadata::NONE.with_remaining_exposures())
+    );
+    assert!(cmp.is_valid());
+}
+
+#[test]
+#[cfg(feature = "display-secret")]
+fn test_display_secret() {
+    use core::fmt::Write;
+    use sosecrets_rs::{
+        prelude::typenum::U2, runtime::NonPanickingRTSecret, traits::DisplaySecret,
+    };
+    #[cfg(feature = "zeroize")]
+    use zeroize::Zeroize;
+
+    struct ApiKey(&'static str);
+
+    #[cfg(feature = "zeroize")]
+    impl Zeroize for ApiKey {
+        fn zeroize(&mut self) {
+            self.0 = "";
+        }
+    }
+
+    impl DisplaySecret for ApiKey {
+        const SHOWN_PREFIX_CHARS: usize = 8;
+        const SHOWN_SUFFIX_CHARS: usize = 4;
+
+        fn display_secret_str(&self) -> &str {
+            self.0
+        }
+    }
+
+    struct Pin(&'static str);
+
+    #[cfg(feature = "zeroize")]
+    impl Zeroize for Pin {
+        fn zeroize(&mut self) {
+            self.0 = "";
+        }
+    }
+
+    impl DisplaySecret for Pin {
+        fn display_secret_str(&self) -> &str {
+            self.0
+        }
+    }
+
+    // Showing the edges counts as an exposure.
+    let new_secret: RTSecret<ApiKey, U2> = RTSecret::new(ApiKey("sk_live_51HxYzabcd"));
+    let mut cmp = common::Comparator::new("sk_live_****abcd");
+    let _ = write!(&mut cmp, "{}", new_secret);
+    assert!(cmp.is_valid());
+    assert_eq!(new_secret.exposure_count(), 1);
+
+    let _ = new_secret.expose_secret(|_| {});
+    assert!(new_secret.is_exhausted());
+
+    // Once exhausted, the secret is fully masked and does not panic.
+    let mut cmp = common::Comparator::new("****");
+    let _ = write!(&mut cmp, "{}", new_secret);
+    assert!(cmp.is_valid());
+    assert_eq!(new_secret.exposure_count(), 2);
+
+    // Without shown edges, the secret is not read and no exposure is counted.
+    let new_secret: RTSecret<Pin, U2> = RTSecret::new(Pin("1234"));
+    let mut cmp = common::Comparator::new("****");
+    let _ = write!(&mut cmp, "{}", new_secret);
+    assert!(cmp.is_valid());
+    assert_eq!(new_secret.exposure_count(), 0);
+
+    let non_panicking_secret =
+        NonPanickingRTSecret::<ApiKey, U2>::new(ApiKey("sk_live_51HxYzabcd"));
+    let mut cmp = common::Comparator::new("sk_live_****abcd");
+    let _ = write!(&mut cmp, "{}", non_panicking_secret);
+    assert!(cmp.is_valid());
+    assert_eq!(non_panicking_secret.remaining_exposures(), 1);
+}
+
+#[test]
+#[cfg(all(feature = "display-secret", feature = "zeroize"))]
+fn test_display_secret_zeroize_on_exhaustion() {
+    use sosecrets_rs::{
+        prelude::{typenum::U1, ZeroizeOnExhaustion},
+        traits::DisplaySecret,
+    };
+    use zeroize::Zeroize;
+
+    struct ApiKey([u8; 12]);
+
+    impl Zeroize for ApiKey {
+        fn zeroize(&mut self) {
+            self.0.zeroize()
+        }
+    }
+
+    impl DisplaySecret for ApiKey {
+        const SHOWN_SUFFIX_CHARS: usize = 4;
+
+        fn display_secret_str(&self) -> &str {
+            core::str::from_utf8(&self.0).unwrap()
+        }
+    }
+
+    let new_secret: RTSecret<ApiKey, U1, ZeroizeOnExhaustion> =
+        RTSecret::new(ApiKey(*b"sk_test_abcd"));
+    assert_eq!(format!("{}", new_secret), "****abcd");
+    assert!(new_secret.is_exhausted());
+    assert_eq!(format!("{}", new_secret), "****");
+}
+
+#[test]
+#[cfg(feature = "fingerprint")]
+fn test_fingerprint() {
+    use sosecrets_rs::{
+        fingerprint::FingerprintKey,
+        prelude::typenum::U2,
+        runtime::{error::ExposeSecretError, NonPanickingRTSecret},
+    };
+
+    let key = FingerprintKey::new(b"fingerprint key", b"production");
+
+    let new_secret: RTSecret<[u8; 12], U2> = RTSecret::new(*b"sk_live_abcd");
+    let fingerprint = new_secret.fingerprint(&key);
+    assert_eq!(fingerprint.to_string(), "74952a968620c9aa");
+    assert_eq!(new_secret.exposure_count(), 1);
+    assert_eq!(new_secret.try_fingerprint(&key).unwrap(), fingerprint);
+    assert!(new_secret.is_exhausted());
+    assert!(matches!(
+        new_secret.try_fingerprint(&key),
+        Err(ExposeSecretError::ExposeMoreThanMaximallyAllow(_))
+    ));
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        new_secret.fingerprint(&key)
+    }));
+    assert!(result.is_err());
+
+    let secrecy_secret = SecrecySecret::new(*b"sk_live_abcd");
+    assert_eq!(secrecy_secret.fingerprint(&key), fingerprint);
+    assert_eq!(secrecy_secret.fingerprint(&key), fingerprint);
+
+    let non_panicking_secret = NonPanickingRTSecret::<[u8; 12], U2>::new(*b"sk_live_abcd");
+    assert_eq!(
+        non_panicking_secret.try_fingerprint(&key).unwrap(),
+        fingerprint
+    );
+    assert_eq!(non_panicking_secret.exposure_count(), 1);
+}
+
+#[test]
+fn test_expose_secret_mut() {
+    use sosecrets_rs::{
+        containers::SecretArray,
+        prelude::typenum::U2,
+        runtime::{error::ExposeSecretError, NonPanickingRTSecret},
+    };
+
+    let mut new_secret = RTSecret::<SecretArray<16>, U2>::new(SecretArray::new());
+    new_secret
+        .expose_secret_mut(|mut exposed_secret| exposed_secret.try_push_str("hunter"))
+        .unwrap();
+    assert_eq!(new_secret.exposure_count(), 1);
+    new_secret
+        .try_expose_secret_mut(|mut exposed_secret| exposed_secret.try_push(b'2'))
+        .unwrap()
+        .unwrap();
+    assert!(new_secret.is_exhausted());
+    assert!(matches!(
+        new_secret.try_expose_secret_mut(|mut exposed_secret| exposed_secret.clear()),
+        Err(ExposeSecretError::ExposeMoreThanMaximallyAllow(_))
+    ));
+    assert!(new_secret
+        .try_expose_secret(|exposed_secret| exposed_secret.len())
+        .is_err());
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        new_secret.expose_secret_mut(|mut exposed_secret| exposed_secret.clear())
+    }));
+    assert!(result.is_err());
+
+    let mut secrecy_secret = SecrecySecret::new(SecretArray::<16>::new());
+    for _ in 0..3 {
+        secrecy_secret
+            .expose_secret_mut(|mut exposed_secret| exposed_secret.try_push_str("ab"))
+            .unwrap();
+    }
+    assert!(
+        secrecy_secret.expose_secret(|exposed_secret| exposed_secret.as_str() == Some("ababab"))
+    );
+
+    let mut non_panicking_secret = NonPanickingRTSecret::<SecretArray<4>, U2>::new(
+        SecretArray::from_slice(&[1, 2, 3, 4]).unwrap(),
+    );
+    non_panicking_secret
+        .try_expose_secret_mut(|mut exposed_secret| exposed_secret.as_mut_slice()[0] = 69)
+        .unwrap();
+    assert_eq!(
+        non_panicking_secret
+            .try_expose_secret(|exposed_secret| exposed_secret.as_slice()[0])
+            .unwrap(),
+        69
+    );
+}
+
+#[cfg(feature = "zeroize")]
+#[test]
+fn test_expose_secret_mut_zeroize_on_exhaustion() {
+    use sosecrets_rs::{
+        containers::SecretArray,
+        prelude::{typenum::U1, ZeroizeOnExhaustion},
+    };
+
+    let mut new_secret =
+        RTSecret::<SecretArray<16>, U1, ZeroizeOnExhaustion>::new(SecretArray::new());
+    let len = new_secret.expose_secret_mut(|mut exposed_secret| {
+        exposed_secret.try_push_str("hunter2").unwrap();
+        exposed_secret.len()
+    });
+    assert_eq!(len, 7);
+    assert!(new_secret.is_exhausted());
+    assert!(new_secret
+        .try_expose_secret(|exposed_secret| exposed_secret.len())
+        .is_err());
+}
+
+#[cfg(feature = "zeroize")]
+#[test]
+fn test_zeroize_on_drop_secret_is_zeroized_once_on_exhaustion() {
+    use core::sync::atomic::{AtomicUsize, Ordering};
+    use sosecrets_rs::{
+        containers::ZeroizedOnDrop,
+        prelude::{typenum::U2, ZeroizingRTSecret},
+    };
+    use zeroize::ZeroizeOnDrop;
+
+    static DROPS: AtomicUsize = AtomicUsize::new(0);
+
+    struct SelfZeroizing([u8; 4]);
+
+    impl Drop for SelfZeroizing {
+        fn drop(&mut self) {
+            self.0 = [0; 4];
+            DROPS.fetch_add(1, Ordering::SeqCst);
+        }
+    }
+
+    impl ZeroizeOnDrop for SelfZeroizing {}
+
+    let secret = ZeroizingRTSecret::<_, U2>::new(ZeroizedOnDrop::new(SelfZeroizing([1, 2, 3, 4])));
+    assert_eq!(
+        secret.expose_secret(|exposed_secret| exposed_secret.get().map(|value| value.0[0])),
+        Some(1)
+    );
+    assert_eq!(DROPS.load(Ordering::SeqCst), 0);
+    assert_eq!(
+        secret.expose_secret(|exposed_secret| exposed_secret.get().map(|value| value.0[1])),
+        Some(2)
+    );
+    // Zeroized by dropping the wrapped value right after the last allowed exposure.
+    assert_eq!(DROPS.load(Ordering::SeqCst), 1);
+    assert!(secret
+        .try_expose_secret(|exposed_secret| exposed_secret.is_zeroized())
+        .is_err());
+    drop(secret);
+    assert_eq!(DROPS.load(Ordering::SeqCst), 1);
+
+    let secret = SecrecySecret::new(ZeroizedOnDrop::new(SelfZeroizing([1, 2, 3, 4])));
+    assert_eq!(
+        secret.expose_secret(|exposed_secret| exposed_secret.get().map(|value| value.0[2])),
+        Some(3)
+    );
+    drop(secret);
+    assert_eq!(DROPS.load(Ordering::SeqCst), 2);
+}
+
+#[cfg(all(feature = "alloc", feature = "zeroize"))]
+#[test]
+fn test_expose_unsized_secret() {
+    use sosecrets_rs::{
+        prelude::typenum::U2,
+        runtime::{error::ExposeSecretError, NonPanickingRTSecret},
+    };
+
+    let secret = RTSecret::<Box<str>, U2>::new("hunter2".into());
+    let len = secret.expose_unsized_secret(|exposed_secret| {
+        let exposed_str: &str = &exposed_secret;
+        exposed_str.len()
+    });
+    assert_eq!(len, 7);
+    assert!(secret
+        .try_expose_unsized_secret(|exposed_secret| exposed_secret.starts_with("hunter"))
+        .unwrap());
+    assert!(matches!(
+        secret.try_expose_unsized_secret(|exposed_secret| exposed_secret.len()),
+        Err(ExposeSecretError::ExposeMoreThanMaximallyAllow(_))
+    ));
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        secret.expose_unsized_secret(|exposed_secret| exposed_secret.len())
+    }));
+    assert!(result.is_err());
+
+    let secrecy_secret = SecrecySecret::<Box<[u8]>>::new(vec![1, 2, 3].into_boxed_slice());
+    for _ in 0..3 {
+        assert_eq!(
+            secrecy_secret
+                .expose_unsized_secret(|exposed_secret| exposed_secret.iter().sum::<u8>()),
+            6
+        );
+    }
+
+    let non_panicking_secret =
+        NonPanickingRTSecret::<Box<[u8]>, U2>::new(vec![1, 2, 3].into_boxed_slice());
+    assert_eq!(
+        non_panicking_secret
+            .try_expose_unsized_secret(|exposed_secret| exposed_secret[2])
+            .unwrap(),
+        3
+    );
+}
+
+#[cfg(all(
+    feature = "memfd-secret",
+    target_os = "linux",
+    any(
+        target_arch = "x86_64",
+        target_arch = "aarch64",
+        target_arch = "riscv64"
+    )
+))]
+#[test]
+fn test_protected_bytes() {
+    use sosecrets_rs::{
+        containers::{ProtectedBytes, ProtectionMode},
+        prelude::typenum::U2,
+        runtime::NonPanickingRTSecret,
+    };
+
+    let mut secret = RTSecret::<_, U2>::new(ProtectedBytes::zeroed(7).unwrap());
+    let mode = secret.protection_mode();
+    assert!(matches!(
+        mode,
+        ProtectionMode::MemfdSecret | ProtectionMode::Mlock
+    ));
+    secret.expose_secret_mut(|mut exposed_secret| {
+        assert_eq!(exposed_secret.as_slice(), &[0; 7]);
+        exposed_secret.as_mut_slice().copy_from_slice(b"hunter2")
+    });
+    assert!(secret.expose_secret(|exposed_secret| exposed_secret.as_slice() == b"hunter2"));
+    assert!(secret.is_exhausted());
+    assert_eq!(secret.protection_mode(), mode);
+
+    let secrecy_secret = SecrecySecret::new(ProtectedBytes::from_slice(b"hunter2").unwrap());
+    assert_eq!(secrecy_secret.protection_mode(), mode);
+    assert_eq!(
+        secrecy_secret.expose_secret(|exposed_secret| exposed_secret.len()),
+        7
+    );
+
+    let non_panicking_secret =
+        NonPanickingRTSecret::<_, U2>::new(ProtectedBytes::from_slice(&[]).unwrap());
+    assert_eq!(non_panicking_secret.protection_mode(), mode);
+    assert!(non_panicking_secret
+        .try_expose_secret(|exposed_secret| exposed_secret.is_empty())
+        .unwrap());
+
+    let large = ProtectedBytes::from_slice(&[0xa5; 10_000]).unwrap();
+    assert!(large.as_slice().iter().all(|byte| *byte == 0xa5));
+    assert_eq!(format!("{:?}", large), "ProtectedBytes([REDACTED])");
+    assert!(["memfd_secret", "mlock"].contains(&format!("{}", large.mode()).as_str()));
+}
+
+#[cfg(all(feature = "keyring", target_os = "linux"))]
+#[test]
+fn test_keyring_secret() {
+    use sosecrets_rs::{
+        prelude::typenum::U2,
+        runtime::keyring::{Keyring, KeyringError, KeyringSecret},
+    };
+    use std::io::ErrorKind;
+
+    let description = "sosecrets-rs-test_keyring_secret";
+    let secret = KeyringSecret::<U2>::add(Keyring::Process, description, b"hunter2").unwrap();
+    secret.set_timeout(60).unwrap();
+    assert!(format!("{:?}", secret).contains(&secret.serial().to_string()));
+    assert!(secret.expose_secret(|exposed_secret| &*exposed_secret == b"hunter2"));
+
+    let found = KeyringSecret::<U2>::find(Keyring::Process, description).unwrap();
+    assert_eq!(found.serial(), secret.serial());
+    assert_eq!(found.exposure_count(), 0);
+
+    // Updating the key with a larger payload is seen by every `KeyringSecret` referring to it.
+    let updated = KeyringSecret::<U2>::add(Keyring::Process, description, &[7; 4096]).unwrap();
+    assert_eq!(updated.serial(), secret.serial());
+    assert_eq!(
+        secret.expose_secret(|exposed_secret| exposed_secret.len()),
+        4096
+    );
+    assert!(secret.is_exhausted());
+    let err = secret
+        .try_expose_secret(|exposed_secret| exposed_secret.len())
+        .unwrap_err();
+    assert!(matches!(err, KeyringError::Exposure(_)));
+    assert!(std::error::Error::source(&err).is_none());
+
+    updated.revoke().unwrap();
+    match found.try_expose_secret(|exposed_secret| exposed_secret.len()) {
+        Err(KeyringError::Kernel(error)) => assert_eq!(error.raw_os_error(), Some(128)),
+        _ => panic!("a revoked key must not be readable"),
+    }
+    assert!(KeyringSecret::<U2>::find(Keyring::Process, "sosecrets-rs-missing").is_err());
+    assert_eq!(
+        KeyringSecret::<U2>::add(Keyring::Process, "nul\0byte", b"")
+            .unwrap_err()
+            .kind(),
+        ErrorKind::InvalidInput
+    );
+}
+
+#[cfg(feature = "sealed")]
+#[test]
+fn test_sealed_secret() {
+    use sosecrets_rs::{
+        containers::{SecretArray, SecretBytes, SecretString},
+        prelude::typenum::{U1, U2},
+        runtime::{error::ExposeSecretError, sealed::SealedSecret},
+    };
+
+    let secret = SealedSecret::<String, U2>::new(String::from("hunter2")).unwrap();
+    assert_eq!(format!("{:?}", secret), "SealedSecret([REDACTED])");
+    assert!(secret.expose_secret(|exposed_secret| exposed_secret.as_str() == "hunter2"));
+    assert_eq!(secret.exposure_count(), 1);
+    assert!(secret
+        .try_expose_secret(|exposed_secret| exposed_secret.len() == 7)
+        .unwrap());
+    assert!(secret.is_exhausted());
+    match secret.try_expose_secret(|exposed_secret| exposed_secret.len()) {
+        Err(ExposeSecretError::ExposeMoreThanMaximallyAllow(ref err))
+            if err.mec == 2 && err.ec == 2 => {}
+        _ => panic!("an exhausted `SealedSecret` must not be exposed"),
+    }
+
+    let array = SealedSecret::<[u8; 4], U1>::new(*b"abcd").unwrap();
+    assert!(array.expose_secret(|exposed_secret| &*exposed_secret == b"abcd"));
+    let bytes = SealedSecret::<Vec<u8>, U1>::new(vec![0; 1000]).unwrap();
+    assert!(bytes.expose_secret(|exposed_secret| exposed_secret.iter().all(|byte| *byte == 0)));
+    let empty = SealedSecret::<Vec<u8>, U1>::new(Vec::new()).unwrap();
+    assert!(empty.expose_secret(|exposed_secret| exposed_secret.is_empty()));
+
+    let secret_string = SealedSecret::<_, U1>::new(SecretString::from("hunter2")).unwrap();
+    assert!(secret_string.expose_secret(|exposed_secret| exposed_secret.as_str() == "hunter2"));
+    let secret_bytes = SealedSecret::<_, U1>::new(SecretBytes::from(&b"hunter2"[..])).unwrap();
+    assert!(secret_bytes.expose_secret(|exposed_secret| exposed_secret.as_slice() == b"hunter2"));
+    let secret_array =
+        SealedSecret::<_, U1>::new(SecretArray::<16>::from_slice(b"hunter2").unwrap()).unwrap();
+    assert!(secret_array.expose_secret(|exposed_secret| exposed_secret.as_slice() == b"hunter2"));
+}
+
+#[cfg(feature = "envelope")]
+#[test]
+fn test_envelope() {
+    use sosecrets_rs::{
+        envelope::{Envelope, EnvelopeError, EnvelopeKey, Kdf},
+        prelude::typenum::U2,
+    };
+
+    let key = EnvelopeKey::passphrase(b"correct horse battery staple");
+    let golden: &[u8] = include_bytes!("golden/envelope_v1_passphrase.bin");
+    let opened = RTSecret::<Vec<u8>, U2>::open_from_reader(&key, golden).unwrap();
+    assert_eq!(opened.exposure_count(), 0);
+    assert!(opened.expose_secret(|exposed_secret| {
+        exposed_secret
+            .iter()
+            .enumerate()
+            .all(|(index, byte)| *byte == (index * 7 % 256) as u8)
+    }));
+
+    let key = key.with_iterations(2_000);
+    let mut file = Vec::new();
+    opened.seal_to_writer(&key, "api-token", &mut file).unwrap();
+    assert!(opened.is_exhausted());
+    let mut exhausted_file = Vec::new();
+    let err = opened
+        .seal_to_writer(&key, "api-token", &mut exhausted_file)
+        .unwrap_err();
+    assert!(matches!(err, EnvelopeError::Exposure(_)));
+    assert!(std::error::Error::source(&err).is_none());
+    assert!(exhausted_file.is_empty());
+    let envelope = Envelope::parse(&file).unwrap();
+    assert_eq!(envelope.label(), "api-token");
+    assert_eq!(envelope.kdf(), Kdf::Pbkdf2HmacSha256 { iterations: 2_000 });
+    assert_eq!(envelope.secret_len(), 100);
+
+    let secrecy_secret = SecrecySecret::<Vec<u8>>::open_from_reader(&key, file.as_slice()).unwrap();
+    let mut other_file = Vec::new();
+    secrecy_secret
+        .seal_to_writer(&key, "api-token", &mut other_file)
+        .unwrap();
+    // The salt and the nonce are random.
+    assert_ne!(file, other_file);
+
+    assert!(matches!(
+        RTSecret::<Vec<u8>, U2>::open_from_reader(&key, &b"SOSECENV"[..]),
+        Err(EnvelopeError::Truncated)
+    ));
+    assert!(format!("{:?}", key).contains("REDACTED"));
+    assert!(!format!("{:?}", key).contains("horse"));
+}
+
+#[cfg(all(feature = "command", unix))]
+#[test]
+fn test_command_secret() {
+    use sosecrets_rs::runtime::{
+        command::{CommandSecretError, SecretCommandExt},
+        error::ExposeSecretError,
+    };
+    use std::process::{Command, Stdio};
+
+    let secret = RTSecret::<_, U1>::new(*b"hunter2");
+    let output = Command::new("/bin/sh")
+        .args(["-c", r#"test "$(cat <&3)" = hunter2 && test "$(cat <&4)" = abcd"#])
+        .secret_fd(3, &secret)
+        .unwrap()
+        .secret_fd(4, &SecrecySecret::new(*b"abcd"))
+        .unwrap()
+        .output()
+        .unwrap();
+    assert!(output.status.success(), "{:?}", output);
+    assert!(secret.is_exhausted());
+
+    // An exhausted secret is neither exposed nor passed to the command, which is not spawned.
+    assert!(matches!(
+        Command::new("/bin/sh").secret_fd(3, &secret),
+        Err(CommandSecretError::Exposure(
+            ExposeSecretError::ExposeMoreThanMaximallyAllow(_)
+        ))
+    ));
+    assert!(matches!(
+        Command::new("/nonexistent").spawn_with_secret_stdin(&secret),
+        Err(CommandSecretError::Exposure(_))
+    ));
+
+    let secret = RTSecret::<_, U2>::new([0xff_u8; 100_000]);
+    let child = Command::new("/bin/sh")
+        .args(["-c", "wc -c"])
+        .stdout(Stdio::piped())
+        .spawn_with_secret_stdin(&secret)
+        .unwrap();
+    assert!(child.stdin.is_none());
+    let output = child.wait_with_output().unwrap();
+    assert_eq!(String::from_utf8(output.stdout).unwrap().trim(), "100000");
+    assert_eq!(secret.exposure_count(), 1);
+
+    // A child process closing its standard input early is not an error.
+    let child = Command::new("/bin/sh")
+        .args(["-c", "exec 0<&-; exit 3"])
+        .spawn_with_secret_stdin(&secret)
+        .unwrap();
+    assert_eq!(child.wait_with_output().unwrap().status.code(), Some(3));
+
+    let err = Command::new("/nonexistent")
+        .spawn_with_secret_stdin(&SecrecySecret::new(*b"abcd"))
+        .unwrap_err();
+    assert!(matches!(err, CommandSecretError::Io(_)));
+    assert!(std::error::Error::source(&err).is_none());
+}
+
+#[cfg(feature = "hkdf")]
+#[test]
+fn test_derive_key() {
+    use sosecrets_rs::{hkdf::HkdfParams, prelude::Secret, traits::ExposeSecret};
+
+    // The test case 3 of RFC 5869, with an empty salt and info.
+    let params = HkdfParams::new(b"", b"");
+    let expected_okm = [
+        0x8d, 0xa4, 0xe7, 0x75, 0xa5, 0x63, 0xc1, 0x8f, 0x71, 0x5f, 0x80, 0x2a, 0x06, 0x3c, 0x5a,
+        0x31, 0xb8, 0xa1, 0x1f, 0x5c, 0x5e, 0xe1, 0x87, 0x9e, 0xc3, 0x45, 0x4e, 0x5f, 0x3c, 0x73,
+        0x8d, 0x2d, 0x9d, 0x20, 0x13, 0x95, 0xfa, 0xa4, 0xb6, 0x1a, 0x96, 0xc8,
+    ];
+
+    let master_key = RTSecret::<[u8; 22], U2>::new([0x0b; 22]);
+    let okm: Secret<[u8; 42], U1> = master_key.derive_key(&params);
+    assert_eq!(master_key.exposure_count(), 1);
+    let (_, okm) = okm.expose_secret(|exposed_secret| *exposed_secret);
+    assert_eq!(okm, expected_okm);
+
+    let okm = master_key.try_derive_key::<42, U1>(&params).unwrap();
+    assert_eq!(master_key.exposure_count(), 2);
+    let (_, okm) = okm.expose_secret(|exposed_secret| *exposed_secret);
+    assert_eq!(okm, expected_okm);
+    assert!(master_key.try_derive_key::<42, U1>(&params).is_err());
+    assert_eq!(master_key.exposure_count(), 2);
+
+    let master_key = SecrecySecret::new([0x0b; 22]);
+    let okm = master_key.derive_key::<42, U1>(&params);
+    let (_, okm) = okm.expose_secret(|exposed_secret| *exposed_secret);
+    assert_eq!(okm, expected_okm);
+}
+
+#[cfg(feature = "hkdf")]
+#[test]
+#[should_panic]
+fn test_derive_key_exhausted() {
+    use sosecrets_rs::hkdf::HkdfParams;
+
+    let master_key = RTSecret::<[u8; 22], U1>::new([0x0b; 22]);
+    let params = HkdfParams::new(b"salt", b"info");
+    let _ = master_key.derive_key::<32, U1>(&params);
+    let _ = master_key.derive_key::<32, U1>(&params);
+}
+
+#[cfg(feature = "password-hash")]
+#[test]
+fn test_hash_password() {
+    use rand_chacha::{rand_core::SeedableRng, ChaCha20Rng};
+    use sosecrets_rs::password_hash::{Argon2Params, PasswordHashError};
+
+    // The Argon2id hash of "password" with the salt "somesalt" and 2 lanes by the reference implementation.
+    let reference_hash =
+        "$argon2id$v=19$m=256,t=2,p=2$c29tZXNhbHQ$bQk8UB/VmZZF4Oo79iDXuL5/0ttZwg2f/5U52iv1cDc";
+    let password = RTSecret::<[u8; 8], U2>::new(*b"password");
+    assert!(password.verify_password(reference_hash).unwrap());
+    assert_eq!(password.exposure_count(), 1);
+
+    let mut rng = ChaCha20Rng::from_seed([0; 32]);
+    let stored_hash = password
+        .hash_password_with(&Argon2Params::new(64, 1, 1), &mut rng)
+        .unwrap();
+    assert_eq!(password.exposure_count(), 2);
+    assert!(stored_hash.starts_with("$argon2id$v=19$m=64,t=1,p=1$drjgraDxPZBAXWrlU4a9KA$"));
+
+    let candidate = SecrecySecret::new(*b"password");
+    assert!(candidate.verify_password(&stored_hash).unwrap());
+    assert!(candidate.verify_password(reference_hash).unwrap());
+    assert!(!SecrecySecret::new(*b"passw0rd")
+        .verify_password(&stored_hash)
+        .unwrap());
+    let other_hash = candidate
+        .hash_password_with(&Argon2Params::new(64, 1, 1), &mut rng)
+        .unwrap();
+    assert_ne!(other_hash, stored_hash);
+    assert!(matches!(
+        candidate.hash_password_with(&Argon2Params::new(64, 1, 1), &mut common::FailingRng),
+        Err(PasswordHashError::Rng(_))
+    ));
+
+    #[cfg(feature = "getrandom")]
+    {
+        let password = RTSecret::<[u8; 8], U1>::new(*b"password");
+        let stored_hash = password
+            .hash_password(&Argon2Params::new(64, 1, 1))
+            .unwrap();
+        assert!(candidate.verify_password(&stored_hash).unwrap());
+    }
+}
+
+#[cfg(feature = "password-hash")]
+#[test]
+fn test_try_hash_password_exhausted() {
+    use rand_chacha::{rand_core::SeedableRng, ChaCha20Rng};
+    use sosecrets_rs::{password_hash::Argon2Params, runtime::error::ExposeSecretError};
+
+    let password = RTSecret::<[u8; 8], U1>::new(*b"password");
+    let mut rng = ChaCha20Rng::from_seed([0; 32]);
+    let stored_hash = password
+        .try_hash_password_with(&Argon2Params::new(64, 1, 1), &mut rng)
+        .unwrap()
+        .unwrap();
+    assert!(matches!(
+        password.try_hash_password_with(&Argon2Params::new(64, 1, 1), &mut rng),
+        Err(ExposeSecretError::ExposeMoreThanMaximallyAllow(_))
+    ));
+    assert!(matches!(
+        password.try_verify_password(&stored_hash),
+        Err(ExposeSecretError::ExposeMoreThanMaximallyAllow(_))
+    ));
+
+    let password = RTSecret::<[u8; 8], U1>::new(*b"password");
+    assert!(password.try_verify_password(&stored_hash).unwrap().unwrap());
+    assert!(password.try_verify_password(&stored_hash).is_err());
+
+    #[cfg(feature = "getrandom")]
+    {
+        let password = RTSecret::<[u8; 8], U1>::new(*b"password");
+        assert!(password
+            .try_hash_password(&Argon2Params::new(64, 1, 1))
+            .unwrap()
+            .is_ok());
+        assert!(password
+            .try_hash_password(&Argon2Params::new(64, 1, 1))
+            .is_err());
+    }
+}
+
+#[cfg(feature = "password-hash")]
+#[test]
+#[should_panic]
+fn test_verify_password_exhausted() {
+    let password = RTSecret::<[u8; 8], U1>::new(*b"password");
+    let _ = password.verify_password("$argon2id$v=19$m=64,t=1,p=1$c29tZXNhbHQ$");
+    let _ = password.verify_password("$argon2id$v=19$m=64,t=1,p=1$c29tZXNhbHQ$");
+}
+
+#[cfg(feature = "rand-core")]
+#[test]
+fn test_generate() {
+    use core::num::NonZeroU32;
+    use rand_chacha::{rand_core::SeedableRng, ChaCha20Rng};
+    use sosecrets_rs::prelude::rand_core::{self, CryptoRng, RngCore};
+
+    /// A random number generator which always fails.
+    struct FailingRng;
+
+    impl RngCore for FailingRng {
+        fn next_u32(&mut self) -> u32 {
+            unimplemented!()
+        }
+
+        fn next_u64(&mut self) -> u64 {
+            unimplemented!()
+        }
+
+        fn fill_bytes(&mut self, _: &mut [u8]) {
+            unimplemented!()
+        }
+
+        fn try_fill_bytes(&mut self, _: &mut [u8]) -> Result<(), rand_core::Error> {
+            Err(NonZeroU32::new(rand_core::Error::CUSTOM_START).unwrap().into())
+        }
+    }
+
+    impl CryptoRng for FailingRng {}
+
+    // The first bytes of the ChaCha20 keystream under the all-zero key and nonce (RFC 8439, A.1).
+    let keystream = [0x76, 0xb8, 0xe0, 0xad, 0xa0, 0xf1, 0x3d, 0x90];
+
+    let mut rng = ChaCha20Rng::from_seed([0; 32]);
+    let secret = RTSecret::<[u8; 8], U1>::generate_with(&mut rng);
+    assert_eq!(secret.expose_secret(|exposed_secret| *exposed_secret), keystream);
+    assert!(RTSecret::<[u8; 8], U1>::try_generate_with(&mut FailingRng).is_err());
+
+    let mut rng = ChaCha20Rng::from_seed([0; 32]);
+    let secret = SecrecySecret::<[u8; 8]>::try_generate_with(&mut rng).unwrap();
+    assert_eq!(secret.expose_secret(|exposed_secret| *exposed_secret), keystream);
+
+    #[cfg(feature = "alloc")]
+    {
+        use sosecrets_rs::containers::SecretBytes;
+
+        let mut rng = ChaCha20Rng::from_seed([0; 32]);
+        let bytes = SecretBytes::generate_with(1000, &mut rng);
+        assert_eq!(bytes.len(), 1000);
+        assert_eq!(bytes.as_slice()[..8], keystream);
+        assert!(SecretBytes::generate_with(0, &mut rng).is_empty());
+        assert!(SecretBytes::try_generate_with(8, &mut FailingRng).is_err());
+    }
+
+    #[cfg(feature = "getrandom")]
+    {
+        let first = RTSecret::<[u8; 32], U1>::generate();
+        let second = RTSecret::<[u8; 32], U1>::generate();
+        assert_ne!(
+            first.expose_secret(|exposed_secret| *exposed_secret),
+            second.expose_secret(|exposed_secret| *exposed_secret)
+        );
+    }
+}
+
+#[cfg(feature = "password")]
+#[test]
+fn test_password() {
+    use rand_chacha::{rand_core::SeedableRng, ChaCha20Rng};
+    use sosecrets_rs::password::{
+        PassphrasePolicy, PasswordError, PasswordPolicy, AMBIGUOUS, DIGITS, LOWERCASE, SYMBOLS,
+        UPPERCASE, WORD_LIST_LEN,
+    };
+
+    let assert_entropy = |policy: PasswordPolicy, expected: f64| {
+        let entropy = policy.entropy_bits();
+        assert!(
+            (entropy - expected).abs() < 1e-9,
+            "{} != {}",
+            entropy,
+            expected
+        );
+    };
+    assert_entropy(
+        PasswordPolicy::new(10)
+            .with_uppercase(false)
+            .with_digits(false)
+            .with_symbols(false),
+        10.0 * 26f64.log2(),
+    );
+    // 36^2 passwords of lowercase letters and digits, without the 26^2 without digits and the 10^2 without letters.
+    assert_entropy(
+        PasswordPolicy::new(2)
+            .with_uppercase(false)
+            .with_symbols(false),
+        520f64.log2(),
+    );
+    let entropy = PasswordPolicy::new(20).entropy_bits();
+    assert!(entropy < 20.0 * 90f64.log2() && entropy > 20.0 * 90f64.log2() - 1.0);
+    assert_eq!(PasswordPolicy::new(3).entropy_bits(), 0.0);
+
+    let policy = PasswordPolicy::new(16).with_ambiguous(false);
+    let mut rng = ChaCha20Rng::from_seed([0; 32]);
+    let generated = policy.generate_with(&mut rng).unwrap();
+    assert_eq!(generated.entropy_bits(), policy.entropy_bits());
+    let debug = format!("{:?}", generated);
+    let password = generated.into_rt_secret::<U2, sosecrets_rs::runtime::RetainOnExhaustion>();
+    let password = password.expose_secret(|exposed_secret| exposed_secret.clone());
+    assert_eq!(password.len(), 16);
+    assert!(!debug.contains(&password));
+    for class in [LOWERCASE, UPPERCASE, DIGITS, SYMBOLS] {
+        assert!(
+            password.chars().any(|char| class.contains(char)),
+            "{}",
+            class
+        );
+    }
+    assert!(!password.chars().any(|char| AMBIGUOUS.contains(char)));
+    // The same seed generates the same password.
+    let mut rng = ChaCha20Rng::from_seed([0; 32]);
+    let secret: RTSecret<String, U1> = policy.generate_with(&mut rng).unwrap().into_rt_secret();
+    secret.expose_secret(|exposed_secret| assert_eq!(*exposed_secret, password));
+
+    assert!(matches!(
+        PasswordPolicy::new(8)
+            .with_lowercase(false)
+            .with_uppercase(false)
+            .with_digits(false)
+            .with_symbols(false)
+            .generate_with(&mut rng),
+        Err(PasswordError::NoCharacterClass)
+    ));
+    assert!(matches!(
+        PasswordPolicy::new(3).generate_with(&mut rng),
+        Err(PasswordError::TooShort {
+            length: 3,
+            minimum: 4
+        })
+    ));
+    assert!(matches!(
+        PasswordPolicy::new(8)
+            .with_min_entropy_bits(64)
+            .generate_with(&mut rng),
+        Err(PasswordError::InsufficientEntropy {
+            required_bits: 64,
+            ..
+        })
+    ));
+    assert!(matches!(
+        PasswordPolicy::new(8).generate_with(&mut common::FailingRng),
+        Err(PasswordError::Rng(_))
+    ));
+
+    assert_eq!(WORD_LIST_LEN, 2048);
+    let policy = PassphrasePolicy::new(5).with_separator(' ');
+    assert_eq!(policy.entropy_bits(), 55.0);
+    let mut rng = ChaCha20Rng::from_seed([0; 32]);
+    let passphrase: RTSecret<String, U1> = policy.generate_with(&mut rng).unwrap().into_rt_secret();
+    passphrase.expose_secret(|exposed_secret| {
+        let words: Vec<_> = exposed_secret.split(' ').collect();
+        assert_eq!(words.len(), 5);
+        // The first word is drawn from the first 4 bytes of the keystream, 0xade0b876 % 2048 = 118.
+        assert_eq!(words[0], "attract");
+        assert!(words.iter().all(|word| (3..=8).contains(&word.len())
+            && word.chars().all(|char| char.is_ascii_lowercase())));
+    });
+    assert!(matches!(
+        PassphrasePolicy::new(0).generate_with(&mut rng),
+        Err(PasswordError::TooShort {
+            length: 0,
+            minimum: 1
+        })
+    ));
+    assert!(matches!(
+        PassphrasePolicy::new(5)
+            .with_min_entropy_bits(56)
+            .generate_with(&mut rng),
+        Err(PasswordError::InsufficientEntropy { .. })
+    ));
+    assert!(PassphrasePolicy::new(7)
+        .with_min_entropy_bits(77)
+        .generate_with(&mut rng)
+        .is_ok());
+
+    #[cfg(feature = "getrandom")]
+    {
+        let first: RTSecret<String, U1> = PassphrasePolicy::new(6)
+            .generate()
+            .unwrap()
+            .into_rt_secret();
+        let second: RTSecret<String, U1> = PassphrasePolicy::new(6)
+            .generate()
+            .unwrap()
+            .into_rt_secret();
+        assert_ne!(
+            first.expose_secret(|exposed_secret| exposed_secret.clone()),
+            second.expose_secret(|exposed_secret| exposed_secret.clone())
+        );
+    }
+}
@@ -34,10 +34,26 @@ fn test_compile_fails() {
     ))]
     t.compile_fail("trybuild_tests/1_70/test_compile_fail_ten.rs");
     t.compile_fail("trybuild_tests/1_70/test_cannot_return_exposed_secret.rs");
+    t.compile_fail("trybuild_tests/1_70/test_cannot_return_exposed_secret_mut.rs");
+    t.compile_fail("trybuild_tests/1_70/test_cannot_take_exposed_secret_mut.rs");
     t.compile_fail("trybuild_tests/1_70/test_panic_cannot_return_exposed.rs");
     #[cfg(not(feature = "zeroize"))]
     t.compile_fail("trybuild_tests/1_70/test_ref_cannot_leak_secret.rs");
     // t.compile_fail("trybuild_tests/1_70/test_compile_fail_eleven.rs");
+
+    #[cfg(all(
+        feature = "derive",
+        feature = "cloneable-secret",
+        not(feature = "alloc"),
+        not(feature = "zeroize")
+    ))]
+    t.compile_fail("trybuild_tests/1_70/test_derive_field_not_cloneable_secret.rs");
+
+    #[cfg(all(feature = "derive", feature = "debug-secret"))]
+    t.compile_fail("trybuild_tests/1_70/test_derive_unknown_secret_attribute.rs");
+
+    #[cfg(feature = "derive")]
+    t.compile_fail("trybuild_tests/1_70/test_secret_struct_invalid.rs");
     #[cfg(feature = "cloneable-secret")]
     t.pass("trybuild_tests/1_70/test_compile_pass_one.rs");
     // no_std env + no alloc + no cloneable-secret should work
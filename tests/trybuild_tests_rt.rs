@@ -4,7 +4,10 @@ fn test_compile_fails() {
     let t = trybuild::TestCases::new();
     t.compile_fail("trybuild_tests/runtime/cannot_cross_unwind_if_not_copy.rs");
     t.compile_fail("trybuild_tests/runtime/cannot_return_exposed_secret.rs");
+    t.compile_fail("trybuild_tests/runtime/cannot_return_exposed_secret_mut.rs");
+    t.compile_fail("trybuild_tests/runtime/cannot_take_exposed_secret_mut.rs");
     t.compile_fail("trybuild_tests/runtime/u0_cannot_call_expose_secret.rs");
+    t.compile_fail("trybuild_tests/runtime/non_panicking_cannot_call_expose_secret.rs");
 
     #[cfg(all(
         not(feature = "debug-secret"),
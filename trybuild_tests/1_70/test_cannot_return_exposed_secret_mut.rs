@@ -0,0 +1,10 @@
+mod common;
+fn main() {
+    use sosecrets_rs::{containers::SecretArray, prelude::*};
+    use typenum::consts::U2;
+
+    let new_secret: Secret<SecretArray<8>, U2> = Secret::new(SecretArray::new());
+
+    let (new_secret, _) = new_secret.expose_secret_mut(|exposed_secret| exposed_secret);
+    let (_, _) = new_secret.expose_secret_mut(|mut exposed_secret| exposed_secret.as_mut_slice());
+}
@@ -0,0 +1,14 @@
+mod common;
+fn main() {
+    use sosecrets_rs::{containers::SecretArray, prelude::*};
+    use typenum::consts::U2;
+
+    let new_secret: Secret<SecretArray<8>, U2> =
+        Secret::new(SecretArray::from_slice(b"hunter2").unwrap());
+
+    let (new_secret, _) =
+        new_secret.expose_secret_mut(|mut exposed_secret| core::mem::take(&mut *exposed_secret));
+    let (_, _) = new_secret.expose_secret_mut(|mut exposed_secret| {
+        core::mem::replace(&mut *exposed_secret, SecretArray::new())
+    });
+}
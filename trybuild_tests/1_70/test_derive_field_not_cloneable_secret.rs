@@ -0,0 +1,12 @@
+use sosecrets_rs::traits::CloneableSecret;
+
+#[derive(Clone)]
+struct NotASecret(u32);
+
+#[derive(Clone, CloneableSecret)]
+struct Credentials {
+    user_id: u64,
+    api_key: NotASecret,
+}
+
+fn main() {}
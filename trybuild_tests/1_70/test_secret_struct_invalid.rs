@@ -0,0 +1,23 @@
+use sosecrets_rs::runtime::secret_struct;
+
+#[secret_struct]
+struct Db {
+    host: &'static str,
+    #[secret(min = 3)]
+    password: [u8; 4],
+    #[secret(max = "3")]
+    token: u64,
+    #[secret]
+    #[secret(max = 3)]
+    pin: u16,
+}
+
+#[secret_struct]
+enum Key {
+    Short(#[secret(max = 1)] u32),
+}
+
+#[secret_struct(serde)]
+struct ApiKey(#[secret(max = 1)] u32);
+
+fn main() {}
@@ -0,0 +1,9 @@
+fn main() {
+    use sosecrets_rs::{containers::SecretArray, prelude::typenum::U2, runtime::secret::RTSecret};
+
+    let mut secret_one = RTSecret::<SecretArray<8>, U2>::new(SecretArray::new());
+
+    let _ = secret_one.expose_secret_mut(|exposed_secret| exposed_secret);
+
+    let _ = secret_one.expose_secret_mut(|mut exposed_secret| exposed_secret.as_mut_slice());
+}
@@ -0,0 +1,13 @@
+fn main() {
+    use sosecrets_rs::{containers::SecretArray, prelude::typenum::U2, runtime::secret::RTSecret};
+
+    let mut secret_one =
+        RTSecret::<SecretArray<8>, U2>::new(SecretArray::from_slice(b"hunter2").unwrap());
+
+    let _ =
+        secret_one.expose_secret_mut(|mut exposed_secret| core::mem::take(&mut *exposed_secret));
+
+    let _ = secret_one.expose_secret_mut(|mut exposed_secret| {
+        core::mem::replace(&mut *exposed_secret, SecretArray::new())
+    });
+}
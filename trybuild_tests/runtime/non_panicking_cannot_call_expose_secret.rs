@@ -0,0 +1,18 @@
+fn main() {
+    use sosecrets_rs::{
+        prelude::typenum::U2,
+        runtime::{traits::RTExposeSecret, NonPanickingRTSecret},
+    };
+
+    let secret_one = NonPanickingRTSecret::<isize, U2>::new(69);
+
+    // Ok
+    let _ = secret_one.try_expose_secret(|exposed_secret| {
+        assert_eq!(*exposed_secret, 69);
+    });
+
+    // Not Ok
+    let _ = secret_one.expose_secret(|exposed_secret| {
+        assert_eq!(*exposed_secret, 69);
+    });
+}
@@ -0,0 +1,13 @@
+use sosecrets_rs::traits::DebugSecret;
+
+#[derive(DebugSecret)]
+struct Credentials {
+    #[secret(show)]
+    user_id: u64,
+    #[secret(hide)]
+    api_key: [u8; 4],
+    #[secret(show, redact)]
+    pin: u16,
+}
+
+fn main() {}